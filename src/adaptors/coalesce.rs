@@ -156,6 +156,19 @@ impl<T, F: FnMut(&T, &T) -> bool> DedupPredicate<T> for F {
     }
 }
 
+#[derive(Clone)]
+pub struct DedupKey<F>(F);
+
+impl<F> fmt::Debug for DedupKey<F> {
+    debug_fmt_fields!(DedupKey,);
+}
+
+impl<T, K: PartialEq, F: FnMut(&T) -> K> DedupPredicate<T> for DedupKey<F> {
+    fn dedup_pair(&mut self, a: &T, b: &T) -> bool {
+        (self.0)(a) == (self.0)(b)
+    }
+}
+
 /// Create a new `DedupBy`.
 pub fn dedup_by<I, Pred>(mut iter: I, dedup_pred: Pred) -> DedupBy<I, Pred>
 where
@@ -181,6 +194,21 @@ where
     dedup_by(iter, DedupEq)
 }
 
+/// An iterator adaptor that removes repeated duplicates, determining equality by comparing keys.
+///
+/// See [`.dedup_by_key()`](crate::Itertools::dedup_by_key) for more information.
+pub type DedupByKey<I, F> = DedupBy<I, DedupKey<F>>;
+
+/// Create a new `DedupByKey`.
+pub fn dedup_by_key<I, K, F>(iter: I, key: F) -> DedupByKey<I, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    dedup_by(iter, DedupKey(key))
+}
+
 /// An iterator adaptor that removes repeated duplicates, while keeping a count of how many
 /// repeated elements were present. This will determine equality using a comparison function.
 ///
@@ -235,3 +263,32 @@ where
 {
     dedup_by_with_count(iter, DedupEq)
 }
+
+/// An iterator adaptor that yields the length of every run of equal
+/// consecutive elements, along with the first element of the run.
+///
+/// See [`.run_lengths()`](crate::Itertools::run_lengths) for more information.
+pub type RunLengths<I> = DedupWithCount<I>;
+
+/// An iterator adaptor that yields the length of every run of consecutive
+/// elements that are equal according to a comparison function, along with
+/// the first element of the run.
+///
+/// See [`.run_lengths_by()`](crate::Itertools::run_lengths_by) for more information.
+pub type RunLengthsBy<I, Pred> = DedupByWithCount<I, Pred>;
+
+/// An iterator adaptor that yields the length of every run of consecutive
+/// elements with equal keys, along with the first element of the run.
+///
+/// See [`.run_lengths_by_key()`](crate::Itertools::run_lengths_by_key) for more information.
+pub type RunLengthsByKey<I, F> = DedupByWithCount<I, DedupKey<F>>;
+
+/// Create a new `RunLengthsByKey`.
+pub fn run_lengths_by_key<I, K, F>(iter: I, key: F) -> RunLengthsByKey<I, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    dedup_by_with_count(iter, DedupKey(key))
+}
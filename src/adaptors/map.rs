@@ -1,3 +1,4 @@
+use either::Either;
 use std::iter::FromIterator;
 use std::marker::PhantomData;
 
@@ -53,6 +54,14 @@ where
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back().map(|i| self.f.call(i))
     }
+
+    fn rfold<Acc, Fold>(self, init: Acc, mut fold_f: Fold) -> Acc
+    where
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.rfold(init, move |acc, v| fold_f(acc, f.call(v)))
+    }
 }
 
 impl<I, R> ExactSizeIterator for MapSpecialCase<I, R>
@@ -100,6 +109,41 @@ where
     }
 }
 
+/// An iterator adapter to apply a fallible transformation within a nested
+/// `Result::Ok`.
+///
+/// See [`.and_then_ok()`](crate::Itertools::and_then_ok) for more information.
+pub type AndThenOk<I, F> = MapSpecialCase<I, MapSpecialCaseFnAndThenOk<F>>;
+
+impl<F, T, U, E> MapSpecialCaseFn<Result<T, E>> for MapSpecialCaseFnAndThenOk<F>
+where
+    F: FnMut(T) -> Result<U, E>,
+{
+    type Out = Result<U, E>;
+    fn call(&mut self, t: Result<T, E>) -> Self::Out {
+        t.and_then(|v| self.0(v))
+    }
+}
+
+#[derive(Clone)]
+pub struct MapSpecialCaseFnAndThenOk<F>(F);
+
+impl<F> std::fmt::Debug for MapSpecialCaseFnAndThenOk<F> {
+    debug_fmt_fields!(MapSpecialCaseFnAndThenOk,);
+}
+
+/// Create a new `AndThenOk` iterator.
+pub fn and_then_ok<I, F, T, U, E>(iter: I, f: F) -> AndThenOk<I, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(T) -> Result<U, E>,
+{
+    MapSpecialCase {
+        iter,
+        f: MapSpecialCaseFnAndThenOk(f),
+    }
+}
+
 /// An iterator adapter to apply `Into` conversion to each element.
 ///
 /// See [`.map_into()`](crate::Itertools::map_into) for more information.
@@ -122,3 +166,75 @@ pub fn map_into<I, R>(iter: I) -> MapInto<I, R> {
         f: MapSpecialCaseFnInto(PhantomData),
     }
 }
+
+/// An iterator adapter to apply one of two transformations to each element,
+/// depending on a predicate.
+///
+/// See [`.map_either()`](crate::Itertools::map_either) for more information.
+pub type MapEither<I, P, F, G> = MapSpecialCase<I, MapSpecialCaseFnEither<P, F, G>>;
+
+impl<P, F, G, T, L, R> MapSpecialCaseFn<T> for MapSpecialCaseFnEither<P, F, G>
+where
+    P: FnMut(&T) -> bool,
+    F: FnMut(T) -> L,
+    G: FnMut(T) -> R,
+{
+    type Out = Either<L, R>;
+    fn call(&mut self, t: T) -> Self::Out {
+        if (self.pred)(&t) {
+            Either::Left((self.f_true)(t))
+        } else {
+            Either::Right((self.f_false)(t))
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct MapSpecialCaseFnEither<P, F, G> {
+    pred: P,
+    f_true: F,
+    f_false: G,
+}
+
+impl<P, F, G> std::fmt::Debug for MapSpecialCaseFnEither<P, F, G> {
+    debug_fmt_fields!(MapSpecialCaseFnEither,);
+}
+
+/// Create a new `MapEither` iterator.
+pub fn map_either<I, P, F, G, L, R>(iter: I, pred: P, f_true: F, f_false: G) -> MapEither<I, P, F, G>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+    F: FnMut(I::Item) -> L,
+    G: FnMut(I::Item) -> R,
+{
+    MapSpecialCase {
+        iter,
+        f: MapSpecialCaseFnEither { pred, f_true, f_false },
+    }
+}
+
+/// An iterator adapter to collapse each `Either<T, T>` element into its `T`.
+///
+/// See [`.merge_either()`](crate::Itertools::merge_either) for more information.
+pub type MergeEither<I> = MapSpecialCase<I, MapSpecialCaseFnMergeEither>;
+
+impl<T> MapSpecialCaseFn<Either<T, T>> for MapSpecialCaseFnMergeEither {
+    type Out = T;
+    fn call(&mut self, t: Either<T, T>) -> Self::Out {
+        match t {
+            Either::Left(v) | Either::Right(v) => v,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MapSpecialCaseFnMergeEither;
+
+/// Create a new [`MergeEither`] iterator.
+pub fn merge_either<I>(iter: I) -> MergeEither<I> {
+    MapSpecialCase {
+        iter,
+        f: MapSpecialCaseFnMergeEither,
+    }
+}
@@ -8,7 +8,7 @@ mod coalesce;
 mod map;
 mod multi_product;
 pub use self::coalesce::*;
-pub use self::map::{map_into, map_ok, MapInto, MapOk};
+pub use self::map::{and_then_ok, map_either, map_into, map_ok, merge_either, AndThenOk, MapEither, MapInto, MapOk, MergeEither};
 #[allow(deprecated)]
 pub use self::map::MapResults;
 #[cfg(feature = "use_alloc")]
@@ -167,6 +167,161 @@ impl<I, J> FusedIterator for InterleaveShortest<I, J>
           J: FusedIterator<Item = I::Item>
 {}
 
+/// Decides what [`InterleaveTail`] does once the iterator whose turn it is
+/// has run out.
+///
+/// Implemented by [`StopAtShortest`], [`DrainLongest`] and [`PadWith`].
+pub trait TailPolicy<T> {
+    /// Whether the remaining elements of the longer iterator are produced.
+    fn drains(&self) -> bool;
+
+    /// Whether the exhausted iterator is stood in for by [`.pad()`](TailPolicy::pad).
+    fn pads(&self) -> bool;
+
+    /// Produce an element in place of the exhausted iterator.
+    fn pad(&mut self) -> Option<T>;
+}
+
+/// Tail policy: stop as soon as the iterator whose turn it is runs out,
+/// like [`.interleave_shortest()`](crate::Itertools::interleave_shortest).
+#[derive(Copy, Clone, Debug)]
+pub struct StopAtShortest;
+
+/// Tail policy: once one iterator runs out, produce the rest of the other,
+/// like [`.interleave()`](crate::Itertools::interleave).
+#[derive(Copy, Clone, Debug)]
+pub struct DrainLongest;
+
+/// Tail policy: once one iterator runs out, keep alternating strictly by
+/// calling the closure in place of the exhausted iterator, until both have
+/// run out.
+#[derive(Copy, Clone, Debug)]
+pub struct PadWith<F>(pub F);
+
+impl<T> TailPolicy<T> for StopAtShortest {
+    fn drains(&self) -> bool { false }
+    fn pads(&self) -> bool { false }
+    fn pad(&mut self) -> Option<T> { None }
+}
+
+impl<T> TailPolicy<T> for DrainLongest {
+    fn drains(&self) -> bool { true }
+    fn pads(&self) -> bool { false }
+    fn pad(&mut self) -> Option<T> { None }
+}
+
+impl<T, F: FnMut() -> T> TailPolicy<T> for PadWith<F> {
+    fn drains(&self) -> bool { true }
+    fn pads(&self) -> bool { true }
+    fn pad(&mut self) -> Option<T> { Some((self.0)()) }
+}
+
+/// An iterator adaptor that alternates elements from two iterators, with a
+/// [`TailPolicy`] deciding what happens once one of them runs out.
+///
+/// This iterator is *fused*.
+///
+/// See [`.interleave_tail()`](crate::Itertools::interleave_tail) for more
+/// information.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct InterleaveTail<I, J, P>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>
+{
+    a: Fuse<I>,
+    b: Fuse<J>,
+    // element of the side whose turn it is, pulled early while padding
+    pending: Option<I::Item>,
+    policy: P,
+    done: bool,
+    phase: bool, // false ==> a, true ==> b
+}
+
+/// Create a new `InterleaveTail` iterator.
+pub fn interleave_tail<I, J, P>(a: I, b: J, policy: P) -> InterleaveTail<I, J, P>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          P: TailPolicy<I::Item>
+{
+    InterleaveTail {
+        a: a.fuse(),
+        b: b.fuse(),
+        pending: None,
+        policy,
+        done: false,
+        phase: false,
+    }
+}
+
+impl<I, J, P> Iterator for InterleaveTail<I, J, P>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          P: TailPolicy<I::Item>
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if let Some(elt) = self.pending.take() {
+            self.phase = !self.phase;
+            return Some(elt);
+        }
+        let cur = if self.phase { self.b.next() } else { self.a.next() };
+        if cur.is_some() {
+            self.phase = !self.phase;
+            return cur;
+        }
+        if !self.policy.drains() {
+            self.done = true;
+            return None;
+        }
+        let other = if self.phase { self.a.next() } else { self.b.next() };
+        match other {
+            None => {
+                self.done = true;
+                None
+            }
+            Some(elt) => match self.policy.pad() {
+                None => Some(elt),
+                Some(pad) => {
+                    self.phase = !self.phase;
+                    self.pending = Some(elt);
+                    Some(pad)
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (a_hint, b_hint) = (self.a.size_hint(), self.b.size_hint());
+        let (cur, other) = if self.phase { (b_hint, a_hint) } else { (a_hint, b_hint) };
+        let cur = size_hint::add_scalar(cur, self.pending.is_some() as usize);
+        if !self.policy.drains() {
+            // cur, other, cur, other, ... stopping at the first gap in either
+            let twice_other = size_hint::add_scalar(size_hint::mul_scalar(other, 2), 1);
+            return size_hint::min(size_hint::mul_scalar(cur, 2), twice_other);
+        }
+        if !self.policy.pads() {
+            return size_hint::add(cur, other);
+        }
+        // every round yields two elements, the last round may stop halfway
+        let twice_cur = size_hint::sub_scalar(size_hint::mul_scalar(cur, 2), 1);
+        size_hint::max(twice_cur, size_hint::mul_scalar(other, 2))
+    }
+}
+
+impl<I, J, P> FusedIterator for InterleaveTail<I, J, P>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          P: TailPolicy<I::Item>
+{}
+
 #[derive(Clone, Debug)]
 /// An iterator adaptor that allows putting back a single
 /// item to the front of the iterator.
@@ -212,6 +367,11 @@ impl<I> PutBack<I>
     pub fn put_back(&mut self, x: I::Item) {
         self.top = Some(x)
     }
+
+    #[inline]
+    pub(crate) fn top(&self) -> Option<&I::Item> {
+        self.top.as_ref()
+    }
 }
 
 impl<I> Iterator for PutBack<I>
@@ -290,6 +450,8 @@ pub struct Product<I, J>
     a_cur: Option<I::Item>,
     b: J,
     b_orig: J,
+    // the last row, when iterated from the back
+    back: Option<(I::Item, J)>,
 }
 
 /// Create a new cartesian product iterator
@@ -305,6 +467,7 @@ pub fn cartesian_product<I, J>(mut i: I, j: J) -> Product<I, J>
         a: i,
         b: j.clone(),
         b_orig: j,
+        back: None,
     }
 }
 
@@ -321,9 +484,23 @@ impl<I, J> Iterator for Product<I, J>
                 self.b = self.b_orig.clone();
                 match self.b.next() {
                     None => return None,
-                    Some(x) => {
-                        self.a_cur = self.a.next();
-                        x
+                    Some(x) => match self.a.next() {
+                        Some(a) => {
+                            self.a_cur = Some(a);
+                            x
+                        }
+                        // continue with what is left of the row started from the back
+                        None => match self.back.take() {
+                            Some((a, b)) => {
+                                self.a_cur = Some(a);
+                                self.b = b;
+                                self.b.next()?
+                            }
+                            None => {
+                                self.a_cur = None;
+                                return None;
+                            }
+                        }
                     }
                 }
             }
@@ -342,10 +519,17 @@ impl<I, J> Iterator for Product<I, J>
         // Not ExactSizeIterator because size may be larger than usize
         let (b_min, b_max) = self.b.size_hint();
 
-        // Compute a * b_orig + b for both lower and upper bound
+        let back = match self.back {
+            Some((_, ref b)) => b.size_hint(),
+            None => (0, Some(0)),
+        };
+
+        // Compute a * b_orig + b + back for both lower and upper bound
         size_hint::add(
-            size_hint::mul(self.a.size_hint(), self.b_orig.size_hint()),
-            (b_min * has_cur, b_max.map(move |x| x * has_cur)))
+            size_hint::add(
+                size_hint::mul(self.a.size_hint(), self.b_orig.size_hint()),
+                (b_min * has_cur, b_max.map(move |x| x * has_cur))),
+            back)
     }
 
     fn fold<Acc, G>(mut self, mut accum: Acc, mut f: G) -> Acc
@@ -367,10 +551,38 @@ impl<I, J> Iterator for Product<I, J>
                 }
             }
         }
+        if let Some((a, b)) = self.back {
+            accum = b.fold(accum, |acc, elt| f(acc, (a.clone(), elt)));
+        }
         accum
     }
 }
 
+impl<I, J> DoubleEndedIterator for Product<I, J>
+    where I: DoubleEndedIterator,
+          J: Clone + DoubleEndedIterator,
+          I::Item: Clone
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((ref a, ref mut b)) = self.back {
+                if let Some(elt_b) = b.next_back() {
+                    return Some((a.clone(), elt_b));
+                }
+            }
+            match self.a.next_back() {
+                Some(a) => self.back = Some((a, self.b_orig.clone())),
+                None => {
+                    // only the front row is left
+                    self.back = None;
+                    let a = self.a_cur.as_ref()?;
+                    return self.b.next_back().map(|elt_b| (a.clone(), elt_b));
+                }
+            }
+        }
+    }
+}
+
 impl<I, J> FusedIterator for Product<I, J>
     where I: FusedIterator,
           J: Clone + FusedIterator,
@@ -689,8 +901,88 @@ impl<I, A> Iterator for WhileSome<I>
     fn size_hint(&self) -> (usize, Option<usize>) {
         (0, self.iter.size_hint().1)
     }
+
+    fn fold<Acc, F>(mut self, init: Acc, mut f: F) -> Acc
+        where F: FnMut(Acc, Self::Item) -> Acc,
+    {
+        // stop at the first `None`, which `try_fold` reports as `Err`
+        let res = self.iter.try_fold(init, |acc, elt| match elt {
+            Some(elt) => Ok(f(acc, elt)),
+            None => Err(acc),
+        });
+        match res {
+            Ok(acc) | Err(acc) => acc,
+        }
+    }
+}
+
+/// An iterator adaptor that filters `Option<A>` iterator elements
+/// and produces `A`, stops on the first `None` encountered, and records why
+/// it stopped.
+///
+/// See [`.while_some_complete()`](crate::Itertools::while_some_complete) for
+/// more information.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct WhileSomeComplete<I> {
+    iter: I,
+    // `Some(true)` once exhausted, `Some(false)` once stopped at a `None`
+    complete: Option<bool>,
+}
+
+/// Create a new `WhileSomeComplete<I>`.
+pub fn while_some_complete<I>(iter: I) -> WhileSomeComplete<I> {
+    WhileSomeComplete { iter, complete: None }
+}
+
+impl<I> WhileSomeComplete<I> {
+    /// Return `true` if iteration ended because the adapted iterator was
+    /// exhausted.
+    pub fn is_complete(&self) -> bool {
+        self.complete == Some(true)
+    }
+
+    /// Return `true` if iteration ended at a `None` element.
+    pub fn stopped_at_none(&self) -> bool {
+        self.complete == Some(false)
+    }
+}
+
+impl<I, A> Iterator for WhileSomeComplete<I>
+    where I: Iterator<Item = Option<A>>
+{
+    type Item = A;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.complete.is_some() {
+            return None;
+        }
+        match self.iter.next() {
+            Some(Some(elt)) => Some(elt),
+            Some(None) => {
+                self.complete = Some(false);
+                None
+            }
+            None => {
+                self.complete = Some(true);
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.complete.is_some() {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
 }
 
+impl<I, A> FusedIterator for WhileSomeComplete<I>
+    where I: Iterator<Item = Option<A>>
+{}
+
 /// An iterator to iterate through all combinations in a `Clone`-able iterator that produces tuples
 /// of a specific size.
 ///
@@ -998,6 +1290,53 @@ impl<I, F, T, U, E> FusedIterator for FilterMapOk<I, F>
           F: FnMut(T) -> Option<U>,
 {}
 
+/// An iterator adapter to transform values within a nested `Result::Ok`
+/// for as long as the transformation succeeds.
+///
+/// See [`.map_while_ok()`](crate::Itertools::map_while_ok) for more information.
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MapWhileOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for MapWhileOk<I, F>
+where
+    I: fmt::Debug,
+{
+    debug_fmt_fields!(MapWhileOk, iter);
+}
+
+/// Create a new `MapWhileOk` iterator.
+pub fn map_while_ok<I, F, T, U, E>(iter: I, f: F) -> MapWhileOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(T) -> Option<U>,
+{
+    MapWhileOk {
+        iter,
+        f,
+    }
+}
+
+impl<I, F, T, U, E> Iterator for MapWhileOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(T) -> Option<U>,
+{
+    type Item = Result<U, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next()? {
+            Ok(v) => (self.f)(v).map(Ok),
+            Err(e) => Some(Err(e)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
 /// An iterator adapter to get the positions of each element that matches a predicate.
 ///
 /// See [`.positions()`](crate::Itertools::positions) for more information.
@@ -1048,6 +1387,18 @@ impl<I, F> Iterator for Positions<I, F>
     fn size_hint(&self) -> (usize, Option<usize>) {
         (0, self.iter.size_hint().1)
     }
+
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+        where G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        let mut count = self.count;
+        self.iter.fold(init, move |acc, v| {
+            let i = count;
+            count = i + 1;
+            if f(v) { g(acc, i) } else { acc }
+        })
+    }
 }
 
 impl<I, F> DoubleEndedIterator for Positions<I, F>
@@ -1150,6 +1501,13 @@ where
             None
         }
     }
+
+    fn rfold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+        where G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.rfold(init, move |acc, mut v| { f(&mut v); g(acc, v) })
+    }
 }
 
 impl<I, F> FusedIterator for Update<I, F>
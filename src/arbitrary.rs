@@ -0,0 +1,161 @@
+//! [`Arbitrary`] inputs for the combinatorial adaptors, together with
+//! reference models of their output.
+//!
+//! Each input is a small pool of elements (and, where relevant, a `k`), so
+//! that the expected output stays small enough to compare against. This lets
+//! downstream crates property-test code that consumes
+//! [`.permutations()`](crate::Itertools::permutations),
+//! [`.combinations()`](crate::Itertools::combinations) or
+//! [`.powerset()`](crate::Itertools::powerset) with any fuzzer or property
+//! testing framework built on the [`arbitrary`](https://docs.rs/arbitrary)
+//! crate.
+//!
+//! ```
+//! use itertools::arbitrary::{Arbitrary, CombinationsInput, Unstructured};
+//!
+//! let mut u = Unstructured::new(&[3, 1, 2, 3, 4, 2]);
+//! let input = CombinationsInput::<u8>::arbitrary(&mut u).unwrap();
+//! itertools::assert_equal(input.iter(), input.expected());
+//! ```
+
+pub use ::arbitrary::{Arbitrary, Unstructured};
+
+use ::arbitrary::Result;
+use alloc::vec::Vec;
+
+use crate::structs::{Combinations, Permutations, Powerset};
+use crate::Itertools;
+
+/// The largest pool generated for `PermutationsInput`.
+pub const MAX_PERMUTATIONS_POOL: usize = 6;
+/// The largest pool generated for `CombinationsInput` and `PowersetInput`.
+pub const MAX_COMBINATIONS_POOL: usize = 8;
+
+/// Generate a pool of at most `max` elements.
+fn arbitrary_pool<'a, T: Arbitrary<'a>>(u: &mut Unstructured<'a>, max: usize) -> Result<Vec<T>> {
+    let len = u.int_in_range(0..=max)?;
+    (0..len).map(|_| T::arbitrary(u)).collect()
+}
+
+/// Generate a `k` that is sometimes larger than the pool.
+fn arbitrary_k(u: &mut Unstructured<'_>, pool_len: usize) -> Result<usize> {
+    u.int_in_range(0..=pool_len + 1)
+}
+
+/// Push onto `out` every arrangement of `k` indices below `n` extending
+/// `prefix`, in lexicographic order; if `ordered`, indices are increasing.
+fn arrangements(n: usize, k: usize, ordered: bool, prefix: &mut Vec<usize>, out: &mut Vec<Vec<usize>>) {
+    if prefix.len() == k {
+        out.push(prefix.clone());
+        return;
+    }
+    let start = match prefix.last() {
+        Some(&last) if ordered => last + 1,
+        _ => 0,
+    };
+    for i in start..n {
+        if !prefix.contains(&i) {
+            prefix.push(i);
+            arrangements(n, k, ordered, prefix, out);
+            prefix.pop();
+        }
+    }
+}
+
+fn select<T: Clone>(pool: &[T], k: usize, ordered: bool) -> Vec<Vec<T>> {
+    let mut indices = Vec::new();
+    if k <= pool.len() {
+        arrangements(pool.len(), k, ordered, &mut Vec::new(), &mut indices);
+    }
+    indices.into_iter()
+        .map(|indices| indices.into_iter().map(|i| pool[i].clone()).collect())
+        .collect()
+}
+
+/// An input for [`.permutations()`](crate::Itertools::permutations): a pool
+/// of at most [`MAX_PERMUTATIONS_POOL`] elements and a `k`.
+#[derive(Clone, Debug)]
+pub struct PermutationsInput<T> {
+    /// The elements to permute.
+    pub pool: Vec<T>,
+    /// The length of each permutation.
+    pub k: usize,
+}
+
+impl<T: Clone> PermutationsInput<T> {
+    /// Return the adaptor under test.
+    pub fn iter(&self) -> Permutations<alloc::vec::IntoIter<T>> {
+        self.pool.clone().into_iter().permutations(self.k)
+    }
+
+    /// Return the permutations `iter` should yield, in order.
+    pub fn expected(&self) -> Vec<Vec<T>> {
+        select(&self.pool, self.k, false)
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for PermutationsInput<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let pool = arbitrary_pool(u, MAX_PERMUTATIONS_POOL)?;
+        let k = arbitrary_k(u, pool.len())?;
+        Ok(PermutationsInput { pool, k })
+    }
+}
+
+/// An input for [`.combinations()`](crate::Itertools::combinations): a pool
+/// of at most [`MAX_COMBINATIONS_POOL`] elements and a `k`.
+#[derive(Clone, Debug)]
+pub struct CombinationsInput<T> {
+    /// The elements to choose from.
+    pub pool: Vec<T>,
+    /// The length of each combination.
+    pub k: usize,
+}
+
+impl<T: Clone> CombinationsInput<T> {
+    /// Return the adaptor under test.
+    pub fn iter(&self) -> Combinations<alloc::vec::IntoIter<T>> {
+        self.pool.clone().into_iter().combinations(self.k)
+    }
+
+    /// Return the combinations `iter` should yield, in order.
+    pub fn expected(&self) -> Vec<Vec<T>> {
+        select(&self.pool, self.k, true)
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for CombinationsInput<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let pool = arbitrary_pool(u, MAX_COMBINATIONS_POOL)?;
+        let k = arbitrary_k(u, pool.len())?;
+        Ok(CombinationsInput { pool, k })
+    }
+}
+
+/// An input for [`.powerset()`](crate::Itertools::powerset): a pool of at
+/// most [`MAX_COMBINATIONS_POOL`] elements.
+#[derive(Clone, Debug)]
+pub struct PowersetInput<T> {
+    /// The elements to take subsets of.
+    pub pool: Vec<T>,
+}
+
+impl<T: Clone> PowersetInput<T> {
+    /// Return the adaptor under test.
+    pub fn iter(&self) -> Powerset<alloc::vec::IntoIter<T>> {
+        self.pool.clone().into_iter().powerset()
+    }
+
+    /// Return the subsets `iter` should yield, in order.
+    pub fn expected(&self) -> Vec<Vec<T>> {
+        (0..=self.pool.len())
+            .flat_map(|k| select(&self.pool, k, true))
+            .collect()
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for PowersetInput<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(PowersetInput { pool: arbitrary_pool(u, MAX_COMBINATIONS_POOL)? })
+    }
+}
@@ -0,0 +1,190 @@
+//! Adaptors that produce arrays of a const-generic size.
+
+use std::iter::{Fuse, FusedIterator};
+use std::mem;
+
+use crate::size_hint;
+
+fn empty_buffer<T, const N: usize>() -> [Option<T>; N] {
+    [(); N].map(|_| None)
+}
+
+/// Take the next `N` elements of `iter` as an array, or `None` if there
+/// are fewer than `N` left; those are then left at the front of `buf`.
+fn fill_array<I, const N: usize>(iter: &mut I, buf: &mut [Option<I::Item>; N])
+    -> Option<[I::Item; N]>
+    where I: Iterator,
+{
+    for slot in buf.iter_mut() {
+        *slot = Some(iter.next()?);
+    }
+    Some(mem::replace(buf, empty_buffer()).map(|elt| elt.unwrap()))
+}
+
+/// An iterator over all contiguous windows that produces arrays of a
+/// specific size.
+///
+/// See [`.array_windows()`](crate::Itertools::array_windows) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct ArrayWindows<I, const N: usize>
+    where I: Iterator,
+{
+    iter: Fuse<I>,
+    // the previous window, `None` until the first one is filled
+    window: Option<[I::Item; N]>,
+}
+
+/// Create a new `ArrayWindows` iterator.
+pub fn array_windows<I, const N: usize>(iter: I) -> ArrayWindows<I, N>
+    where I: Iterator,
+          I::Item: Clone,
+{
+    assert!(N != 0, "array_windows: window size must be non-zero");
+    ArrayWindows {
+        iter: iter.fuse(),
+        window: None,
+    }
+}
+
+impl<I, const N: usize> Iterator for ArrayWindows<I, N>
+    where I: Iterator,
+          I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.window {
+            Some(ref mut window) => {
+                let elt = self.iter.next()?;
+                window.rotate_left(1);
+                window[N - 1] = elt;
+                Some(window.clone())
+            }
+            None => {
+                self.window = fill_array(&mut self.iter, &mut empty_buffer());
+                self.window.clone()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let sh = self.iter.size_hint();
+        if self.window.is_some() {
+            sh
+        } else {
+            size_hint::sub_scalar(sh, N - 1)
+        }
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for ArrayWindows<I, N>
+    where I: ExactSizeIterator,
+          I::Item: Clone,
+{}
+
+impl<I, const N: usize> FusedIterator for ArrayWindows<I, N>
+    where I: Iterator,
+          I::Item: Clone,
+{}
+
+/// An iterator that groups the items in arrays of a specific size.
+///
+/// See [`.arrays()`](crate::Itertools::arrays) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct ArrayChunks<I, const N: usize>
+    where I: Iterator,
+{
+    iter: Fuse<I>,
+    buf: [Option<I::Item>; N],
+}
+
+/// Create a new `ArrayChunks` iterator.
+pub fn arrays<I, const N: usize>(iter: I) -> ArrayChunks<I, N>
+    where I: Iterator,
+{
+    assert!(N != 0, "arrays: chunk size must be non-zero");
+    ArrayChunks {
+        iter: iter.fuse(),
+        buf: empty_buffer(),
+    }
+}
+
+impl<I, const N: usize> Iterator for ArrayChunks<I, N>
+    where I: Iterator,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        fill_array(&mut self.iter, &mut self.buf)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = self.iter.size_hint();
+        (low / N, hi.map(|hi| hi / N))
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for ArrayChunks<I, N>
+    where I: ExactSizeIterator,
+{}
+
+impl<I, const N: usize> FusedIterator for ArrayChunks<I, N>
+    where I: Iterator,
+{}
+
+impl<I, const N: usize> ArrayChunks<I, N>
+    where I: Iterator,
+{
+    /// Return an iterator over the items that were not enough to fill a
+    /// last chunk.
+    ///
+    /// The remainder is only complete once the adaptor has returned `None`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut iter = (0..5).arrays::<3>();
+    /// assert_eq!(Some([0, 1, 2]), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// itertools::assert_equal(vec![3, 4], iter.into_remainder());
+    /// ```
+    pub fn into_remainder(self) -> ArrayChunksRemainder<I::Item, N> {
+        ArrayChunksRemainder {
+            cur: 0,
+            buf: self.buf,
+        }
+    }
+}
+
+/// An iterator over the items left over by an [`ArrayChunks`] iterator.
+///
+/// See [`ArrayChunks::into_remainder()`].
+#[derive(Clone, Debug)]
+pub struct ArrayChunksRemainder<T, const N: usize> {
+    cur: usize,
+    buf: [Option<T>; N],
+}
+
+impl<T, const N: usize> Iterator for ArrayChunksRemainder<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.buf.get_mut(self.cur)?.take();
+        self.cur += 1;
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffer = &self.buf[self.cur.min(N)..];
+        let len = buffer.iter()
+                        .position(|x| x.is_none())
+                        .unwrap_or(buffer.len());
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArrayChunksRemainder<T, N> {}
@@ -0,0 +1,61 @@
+use alloc::vec::Vec;
+use std::iter::{Fuse, FusedIterator};
+
+/// An iterator adaptor that collects elements into batches for as long as a
+/// predicate over the current batch allows.
+///
+/// Iterator element type is `Vec<I::Item>`.
+///
+/// See [`.batch_while()`](crate::Itertools::batch_while) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct BatchWhile<I, F> {
+    iter: Fuse<I>,
+    pred: F,
+    // the length of the largest batch so far, used to size the next one
+    capacity: usize,
+}
+
+/// Create a new `BatchWhile` iterator.
+pub fn batch_while<I, F>(iter: I, pred: F) -> BatchWhile<I, F>
+    where I: Iterator,
+          F: FnMut(&[I::Item]) -> bool,
+{
+    BatchWhile {
+        iter: iter.fuse(),
+        pred,
+        capacity: 1,
+    }
+}
+
+impl<I, F> Iterator for BatchWhile<I, F>
+    where I: Iterator,
+          F: FnMut(&[I::Item]) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let mut batch = Vec::with_capacity(self.capacity);
+        batch.push(first);
+        while (self.pred)(&batch) {
+            match self.iter.next() {
+                Some(elt) => batch.push(elt),
+                None => break,
+            }
+        }
+        self.capacity = self.capacity.max(batch.len());
+        Some(batch)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = self.iter.size_hint();
+        ((low > 0) as usize, hi)
+    }
+}
+
+impl<I, F> FusedIterator for BatchWhile<I, F>
+    where I: Iterator,
+          F: FnMut(&[I::Item]) -> bool,
+{}
@@ -0,0 +1,82 @@
+use alloc::vec::Vec;
+use std::fmt;
+use std::iter::{Fuse, FusedIterator};
+
+/// An iterator adaptor that groups runs of consecutive elements where every
+/// pair of neighbors satisfies a predicate.
+///
+/// Iterator element type is `Vec<I::Item>`.
+///
+/// See [`.chunk_while()`](crate::Itertools::chunk_while) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ChunkWhile<I, F>
+    where I: Iterator,
+{
+    iter: Fuse<I>,
+    pred: F,
+    // the first element of the next chunk
+    pending: Option<I::Item>,
+}
+
+impl<I, F> fmt::Debug for ChunkWhile<I, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(ChunkWhile, iter, pending);
+}
+
+impl<I, F> Clone for ChunkWhile<I, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          F: Clone,
+{
+    clone_fields!(iter, pred, pending);
+}
+
+/// Create a new `ChunkWhile` iterator.
+pub fn chunk_while<I, F>(iter: I, pred: F) -> ChunkWhile<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    ChunkWhile {
+        iter: iter.fuse(),
+        pred,
+        pending: None,
+    }
+}
+
+impl<I, F> Iterator for ChunkWhile<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.pending.take() {
+            Some(elt) => elt,
+            None => self.iter.next()?,
+        };
+        let mut chunk = Vec::new();
+        chunk.push(first);
+        for elt in &mut self.iter {
+            if !(self.pred)(chunk.last().unwrap(), &elt) {
+                self.pending = Some(elt);
+                break;
+            }
+            chunk.push(elt);
+        }
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = self.iter.size_hint();
+        let pending = self.pending.is_some() as usize;
+        ((low > 0 || pending > 0) as usize, hi.and_then(|hi| hi.checked_add(pending)))
+    }
+}
+
+impl<I, F> FusedIterator for ChunkWhile<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item, &I::Item) -> bool,
+{}
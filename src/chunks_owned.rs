@@ -0,0 +1,56 @@
+use alloc::vec::Vec;
+use std::iter::{Fuse, FusedIterator};
+
+/// An iterator adaptor that collects the elements of the adapted iterator
+/// into owned chunks of a fixed size.
+///
+/// Iterator element type is `Vec<I::Item>`.
+///
+/// See [`.into_chunks_owned()`](crate::Itertools::into_chunks_owned) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct ChunksOwned<I> {
+    iter: Fuse<I>,
+    size: usize,
+}
+
+/// Create a new `ChunksOwned` iterator.
+pub fn chunks_owned<I>(iter: I, size: usize) -> ChunksOwned<I>
+    where I: Iterator,
+{
+    assert!(size != 0, "chunk size must be non-zero");
+    ChunksOwned {
+        iter: iter.fuse(),
+        size,
+    }
+}
+
+impl<I> Iterator for ChunksOwned<I>
+    where I: Iterator,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let mut chunk = Vec::with_capacity(self.size);
+        chunk.push(first);
+        chunk.extend(self.iter.by_ref().take(self.size - 1));
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // every chunk but the last is full
+        let chunks = |n: usize| if n == 0 { 0 } else { (n - 1) / self.size + 1 };
+        let (low, hi) = self.iter.size_hint();
+        (chunks(low), hi.map(chunks))
+    }
+}
+
+impl<I> ExactSizeIterator for ChunksOwned<I>
+    where I: ExactSizeIterator,
+{}
+
+impl<I> FusedIterator for ChunksOwned<I>
+    where I: Iterator,
+{}
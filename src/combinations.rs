@@ -1,31 +1,46 @@
+use std::convert::TryFrom;
 use std::fmt;
 use std::iter::FusedIterator;
 
 use super::lazy_buffer::LazyBuffer;
+use super::size_hint;
 use alloc::vec::Vec;
 
 /// An iterator to iterate through all the `k`-length combinations in an iterator.
 ///
 /// See [`.combinations()`](crate::Itertools::combinations) for more information.
+///
+/// # Size hint
+///
+/// The size hint counts the combinations left from the bounds on the length
+/// of the source iterator. A bound that does not fit in a `usize` saturates:
+/// the lower bound becomes `usize::MAX` and the upper bound `None`, as if the
+/// iterator were unbounded. [`exact_count`](Combinations::exact_count) returns
+/// the exact number as a `u128` instead. The other combinatorial adaptors,
+/// [`CombinationsWithReplacement`](crate::structs::CombinationsWithReplacement),
+/// [`Permutations`](crate::structs::Permutations) and
+/// [`Powerset`](crate::structs::Powerset), follow the same policy.
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct Combinations<I: Iterator> {
     indices: Vec<usize>,
     pool: LazyBuffer<I>,
     first: bool,
+    // the number of combinations yielded since the last reset
+    rank: usize,
 }
 
 impl<I> Clone for Combinations<I>
     where I: Clone + Iterator,
           I::Item: Clone,
 {
-    clone_fields!(indices, pool, first);
+    clone_fields!(indices, pool, first, rank);
 }
 
 impl<I> fmt::Debug for Combinations<I>
     where I: Iterator + fmt::Debug,
           I::Item: fmt::Debug,
 {
-    debug_fmt_fields!(Combinations, indices, pool, first);
+    debug_fmt_fields!(Combinations, indices, pool, first, rank);
 }
 
 /// Create a new `Combinations` from a clonable iterator.
@@ -39,6 +54,7 @@ pub fn combinations<I>(iter: I, k: usize) -> Combinations<I>
         indices: (0..k).collect(),
         pool,
         first: true,
+        rank: 0,
     }
 }
 
@@ -52,6 +68,48 @@ impl<I: Iterator> Combinations<I> {
     #[inline]
     pub fn n(&self) -> usize { self.pool.len() }
 
+    /// Returns the number of combinations yielded so far, which is also the
+    /// rank of the next one in lexicographic order of indices.
+    #[inline]
+    pub fn rank(&self) -> usize { self.rank }
+
+    /// Consume the iterator and return the number of combinations it would
+    /// still yield, without building them.
+    ///
+    /// Unlike `.count()`, this does not overflow `usize`: the count saturates
+    /// at `u128::MAX` instead. The source iterator is run to completion to
+    /// find its length, unless its size hint reports it as unbounded, like
+    /// `0..` does; then the count is infinite if `k` is not 0, and `None` is
+    /// returned.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut combs = (0..5).combinations(2);
+    /// combs.next();
+    /// assert_eq!(combs.exact_count(), Some(9));
+    ///
+    /// // 120 choose 60, which overflows a `u64`
+    /// let combs = (0..120).combinations(60);
+    /// assert_eq!(combs.size_hint(), (usize::MAX, None));
+    /// assert_eq!(combs.exact_count(), Some(96614908840363322603893139521372656));
+    ///
+    /// assert_eq!((0..1000).combinations(500).exact_count(), Some(u128::MAX));
+    /// assert_eq!((0..).combinations(2).exact_count(), None);
+    /// ```
+    pub fn exact_count(self) -> Option<u128> {
+        let k = self.k();
+        let rank = self.rank as u128;
+        if size_hint::is_unbounded(self.pool.size_hint()) {
+            // only the empty combination is finite in number
+            return if k == 0 { Some(1 - rank) } else { None };
+        }
+        Some(binomial(self.pool.total_len(), k).map_or(u128::MAX, |total| total - rank))
+    }
+
+    /// Returns the buffer of elements from the source iterator.
+    pub(crate) fn into_pool(self) -> LazyBuffer<I> { self.pool }
+
     /// Returns a reference to the source iterator.
     #[inline]
     pub(crate) fn src(&self) -> &I { &self.pool.it }
@@ -62,6 +120,7 @@ impl<I: Iterator> Combinations<I> {
     /// elements.
     pub(crate) fn reset(&mut self, k: usize) {
         self.first = true;
+        self.rank = 0;
 
         if k < self.indices.len() {
             self.indices.truncate(k);
@@ -85,13 +144,55 @@ impl<I> Iterator for Combinations<I>
 {
     type Item = Vec<I::Item>;
     fn next(&mut self) -> Option<Self::Item> {
+        if !self.step() {
+            return None;
+        }
+
+        // Create result vector based on the indices
+        Some(self.indices.iter().map(|i| self.pool[*i].clone()).collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The remaining count, from the bounds on the pool length
+        let remaining = |n| binomial(n, self.k()).map(|total| total - self.rank as u128);
+        let (low, hi) = self.pool.size_hint();
+        let low = remaining(low).map_or(usize::MAX, |low| usize::try_from(low).unwrap_or(usize::MAX));
+        let hi = hi.and_then(remaining).and_then(|hi| usize::try_from(hi).ok());
+        (low, hi)
+    }
+}
+
+impl<I: Iterator> Combinations<I> {
+    /// Skip ahead until [`rank`](Combinations::rank) is `rank`, without
+    /// building the skipped combinations. If there are fewer combinations,
+    /// the iterator is exhausted.
+    ///
+    /// **Panics** if `rank` is less than the current rank.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut it = (0..5).combinations(2);
+    /// it.advance_to_rank(7);
+    /// assert_eq!(it.rank(), 7);
+    /// assert_eq!(it.next(), Some(vec![2, 3]));
+    /// assert_eq!(it.rank(), 8);
+    /// ```
+    pub fn advance_to_rank(&mut self, rank: usize) {
+        assert!(rank >= self.rank, "advance_to_rank: cannot go back from rank {} to {}", self.rank, rank);
+        while self.rank < rank && self.step() {}
+    }
+
+    /// Move the indices to the next combination, and return whether there
+    /// is one.
+    fn step(&mut self) -> bool {
         if self.first {
             if self.k() > self.n() {
-                return None;
+                return false;
             }
             self.first = false;
         } else if self.indices.is_empty() {
-            return None;
+            return false;
         } else {
             // Scan from the end, looking for an index to increment
             let mut i: usize = self.indices.len() - 1;
@@ -106,7 +207,7 @@ impl<I> Iterator for Combinations<I>
                     i -= 1;
                 } else {
                     // Reached the last combination
-                    return None;
+                    return false;
                 }
             }
 
@@ -116,10 +217,35 @@ impl<I> Iterator for Combinations<I>
                 self.indices[j] = self.indices[j - 1] + 1;
             }
         }
+        self.rank += 1;
+        true
+    }
+}
 
-        // Create result vector based on the indices
-        Some(self.indices.iter().map(|i| self.pool[*i].clone()).collect())
+/// Returns the number of `k`-element subsets of a set of `n` elements, or
+/// `None` if it does not fit in a `u128`.
+pub(crate) fn binomial(n: usize, k: usize) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut count: u128 = 1;
+    for i in 0..k as u128 {
+        // `count` is `binomial(n, i)`, so `count * (n - i)` is divisible by
+        // `i + 1`; dividing first keeps the product exact.
+        let g = gcd(count, i + 1);
+        count = (count / g).checked_mul((n as u128 - i) / ((i + 1) / g))?;
+    }
+    Some(count)
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
     }
+    a
 }
 
 impl<I> FusedIterator for Combinations<I>
@@ -1,13 +1,21 @@
 use alloc::vec::Vec;
+use std::convert::TryFrom;
 use std::fmt;
 use std::iter::FusedIterator;
 
+use super::combinations::binomial;
 use super::lazy_buffer::LazyBuffer;
+use super::size_hint;
 
 /// An iterator to iterate through all the `n`-length combinations in an iterator, with replacement.
 ///
 /// See [`.combinations_with_replacement()`](crate::Itertools::combinations_with_replacement)
 /// for more information.
+///
+/// Its size hint saturates at `usize::MAX` like that of
+/// [`Combinations`](crate::structs::Combinations); use
+/// [`exact_count`](CombinationsWithReplacement::exact_count) for counts that
+/// overflow a `usize`.
 #[derive(Clone)]
 pub struct CombinationsWithReplacement<I>
 where
@@ -17,6 +25,8 @@ where
     indices: Vec<usize>,
     pool: LazyBuffer<I>,
     first: bool,
+    // the number of combinations yielded so far
+    rank: usize,
 }
 
 impl<I> fmt::Debug for CombinationsWithReplacement<I>
@@ -24,7 +34,7 @@ where
     I: Iterator + fmt::Debug,
     I::Item: fmt::Debug + Clone,
 {
-    debug_fmt_fields!(Combinations, indices, pool, first);
+    debug_fmt_fields!(Combinations, indices, pool, first, rank);
 }
 
 impl<I> CombinationsWithReplacement<I>
@@ -36,6 +46,47 @@ where
     fn current(&self) -> Vec<I::Item> {
         self.indices.iter().map(|i| self.pool[*i].clone()).collect()
     }
+
+    /// Consume the iterator and return the number of combinations it would
+    /// still yield, without building them.
+    ///
+    /// Unlike `.count()`, this does not overflow `usize`: the count saturates
+    /// at `u128::MAX` instead. The source iterator is run to completion to
+    /// find its length, unless its size hint reports it as unbounded, like
+    /// `0..` does; then the count is infinite if `k` is not 0, and `None` is
+    /// returned.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut combs = (0..4).combinations_with_replacement(2);
+    /// combs.next();
+    /// assert_eq!(combs.exact_count(), Some(9));
+    ///
+    /// assert_eq!((0..100).combinations_with_replacement(30).exact_count(),
+    ///            Some(200949104054221844315257489600));
+    /// assert_eq!((0..1000).combinations_with_replacement(500).exact_count(), Some(u128::MAX));
+    /// assert_eq!((0..).combinations_with_replacement(2).exact_count(), None);
+    /// ```
+    pub fn exact_count(self) -> Option<u128> {
+        let k = self.indices.len();
+        let rank = self.rank as u128;
+        if size_hint::is_unbounded(self.pool.size_hint()) {
+            // only the empty combination is finite in number
+            return if k == 0 { Some(1 - rank) } else { None };
+        }
+        Some(remaining(self.pool.total_len(), k, self.rank).unwrap_or(u128::MAX))
+    }
+}
+
+/// The number of combinations with replacement of `k` out of `n` elements
+/// after the first `rank` ones, or `None` if it does not fit in a `u128`.
+fn remaining(n: usize, k: usize, rank: usize) -> Option<u128> {
+    let total = match n {
+        0 => (k == 0) as u128,
+        n => binomial(n.checked_add(k)? - 1, k)?,
+    };
+    Some(total - rank as u128)
 }
 
 /// Create a new `CombinationsWithReplacement` from a clonable iterator.
@@ -51,6 +102,7 @@ where
         indices,
         pool,
         first: true,
+        rank: 0,
     }
 }
 
@@ -69,6 +121,7 @@ where
             // Otherwise, yield the initial state
             } else {
                 self.first = false;
+                self.rank += 1;
                 Some(self.current())
             };
         }
@@ -94,12 +147,22 @@ where
                 for indices_index in increment_from..self.indices.len() {
                     self.indices[indices_index] = increment_value
                 }
+                self.rank += 1;
                 Some(self.current())
             }
             // Otherwise, we're done
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The remaining count, from the bounds on the pool length
+        let k = self.indices.len();
+        let (low, hi) = self.pool.size_hint();
+        let low = remaining(low, k, self.rank).map_or(usize::MAX, |low| usize::try_from(low).unwrap_or(usize::MAX));
+        let hi = hi.and_then(|hi| remaining(hi, k, self.rank)).and_then(|hi| usize::try_from(hi).ok());
+        (low, hi)
+    }
 }
 
 impl<I> FusedIterator for CombinationsWithReplacement<I>
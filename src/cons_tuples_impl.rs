@@ -28,7 +28,7 @@ macro_rules! impl_cons_iter(
             where Iter: DoubleEndedIterator<Item = (($($B,)*), X)>,
         {
             fn next_back(&mut self) -> Option<Self::Item> {
-                self.iter.next().map(|(($($B,)*), x)| ($($B,)* x, ))
+                self.iter.next_back().map(|(($($B,)*), x)| ($($B,)* x, ))
             }
         }
 
@@ -0,0 +1,124 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Add;
+
+/// An iterator adaptor that yields the running totals of the adapted
+/// iterator, combining elements with a custom function.
+///
+/// See [`.cumsum_by()`](crate::Itertools::cumsum_by) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct CumSumBy<I, F>
+    where I: Iterator,
+{
+    iter: I,
+    total: Option<I::Item>,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for CumSumBy<I, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(CumSumBy, iter, total);
+}
+
+impl<I, F> Clone for CumSumBy<I, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          F: Clone,
+{
+    clone_fields!(iter, total, f);
+}
+
+pub trait CumSumFn<T> {
+    fn add(&mut self, total: T, elt: T) -> T;
+}
+
+impl<T, F: FnMut(T, T) -> T> CumSumFn<T> for F {
+    fn add(&mut self, total: T, elt: T) -> T {
+        self(total, elt)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CumSumFnAdd;
+
+impl<T: Add<Output = T>> CumSumFn<T> for CumSumFnAdd {
+    fn add(&mut self, total: T, elt: T) -> T {
+        total + elt
+    }
+}
+
+/// An iterator adaptor that yields the running totals of the adapted
+/// iterator.
+///
+/// See [`.cumsum()`](crate::Itertools::cumsum) for more information.
+pub type CumSum<I> = CumSumBy<I, CumSumFnAdd>;
+
+/// Create a new `CumSumBy` iterator.
+pub fn cumsum_by<I, F>(iter: I, f: F) -> CumSumBy<I, F>
+    where I: Iterator,
+          I::Item: Clone,
+          F: CumSumFn<I::Item>,
+{
+    CumSumBy {
+        iter,
+        total: None,
+        f,
+    }
+}
+
+/// Create a new `CumSum` iterator.
+pub fn cumsum<I>(iter: I) -> CumSum<I>
+    where I: Iterator,
+          I::Item: Clone + Add<Output = I::Item>,
+{
+    cumsum_by(iter, CumSumFnAdd)
+}
+
+impl<I, F> Iterator for CumSumBy<I, F>
+    where I: Iterator,
+          I::Item: Clone,
+          F: CumSumFn<I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let elt = self.iter.next()?;
+        let total = match self.total.take() {
+            Some(total) => self.f.add(total, elt),
+            None => elt,
+        };
+        self.total = Some(total.clone());
+        Some(total)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+        where G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold((self.total, init), |(total, acc), elt| {
+            let total = match total {
+                Some(total) => f.add(total, elt),
+                None => elt,
+            };
+            (Some(total.clone()), g(acc, total))
+        }).1
+    }
+}
+
+impl<I, F> ExactSizeIterator for CumSumBy<I, F>
+    where I: ExactSizeIterator,
+          I::Item: Clone,
+          F: CumSumFn<I::Item>,
+{}
+
+impl<I, F> FusedIterator for CumSumBy<I, F>
+    where I: FusedIterator,
+          I::Item: Clone,
+          F: CumSumFn<I::Item>,
+{}
@@ -0,0 +1,140 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Sub;
+
+use crate::size_hint;
+
+/// An iterator adaptor that applies a function to every pair of consecutive
+/// elements of the adapted iterator.
+///
+/// See [`.diff_by()`](crate::Itertools::diff_by) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct DiffBy<I, F>
+    where I: Iterator,
+{
+    iter: I,
+    prev: Option<I::Item>,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for DiffBy<I, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(DiffBy, iter, prev);
+}
+
+impl<I, F> Clone for DiffBy<I, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          F: Clone,
+{
+    clone_fields!(iter, prev, f);
+}
+
+pub trait DiffFn<T> {
+    type Out;
+    // `prev` is not needed afterwards, so it's passed by value
+    fn diff(&mut self, prev: T, next: &T) -> Self::Out;
+}
+
+impl<T, U, F: FnMut(&T, &T) -> U> DiffFn<T> for F {
+    type Out = U;
+    fn diff(&mut self, prev: T, next: &T) -> U {
+        self(&prev, next)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DiffFnSub;
+
+impl<T: Clone + Sub<Output = U>, U> DiffFn<T> for DiffFnSub {
+    type Out = U;
+    fn diff(&mut self, prev: T, next: &T) -> U {
+        next.clone() - prev
+    }
+}
+
+/// An iterator adaptor that yields the differences between consecutive
+/// elements of the adapted iterator.
+///
+/// See [`.deltas()`](crate::Itertools::deltas) for more information.
+pub type Deltas<I> = DiffBy<I, DiffFnSub>;
+
+/// Create a new `DiffBy` iterator.
+pub fn diff_by<I, F>(iter: I, f: F) -> DiffBy<I, F>
+    where I: Iterator,
+          F: DiffFn<I::Item>,
+{
+    DiffBy {
+        iter,
+        prev: None,
+        f,
+    }
+}
+
+/// Create a new `Deltas` iterator.
+pub fn deltas<I>(iter: I) -> Deltas<I>
+    where I: Iterator,
+          I::Item: Clone + Sub,
+{
+    diff_by(iter, DiffFnSub)
+}
+
+impl<I, F> Iterator for DiffBy<I, F>
+    where I: Iterator,
+          F: DiffFn<I::Item>,
+{
+    type Item = F::Out;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let prev = match self.prev.take() {
+            Some(prev) => prev,
+            None => self.iter.next()?,
+        };
+        let next = match self.iter.next() {
+            Some(next) => next,
+            None => {
+                self.prev = Some(prev);
+                return None;
+            }
+        };
+        let diff = self.f.diff(prev, &next);
+        self.prev = Some(next);
+        Some(diff)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let sh = self.iter.size_hint();
+        if self.prev.is_some() {
+            sh
+        } else {
+            size_hint::sub_scalar(sh, 1)
+        }
+    }
+
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+        where G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut iter = self.iter;
+        let prev = match self.prev.or_else(|| iter.next()) {
+            Some(prev) => prev,
+            None => return init,
+        };
+        let mut f = self.f;
+        iter.fold((prev, init), |(prev, acc), next| {
+            let acc = g(acc, f.diff(prev, &next));
+            (next, acc)
+        }).1
+    }
+}
+
+impl<I, F> ExactSizeIterator for DiffBy<I, F>
+    where I: ExactSizeIterator,
+          F: DiffFn<I::Item>,
+{}
+
+impl<I, F> FusedIterator for DiffBy<I, F>
+    where I: FusedIterator,
+          F: DiffFn<I::Item>,
+{}
@@ -0,0 +1,133 @@
+use alloc::vec::Vec;
+use std::fmt;
+use std::iter::{Fuse, FusedIterator};
+
+use crate::size_hint;
+
+/// An iterator adaptor that iterates over the cartesian product of two
+/// iterators in diagonal order.
+///
+/// Iterator element type is `(I::Item, J::Item)`.
+///
+/// See [`.cartesian_product_diagonal()`](crate::Itertools::cartesian_product_diagonal)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct DiagonalProduct<I, J>
+    where I: Iterator,
+          J: Iterator,
+{
+    a: Fuse<I>,
+    b: Fuse<J>,
+    a_buf: Vec<I::Item>,
+    b_buf: Vec<J::Item>,
+    // the lengths of `a` and `b`, once they are exhausted
+    a_len: Option<usize>,
+    b_len: Option<usize>,
+    // the next pair is `(a_buf[i], b_buf[diagonal - i])`
+    diagonal: usize,
+    i: usize,
+    yielded: usize,
+}
+
+impl<I, J> fmt::Debug for DiagonalProduct<I, J>
+    where I: Iterator + fmt::Debug,
+          J: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+          J::Item: fmt::Debug,
+{
+    debug_fmt_fields!(DiagonalProduct, a, b, a_buf, b_buf, diagonal, i);
+}
+
+impl<I, J> Clone for DiagonalProduct<I, J>
+    where I: Iterator + Clone,
+          J: Iterator + Clone,
+          I::Item: Clone,
+          J::Item: Clone,
+{
+    clone_fields!(a, b, a_buf, b_buf, a_len, b_len, diagonal, i, yielded);
+}
+
+/// Create a new `DiagonalProduct` iterator.
+pub fn diagonal_product<I, J>(a: I, b: J) -> DiagonalProduct<I, J>
+    where I: Iterator,
+          J: Iterator,
+{
+    DiagonalProduct {
+        a: a.fuse(),
+        b: b.fuse(),
+        a_buf: Vec::new(),
+        b_buf: Vec::new(),
+        a_len: None,
+        b_len: None,
+        diagonal: 0,
+        i: 0,
+        yielded: 0,
+    }
+}
+
+/// Read from `iter` until `buf[index]` exists, and return whether it does;
+/// if `iter` runs out first, its length is stored in `len`.
+fn fill<I>(iter: &mut I, buf: &mut Vec<I::Item>, len: &mut Option<usize>, index: usize) -> bool
+    where I: Iterator,
+{
+    while buf.len() <= index && len.is_none() {
+        match iter.next() {
+            Some(elt) => buf.push(elt),
+            None => *len = Some(buf.len()),
+        }
+    }
+    index < buf.len()
+}
+
+impl<I, J> Iterator for DiagonalProduct<I, J>
+    where I: Iterator,
+          J: Iterator,
+          I::Item: Clone,
+          J::Item: Clone,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.i > self.diagonal {
+                self.diagonal += 1;
+                self.i = 0;
+            }
+            match (self.a_len, self.b_len) {
+                (Some(0), _) | (_, Some(0)) => return None,
+                // the last diagonal is `a_len - 1 + b_len - 1`
+                (Some(a_len), Some(b_len)) if self.diagonal + 2 > a_len + b_len => return None,
+                _ => {}
+            }
+            if !fill(&mut self.a, &mut self.a_buf, &mut self.a_len, self.i) {
+                // the rest of this diagonal is past the end of `a`
+                self.i = self.diagonal + 1;
+                continue;
+            }
+            let j = self.diagonal - self.i;
+            if !fill(&mut self.b, &mut self.b_buf, &mut self.b_len, j) {
+                // skip to the first pair within `b`
+                self.i = self.diagonal + 1 - self.b_buf.len();
+                continue;
+            }
+            let pair = (self.a_buf[self.i].clone(), self.b_buf[j].clone());
+            self.i += 1;
+            self.yielded += 1;
+            return Some(pair);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // every pair is yielded exactly once
+        let a = size_hint::add_scalar(self.a.size_hint(), self.a_buf.len());
+        let b = size_hint::add_scalar(self.b.size_hint(), self.b_buf.len());
+        size_hint::sub_scalar(size_hint::mul(a, b), self.yielded)
+    }
+}
+
+impl<I, J> FusedIterator for DiagonalProduct<I, J>
+    where I: Iterator,
+          J: Iterator,
+          I::Item: Clone,
+          J::Item: Clone,
+{}
@@ -5,6 +5,8 @@
 //! describes the difference between two non-`Clone` iterators `I` and `J` after breaking ASAP from
 //! a lock-step comparison.
 
+use std::fmt;
+
 use crate::free::put_back;
 use crate::structs::PutBack;
 
@@ -25,6 +27,44 @@ pub enum Diff<I, J>
     Longer(usize, PutBack<J>),
 }
 
+impl<I, J> Diff<I, J>
+    where I: Iterator,
+          J: Iterator
+{
+    /// The index at which the iterators diverge: the index of the first mismatch, or the number
+    /// of elements of the shorter iterator.
+    pub fn index(&self) -> usize {
+        match *self {
+            Diff::FirstMismatch(idx, _, _) | Diff::Shorter(idx, _) | Diff::Longer(idx, _) => idx,
+        }
+    }
+
+    /// The elements of `I` and `J` at the divergence, `None` for an exhausted iterator.
+    pub fn elements(&self) -> (Option<&I::Item>, Option<&J::Item>) {
+        match self {
+            Diff::FirstMismatch(_, i, j) => (i.top(), j.top()),
+            Diff::Shorter(_, i) => (i.top(), None),
+            Diff::Longer(_, j) => (None, j.top()),
+        }
+    }
+}
+
+impl<I, J> fmt::Debug for Diff<I, J>
+    where I: Iterator + fmt::Debug,
+          J: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+          J::Item: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Diff::FirstMismatch(idx, i, j) =>
+                f.debug_tuple("FirstMismatch").field(idx).field(i).field(j).finish(),
+            Diff::Shorter(idx, i) => f.debug_tuple("Shorter").field(idx).field(i).finish(),
+            Diff::Longer(idx, j) => f.debug_tuple("Longer").field(idx).field(j).finish(),
+        }
+    }
+}
+
 /// Compares every element yielded by both `i` and `j` with the given function in lock-step and
 /// returns a [`Diff`] which describes how `j` differs from `i`.
 ///
@@ -37,6 +77,21 @@ pub enum Diff<I, J>
 ///
 /// If `i` becomes exhausted before `j` becomes exhausted, the number of elements in `i` along with
 /// the remaining `j` elements will be returned as `Diff::Longer`.
+///
+/// Unlike [`assert_equal`](crate::assert_equal), this does not panic, so the returned [`Diff`]
+/// can be used to build a report of the mismatch.
+///
+/// ```
+/// use itertools::diff_with;
+///
+/// let diff = diff_with(&[1, 2, 3], &[1, 5, 3], |a, b| a == b).unwrap();
+/// assert_eq!(diff.index(), 1);
+/// assert_eq!(diff.elements(), (Some(&&2), Some(&&5)));
+///
+/// let diff = diff_with(&[1, 2, 3], &[1, 2], |a, b| a == b).unwrap();
+/// assert_eq!(diff.index(), 2);
+/// assert_eq!(diff.elements(), (Some(&&3), None));
+/// ```
 pub fn diff_with<I, J, F>(i: I, j: J, is_equal: F)
     -> Option<Diff<I::IntoIter, J::IntoIter>>
     where I: IntoIterator,
@@ -0,0 +1,93 @@
+use std::iter::{FusedIterator, Rev};
+
+/// An iterator over the digits of an integer in a given base.
+///
+/// See [`digits()`](crate::digits) for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Digits {
+    // the digits that have not been yielded yet
+    n: u64,
+    base: u64,
+    len: usize,
+}
+
+/// Create an iterator over the digits of `n` in base `base`, least
+/// significant digit first.
+///
+/// Zero has a single digit. The iterator is double-ended, so
+/// `digits(n, base).rev()` yields the most significant digit first; see
+/// also [`digits_msb_first()`].
+///
+/// **Panics** if `base` is less than 2.
+///
+/// ```
+/// use itertools::digits;
+///
+/// itertools::assert_equal(digits(1234, 10), vec![4, 3, 2, 1]);
+/// itertools::assert_equal(digits(6, 2), vec![0, 1, 1]);
+/// itertools::assert_equal(digits(0, 16), vec![0]);
+/// ```
+pub fn digits(n: u64, base: u64) -> Digits {
+    assert!(base >= 2, "digits: base must be at least 2, got {}", base);
+    let mut len = 1;
+    let mut rest = n / base;
+    while rest > 0 {
+        len += 1;
+        rest /= base;
+    }
+    Digits { n, base, len }
+}
+
+/// Create an iterator over the digits of `n` in base `base`, most
+/// significant digit first.
+///
+/// This is `digits(n, base).rev()`.
+///
+/// **Panics** if `base` is less than 2.
+///
+/// ```
+/// use itertools::digits_msb_first;
+///
+/// itertools::assert_equal(digits_msb_first(1234, 10), vec![1, 2, 3, 4]);
+/// itertools::assert_equal(digits_msb_first(0xbeef, 16), vec![0xb, 0xe, 0xe, 0xf]);
+/// ```
+pub fn digits_msb_first(n: u64, base: u64) -> Rev<Digits> {
+    digits(n, base).rev()
+}
+
+impl Iterator for Digits {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let digit = self.n % self.base;
+        self.n /= self.base;
+        Some(digit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl DoubleEndedIterator for Digits {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // `n` has `len + 1` digits left, so this cannot overflow
+        let place = self.base.pow(self.len as u32);
+        let digit = self.n / place;
+        self.n %= place;
+        Some(digit)
+    }
+}
+
+impl ExactSizeIterator for Digits {}
+
+impl FusedIterator for Digits {}
@@ -1,18 +1,20 @@
-use std::hash::Hash;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash};
 
 mod private {
-    use std::collections::HashMap;
-    use std::hash::Hash;
+    use std::collections::{HashMap, HashSet};
+    use std::collections::hash_map::{Entry, RandomState};
+    use std::hash::{BuildHasher, Hash};
     use std::fmt;
 
     #[derive(Clone)]
     #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
-    pub struct DuplicatesBy<I: Iterator, Key, F> {
+    pub struct DuplicatesBy<I: Iterator, Key, F, S = RandomState> {
         pub(crate) iter: I,
-        pub(crate) meta: Meta<Key, F>,
+        pub(crate) meta: Meta<Key, F, S>,
     }
 
-    impl<I, V, F> fmt::Debug for DuplicatesBy<I, V, F>
+    impl<I, V, F, S> fmt::Debug for DuplicatesBy<I, V, F, S>
     where
         I: Iterator + fmt::Debug,
         V: fmt::Debug + Hash + Eq,
@@ -20,12 +22,12 @@ mod private {
         debug_fmt_fields!(DuplicatesBy, iter, meta.used);
     }
 
-    impl<I: Iterator, Key: Eq + Hash, F> DuplicatesBy<I, Key, F> {
-        pub(crate) fn new(iter: I, key_method: F) -> Self {
+    impl<I: Iterator, Key: Eq + Hash, F, S: BuildHasher> DuplicatesBy<I, Key, F, S> {
+        pub(crate) fn new(iter: I, key_method: F, hash_builder: S) -> Self {
             DuplicatesBy {
                 iter,
                 meta: Meta {
-                    used: HashMap::new(),
+                    used: HashMap::with_hasher(hash_builder),
                     pending: 0,
                     key_method,
                 },
@@ -33,16 +35,69 @@ mod private {
         }
     }
 
+    impl<I: Iterator, Key: Eq + Hash, F, S: BuildHasher> DuplicatesBy<I, Key, F, S> {
+        /// Return the number of distinct keys seen so far.
+        pub fn seen_len(&self) -> usize {
+            self.meta.used.len()
+        }
+
+        /// Return the number of keys the seen map can hold without
+        /// reallocating.
+        pub fn seen_capacity(&self) -> usize {
+            self.meta.used.capacity()
+        }
+
+        /// Shrink the capacity of the seen map as much as possible.
+        pub fn shrink_seen_to_fit(&mut self) {
+            self.meta.used.shrink_to_fit();
+        }
+
+        /// Treat the keys in `seen` as seen once already, so that the next
+        /// element with one of those keys is a duplicate.
+        ///
+        /// ```
+        /// use itertools::Itertools;
+        ///
+        /// let mut batch = vec![1, 2, 2].into_iter().duplicates();
+        /// itertools::assert_equal(batch.by_ref(), vec![2]);
+        /// let (_, seen) = batch.into_inner();
+        ///
+        /// let next = vec![3, 1, 2].into_iter().duplicates().with_seen(seen);
+        /// itertools::assert_equal(next, vec![1, 2]);
+        /// ```
+        pub fn with_seen(mut self, seen: HashSet<Key, S>) -> Self {
+            for key in seen {
+                if let Entry::Vacant(entry) = self.meta.used.entry(key) {
+                    entry.insert(false);
+                    self.meta.pending += 1;
+                }
+            }
+            self
+        }
+
+        /// Return the adapted iterator and the set of keys seen so far,
+        /// whether or not they were duplicated.
+        pub fn into_inner(self) -> (I, HashSet<Key, S>)
+            where S: Clone,
+        {
+            let used = self.meta.used;
+            let mut seen = HashSet::with_capacity_and_hasher(used.len(), used.hasher().clone());
+            seen.extend(used.into_keys());
+            (self.iter, seen)
+        }
+    }
+
     #[derive(Clone)]
-    pub struct Meta<Key, F> {
-        used: HashMap<Key, bool>,
+    pub struct Meta<Key, F, S> {
+        used: HashMap<Key, bool, S>,
         pending: usize,
         key_method: F,
     }
 
-    impl<Key, F> Meta<Key, F>
+    impl<Key, F, S> Meta<Key, F, S>
     where
         Key: Eq + Hash,
+        S: BuildHasher,
     {
         /// Takes an item and returns it back to the caller if it's the second time we see it.
         /// Otherwise the item is consumed and None is returned
@@ -68,11 +123,12 @@ mod private {
         }
     }
 
-    impl<I, Key, F> Iterator for DuplicatesBy<I, Key, F>
+    impl<I, Key, F, S> Iterator for DuplicatesBy<I, Key, F, S>
     where
         I: Iterator,
         Key: Eq + Hash,
         F: KeyMethod<Key, I::Item>,
+        S: BuildHasher,
     {
         type Item = I::Item;
 
@@ -102,11 +158,12 @@ mod private {
         }
     }
 
-    impl<I, Key, F> DoubleEndedIterator for DuplicatesBy<I, Key, F>
+    impl<I, Key, F, S> DoubleEndedIterator for DuplicatesBy<I, Key, F, S>
     where
         I: DoubleEndedIterator,
         Key: Eq + Hash,
         F: KeyMethod<Key, I::Item>,
+        S: BuildHasher,
     {
         fn next_back(&mut self) -> Option<Self::Item> {
             let DuplicatesBy { iter, meta } = self;
@@ -189,7 +246,7 @@ mod private {
 ///
 /// See [`.duplicates_by()`](crate::Itertools::duplicates_by) for more information.
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
-pub type DuplicatesBy<I, V, F> = private::DuplicatesBy<I, V, private::ByFn<F>>;
+pub type DuplicatesBy<I, V, F, S = RandomState> = private::DuplicatesBy<I, V, private::ByFn<F>, S>;
 
 /// Create a new `DuplicatesBy` iterator.
 pub fn duplicates_by<I, Key, F>(iter: I, f: F) -> DuplicatesBy<I, Key, F>
@@ -198,13 +255,24 @@ where
     F: FnMut(&I::Item) -> Key,
     I: Iterator,
 {
-    DuplicatesBy::new(iter, private::ByFn(f))
+    duplicates_by_with_hasher(iter, f, RandomState::new())
+}
+
+/// Create a new `DuplicatesBy` iterator using `hash_builder` to hash the keys.
+pub fn duplicates_by_with_hasher<I, Key, F, S>(iter: I, f: F, hash_builder: S) -> DuplicatesBy<I, Key, F, S>
+where
+    Key: Eq + Hash,
+    F: FnMut(&I::Item) -> Key,
+    I: Iterator,
+    S: BuildHasher,
+{
+    DuplicatesBy::new(iter, private::ByFn(f), hash_builder)
 }
 
 /// An iterator adapter to filter out duplicate elements.
 ///
 /// See [`.duplicates()`](crate::Itertools::duplicates) for more information.
-pub type Duplicates<I> = private::DuplicatesBy<I, <I as Iterator>::Item, private::ById>;
+pub type Duplicates<I, S = RandomState> = private::DuplicatesBy<I, <I as Iterator>::Item, private::ById, S>;
 
 /// Create a new `Duplicates` iterator.
 pub fn duplicates<I>(iter: I) -> Duplicates<I>
@@ -212,6 +280,16 @@ where
     I: Iterator,
     I::Item: Eq + Hash,
 {
-    Duplicates::new(iter, private::ById)
+    duplicates_with_hasher(iter, RandomState::new())
+}
+
+/// Create a new `Duplicates` iterator using `hash_builder` to hash the elements.
+pub fn duplicates_with_hasher<I, S>(iter: I, hash_builder: S) -> Duplicates<I, S>
+where
+    I: Iterator,
+    I::Item: Eq + Hash,
+    S: BuildHasher,
+{
+    Duplicates::new(iter, private::ById, hash_builder)
 }
 
@@ -0,0 +1,169 @@
+use std::iter::{self, Chain, FusedIterator, Map, Once};
+use std::ops::RangeInclusive;
+
+use crate::adaptors::{cartesian_product, Product};
+
+/// A type with few enough values to iterate over all of them.
+///
+/// Implemented for `()`, `bool`, the 8 and 16 bit integers, and `Option`s,
+/// tuples of up to four elements and arrays of exhaustible types. The values
+/// are yielded in ascending order, where `None` comes before every `Some`
+/// and tuples and arrays are ordered lexicographically.
+///
+/// See [`all_values()`] for more information.
+pub trait Exhaust: Sized + Clone {
+    /// The iterator over all values of `Self`.
+    type Iter: Iterator<Item = Self> + Clone;
+
+    /// Return an iterator over all values of `Self`.
+    fn exhaust() -> Self::Iter;
+}
+
+/// Return an iterator over all values of `T`, in ascending order.
+///
+/// This is meant for brute-force checks over small input types, instead of
+/// hand-written nested loops.
+///
+/// ```
+/// use itertools::{all_values, Itertools};
+///
+/// itertools::assert_equal(all_values::<Option<bool>>(), vec![None, Some(false), Some(true)]);
+/// assert_eq!(all_values::<(bool, u8)>().count(), 512);
+///
+/// // De Morgan's laws
+/// assert!(all_values::<[bool; 2]>().all(|[a, b]| !(a && b) == (!a || !b)));
+/// ```
+pub fn all_values<T: Exhaust>() -> T::Iter {
+    T::exhaust()
+}
+
+impl Exhaust for () {
+    type Iter = Once<()>;
+
+    fn exhaust() -> Self::Iter {
+        iter::once(())
+    }
+}
+
+impl Exhaust for bool {
+    type Iter = std::array::IntoIter<bool, 2>;
+
+    fn exhaust() -> Self::Iter {
+        IntoIterator::into_iter([false, true])
+    }
+}
+
+macro_rules! impl_exhaust_int {
+    ($($T:ty),*) => {
+        $(
+            impl Exhaust for $T {
+                type Iter = RangeInclusive<$T>;
+
+                fn exhaust() -> Self::Iter {
+                    <$T>::MIN..=<$T>::MAX
+                }
+            }
+        )*
+    };
+}
+
+impl_exhaust_int!(u8, i8, u16, i16);
+
+impl<T: Exhaust> Exhaust for Option<T> {
+    type Iter = Chain<Once<Option<T>>, Map<T::Iter, fn(T) -> Option<T>>>;
+
+    fn exhaust() -> Self::Iter {
+        iter::once(None).chain(T::exhaust().map(Some as fn(T) -> Option<T>))
+    }
+}
+
+impl<A: Exhaust> Exhaust for (A,) {
+    type Iter = Map<A::Iter, fn(A) -> (A,)>;
+
+    fn exhaust() -> Self::Iter {
+        A::exhaust().map((|a| (a,)) as fn(A) -> (A,))
+    }
+}
+
+impl<A: Exhaust, B: Exhaust> Exhaust for (A, B) {
+    type Iter = Product<A::Iter, B::Iter>;
+
+    fn exhaust() -> Self::Iter {
+        cartesian_product(A::exhaust(), B::exhaust())
+    }
+}
+
+impl<A: Exhaust, B: Exhaust, C: Exhaust> Exhaust for (A, B, C) {
+    type Iter = Map<Product<<(A, B) as Exhaust>::Iter, C::Iter>, fn(((A, B), C)) -> (A, B, C)>;
+
+    fn exhaust() -> Self::Iter {
+        cartesian_product(<(A, B)>::exhaust(), C::exhaust())
+            .map((|((a, b), c)| (a, b, c)) as fn(_) -> _)
+    }
+}
+
+impl<A: Exhaust, B: Exhaust, C: Exhaust, D: Exhaust> Exhaust for (A, B, C, D) {
+    type Iter = Map<Product<<(A, B, C) as Exhaust>::Iter, D::Iter>, fn(((A, B, C), D)) -> (A, B, C, D)>;
+
+    fn exhaust() -> Self::Iter {
+        cartesian_product(<(A, B, C)>::exhaust(), D::exhaust())
+            .map((|((a, b, c), d)| (a, b, c, d)) as fn(_) -> _)
+    }
+}
+
+/// An iterator over all values of an array of exhaustible types.
+///
+/// See [`all_values()`] for more information.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ExhaustArray<T: Exhaust, const N: usize> {
+    // the iterator of each position, just past its value in `next`
+    iters: [T::Iter; N],
+    next: Option<[T; N]>,
+}
+
+impl<T: Exhaust, const N: usize> Exhaust for [T; N] {
+    type Iter = ExhaustArray<T, N>;
+
+    fn exhaust() -> Self::Iter {
+        let mut iters = [(); N].map(|_| T::exhaust());
+        let mut pos = 0;
+        let first = [(); N].map(|_| {
+            pos += 1;
+            iters[pos - 1].next()
+        });
+        let next = if first.iter().all(Option::is_some) {
+            Some(first.map(Option::unwrap))
+        } else {
+            None
+        };
+        ExhaustArray { iters, next }
+    }
+}
+
+impl<T: Exhaust, const N: usize> Iterator for ExhaustArray<T, N> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        let mut next = current.clone();
+        // count up like an odometer, the last position fastest
+        for pos in (0..N).rev() {
+            match self.iters[pos].next() {
+                Some(value) => {
+                    next[pos] = value;
+                    self.next = Some(next);
+                    break;
+                }
+                None => {
+                    self.iters[pos] = T::exhaust();
+                    // not empty, since it had a value before
+                    next[pos] = self.iters[pos].next().unwrap();
+                }
+            }
+        }
+        Some(current)
+    }
+}
+
+impl<T: Exhaust, const N: usize> FusedIterator for ExhaustArray<T, N> {}
@@ -14,6 +14,21 @@ pub struct FormatWith<'a, I, F> {
     inner: RefCell<Option<(I, F)>>,
 }
 
+/// Format the iterator elements that the closure writes lazily, separated by
+/// `sep`.
+///
+/// The format value can only be formatted once, after that the iterator is
+/// exhausted.
+///
+/// See [`.format_filter_with()`](crate::Itertools::format_filter_with) for
+/// more information.
+#[derive(Clone)]
+pub struct FormatFilterWith<'a, I, F> {
+    sep: &'a str,
+    /// FormatFilterWith uses interior mutability because Display::fmt takes &self.
+    inner: RefCell<Option<(I, F)>>,
+}
+
 /// Format all iterator elements lazily, separated by `sep`.
 ///
 /// The format value can only be formatted once, after that the iterator is
@@ -28,6 +43,40 @@ pub struct Format<'a, I> {
     inner: RefCell<Option<I>>,
 }
 
+/// Format all iterator elements lazily, separated by `sep` and enclosed in
+/// a prefix and a suffix.
+///
+/// The format value can only be formatted once, after that the iterator is
+/// exhausted.
+///
+/// See [`.format_wrapped()`](crate::Itertools::format_wrapped)
+/// for more information.
+#[derive(Clone)]
+pub struct FormatWrapped<'a, I> {
+    prefix: &'a str,
+    sep: &'a str,
+    suffix: &'a str,
+    empty: Option<&'a str>,
+    /// FormatWrapped uses interior mutability because Display::fmt takes &self.
+    inner: RefCell<Option<I>>,
+}
+
+/// Format at most a given number of iterator elements lazily, separated by
+/// `sep`, followed by a suffix made from the number of elements left out.
+///
+/// The format value can only be formatted once, after that the iterator is
+/// exhausted.
+///
+/// See [`.format_truncated()`](crate::Itertools::format_truncated)
+/// for more information.
+#[derive(Clone)]
+pub struct FormatTruncated<'a, I, F> {
+    sep: &'a str,
+    max: usize,
+    /// FormatTruncated uses interior mutability because Display::fmt takes &self.
+    inner: RefCell<Option<(I, F)>>,
+}
+
 pub fn new_format<I, F>(iter: I, separator: &str, f: F) -> FormatWith<'_, I, F>
     where I: Iterator,
           F: FnMut(I::Item, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result
@@ -70,6 +119,209 @@ impl<'a, I, F> fmt::Display for FormatWith<'a, I, F>
     }
 }
 
+pub fn new_format_filter<I, F>(iter: I, separator: &str, f: F) -> FormatFilterWith<'_, I, F>
+    where I: Iterator,
+          F: FnMut(I::Item, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result
+{
+    FormatFilterWith {
+        sep: separator,
+        inner: RefCell::new(Some((iter, f))),
+    }
+}
+
+impl<'a, I, F> fmt::Display for FormatFilterWith<'a, I, F>
+    where I: Iterator,
+          F: FnMut(I::Item, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (iter, mut format) = match self.inner.borrow_mut().take() {
+            Some(t) => t,
+            None => panic!("FormatFilterWith: was already formatted once"),
+        };
+
+        let mut started = false;
+        for elt in iter {
+            // the separator is written lazily, once the element writes something
+            let mut written = false;
+            format(elt, &mut |disp: &dyn fmt::Display| {
+                if !written {
+                    if started && !self.sep.is_empty() {
+                        f.write_str(self.sep)?;
+                    }
+                    started = true;
+                    written = true;
+                }
+                disp.fmt(f)
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Format all iterator elements lazily, separated by separators that depend
+/// on their position.
+///
+/// The format value can only be formatted once, after that the iterator is
+/// exhausted.
+///
+/// See [`.format_with_indexed()`](crate::Itertools::format_with_indexed) for
+/// more information.
+#[derive(Clone)]
+pub struct FormatWithIndexed<I, S, F> {
+    /// FormatWithIndexed uses interior mutability because Display::fmt takes &self.
+    inner: RefCell<Option<(I, S, F)>>,
+}
+
+pub fn new_format_indexed<I, S, F, D>(iter: I, sep: S, f: F) -> FormatWithIndexed<I, S, F>
+    where I: Iterator,
+          S: FnMut(usize, bool) -> D,
+          D: fmt::Display,
+          F: FnMut(I::Item, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result
+{
+    FormatWithIndexed {
+        inner: RefCell::new(Some((iter, sep, f))),
+    }
+}
+
+impl<I, S, F, D> fmt::Display for FormatWithIndexed<I, S, F>
+    where I: Iterator,
+          S: FnMut(usize, bool) -> D,
+          D: fmt::Display,
+          F: FnMut(I::Item, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (iter, mut sep, mut format) = match self.inner.borrow_mut().take() {
+            Some(t) => t,
+            None => panic!("FormatWithIndexed: was already formatted once"),
+        };
+
+        let mut iter = iter.peekable();
+        if let Some(fst) = iter.next() {
+            format(fst, &mut |disp: &dyn fmt::Display| disp.fmt(f))?;
+            let mut index = 0;
+            while let Some(elt) = iter.next() {
+                write!(f, "{}", sep(index, iter.peek().is_none()))?;
+                index += 1;
+                format(elt, &mut |disp: &dyn fmt::Display| disp.fmt(f))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn new_format_wrapped<'a, I>(iter: I, prefix: &'a str, sep: &'a str, suffix: &'a str)
+    -> FormatWrapped<'a, I>
+    where I: Iterator,
+{
+    FormatWrapped {
+        prefix,
+        sep,
+        suffix,
+        empty: None,
+        inner: RefCell::new(Some(iter)),
+    }
+}
+
+pub fn new_format_truncated<I, F, D>(iter: I, max: usize, sep: &str, suffix: F)
+    -> FormatTruncated<'_, I, F>
+    where I: Iterator,
+          F: FnMut(usize) -> D,
+          D: fmt::Display,
+{
+    FormatTruncated {
+        sep,
+        max,
+        inner: RefCell::new(Some((iter, suffix))),
+    }
+}
+
+impl<'a, I, F, D> FormatTruncated<'a, I, F>
+    where I: Iterator,
+          F: FnMut(usize) -> D,
+          D: fmt::Display,
+{
+    fn format<C>(&self, f: &mut fmt::Formatter, mut cb: C) -> fmt::Result
+        where C: FnMut(&I::Item, &mut fmt::Formatter) -> fmt::Result,
+    {
+        let (mut iter, mut suffix) = match self.inner.borrow_mut().take() {
+            Some(t) => t,
+            None => panic!("FormatTruncated: was already formatted once"),
+        };
+
+        for i in 0..self.max {
+            let elt = match iter.next() {
+                Some(elt) => elt,
+                None => return Ok(()),
+            };
+            if i > 0 && !self.sep.is_empty() {
+                f.write_str(self.sep)?;
+            }
+            cb(&elt, f)?;
+        }
+        let rest = match iter.size_hint() {
+            (low, Some(hi)) if low == hi => low,
+            _ => iter.count(),
+        };
+        if rest > 0 {
+            // the suffix is not an element, so the element flags do not apply
+            write!(f, "{}", suffix(rest))?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, I> FormatWrapped<'a, I> {
+    /// Write `empty` instead of the prefix and the suffix if there are no
+    /// elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let none: [i32; 0] = [];
+    /// assert_eq!(none.iter().format_wrapped("[", ", ", "]").to_string(), "[]");
+    /// assert_eq!(none.iter().format_wrapped("[", ", ", "]").with_empty("nothing").to_string(),
+    ///            "nothing");
+    /// ```
+    pub fn with_empty(mut self, empty: &'a str) -> Self {
+        self.empty = Some(empty);
+        self
+    }
+}
+
+impl<'a, I> FormatWrapped<'a, I>
+    where I: Iterator,
+{
+    fn format<F>(&self, f: &mut fmt::Formatter, mut cb: F) -> fmt::Result
+        where F: FnMut(&I::Item, &mut fmt::Formatter) -> fmt::Result,
+    {
+        let mut iter = match self.inner.borrow_mut().take() {
+            Some(t) => t,
+            None => panic!("FormatWrapped: was already formatted once"),
+        };
+
+        match iter.next() {
+            Some(fst) => {
+                f.write_str(self.prefix)?;
+                cb(&fst, f)?;
+                iter.try_for_each(|elt| {
+                    if !self.sep.is_empty() {
+                        f.write_str(self.sep)?;
+                    }
+                    cb(&elt, f)
+                })?;
+                f.write_str(self.suffix)
+            }
+            None => match self.empty {
+                Some(empty) => f.write_str(empty),
+                None => {
+                    f.write_str(self.prefix)?;
+                    f.write_str(self.suffix)
+                }
+            },
+        }
+    }
+}
+
 impl<'a, I> Format<'a, I>
     where I: Iterator,
 {
@@ -95,9 +347,9 @@ impl<'a, I> Format<'a, I>
 }
 
 macro_rules! impl_format {
-    ($($fmt_trait:ident)*) => {
+    ($ty:ident: $($fmt_trait:ident)*) => {
         $(
-            impl<'a, I> fmt::$fmt_trait for Format<'a, I>
+            impl<'a, I> fmt::$fmt_trait for $ty<'a, I>
                 where I: Iterator,
                       I::Item: fmt::$fmt_trait,
             {
@@ -109,5 +361,27 @@ macro_rules! impl_format {
     }
 }
 
-impl_format!{Display Debug
+impl_format!{Format: Display Debug
+             UpperExp LowerExp UpperHex LowerHex Octal Binary Pointer}
+impl_format!{FormatWrapped: Display Debug
              UpperExp LowerExp UpperHex LowerHex Octal Binary Pointer}
+
+macro_rules! impl_format_truncated {
+    ($($fmt_trait:ident)*) => {
+        $(
+            impl<'a, I, F, D> fmt::$fmt_trait for FormatTruncated<'a, I, F>
+                where I: Iterator,
+                      I::Item: fmt::$fmt_trait,
+                      F: FnMut(usize) -> D,
+                      D: fmt::Display,
+            {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    self.format(f, fmt::$fmt_trait::fmt)
+                }
+            }
+        )*
+    }
+}
+
+impl_format_truncated!{Display Debug
+                       UpperExp LowerExp UpperHex LowerHex Octal Binary Pointer}
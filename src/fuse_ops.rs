@@ -0,0 +1,48 @@
+//! Implementation details of [`fuse_ops!`](crate::fuse_ops).
+//!
+//! Each function wraps the closure built so far, which maps an element of the
+//! source iterator to `Some(value)` or filters it out with `None`.
+
+/// The closure that keeps every element of `iter` unchanged.
+///
+/// Taking the iterator fixes the element type, so that the closures passed
+/// to the following stages don't need type annotations.
+pub fn start<I>(_: &I) -> impl FnMut(I::Item) -> Option<I::Item>
+    where I: Iterator,
+{
+    Some
+}
+
+pub fn map<T, U, V, C, F>(mut ops: C, mut f: F) -> impl FnMut(T) -> Option<V>
+    where C: FnMut(T) -> Option<U>,
+          F: FnMut(U) -> V,
+{
+    move |x| ops(x).map(&mut f)
+}
+
+pub fn filter<T, U, C, F>(mut ops: C, mut f: F) -> impl FnMut(T) -> Option<U>
+    where C: FnMut(T) -> Option<U>,
+          F: FnMut(&U) -> bool,
+{
+    move |x| ops(x).filter(&mut f)
+}
+
+pub fn filter_map<T, U, V, C, F>(mut ops: C, mut f: F) -> impl FnMut(T) -> Option<V>
+    where C: FnMut(T) -> Option<U>,
+          F: FnMut(U) -> Option<V>,
+{
+    move |x| ops(x).and_then(&mut f)
+}
+
+pub fn inspect<T, U, C, F>(mut ops: C, mut f: F) -> impl FnMut(T) -> Option<U>
+    where C: FnMut(T) -> Option<U>,
+          F: FnMut(&U),
+{
+    move |x| {
+        let x = ops(x);
+        if let Some(ref x) = x {
+            f(x);
+        }
+        x
+    }
+}
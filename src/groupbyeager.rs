@@ -0,0 +1,232 @@
+use alloc::vec::{self, Vec};
+use std::fmt;
+use std::iter::{Fuse, FusedIterator};
+use std::marker::PhantomData;
+
+/// An iterator adaptor that groups consecutive elements with equal keys,
+/// buffering each group before yielding it.
+///
+/// Iterator element type is `(K, vec::IntoIter<I::Item>)`.
+///
+/// See [`.group_by_eager()`](crate::Itertools::group_by_eager) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct GroupByEager<K, I, F>
+    where I: Iterator,
+{
+    iter: Fuse<I>,
+    key: F,
+    // the first element of the next group, and its key
+    pending: Option<(K, I::Item)>,
+}
+
+impl<K, I, F> fmt::Debug for GroupByEager<K, I, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+          K: fmt::Debug,
+{
+    debug_fmt_fields!(GroupByEager, iter, pending);
+}
+
+/// Create a new `GroupByEager` iterator.
+pub fn group_by_eager<K, I, F>(iter: I, key: F) -> GroupByEager<K, I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{
+    GroupByEager {
+        iter: iter.fuse(),
+        key,
+        pending: None,
+    }
+}
+
+impl<K, I, F> GroupByEager<K, I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{
+    fn next_group(&mut self) -> Option<(K, Vec<I::Item>)> {
+        let (key, first) = match self.pending.take() {
+            Some(pending) => pending,
+            None => {
+                let elt = self.iter.next()?;
+                ((self.key)(&elt), elt)
+            }
+        };
+        let mut group = Vec::new();
+        group.push(first);
+        for elt in &mut self.iter {
+            let next_key = (self.key)(&elt);
+            if next_key != key {
+                self.pending = Some((next_key, elt));
+                break;
+            }
+            group.push(elt);
+        }
+        Some((key, group))
+    }
+}
+
+impl<K, I, F> Iterator for GroupByEager<K, I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{
+    type Item = (K, vec::IntoIter<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_group().map(|(key, group)| (key, group.into_iter()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = self.iter.size_hint();
+        let pending = self.pending.is_some() as usize;
+        ((low > 0 || pending > 0) as usize, hi.and_then(|hi| hi.checked_add(pending)))
+    }
+}
+
+impl<K, I, F> FusedIterator for GroupByEager<K, I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{}
+
+/// An iterator adaptor that groups consecutive elements with equal keys into
+/// owned `Vec`s.
+///
+/// Iterator element type is `(K, Vec<I::Item>)`.
+///
+/// See [`.chunk_by_owned()`](crate::Itertools::chunk_by_owned) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ChunkByOwned<K, I, F>
+    where I: Iterator,
+{
+    inner: GroupByEager<K, I, F>,
+}
+
+impl<K, I, F> Clone for ChunkByOwned<K, I, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          K: Clone,
+          F: Clone,
+{
+    clone_fields!(inner);
+}
+
+impl<K, I, F> fmt::Debug for ChunkByOwned<K, I, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+          K: fmt::Debug,
+{
+    debug_fmt_fields!(ChunkByOwned, inner);
+}
+
+/// Create a new `ChunkByOwned` iterator.
+pub fn chunk_by_owned<K, I, F>(iter: I, key: F) -> ChunkByOwned<K, I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{
+    ChunkByOwned { inner: group_by_eager(iter, key) }
+}
+
+impl<K, I, F> Iterator for ChunkByOwned<K, I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_group()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, I, F> FusedIterator for ChunkByOwned<K, I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{}
+
+/// An iterator adaptor that groups consecutive elements by two keys, the
+/// groups by the second key nested in the groups by the first one.
+///
+/// Iterator element type is `(K1, Vec<(K2, Vec<I::Item>)>)`.
+///
+/// See [`.chunk_by2()`](crate::Itertools::chunk_by2) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ChunkBy2<K1, K2, I, F1, F2>
+    where I: Iterator,
+{
+    outer: ChunkByOwned<K1, I, F1>,
+    key2: F2,
+    marker: PhantomData<fn() -> K2>,
+}
+
+impl<K1, K2, I, F1, F2> Clone for ChunkBy2<K1, K2, I, F1, F2>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          K1: Clone,
+          F1: Clone,
+          F2: Clone,
+{
+    clone_fields!(outer, key2, marker);
+}
+
+impl<K1, K2, I, F1, F2> fmt::Debug for ChunkBy2<K1, K2, I, F1, F2>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+          K1: fmt::Debug,
+{
+    debug_fmt_fields!(ChunkBy2, outer);
+}
+
+/// Create a new `ChunkBy2` iterator.
+pub fn chunk_by2<K1, K2, I, F1, F2>(iter: I, key1: F1, key2: F2) -> ChunkBy2<K1, K2, I, F1, F2>
+    where I: Iterator,
+          F1: FnMut(&I::Item) -> K1,
+          F2: FnMut(&I::Item) -> K2,
+          K1: PartialEq,
+          K2: PartialEq,
+{
+    ChunkBy2 {
+        outer: chunk_by_owned(iter, key1),
+        key2,
+        marker: PhantomData,
+    }
+}
+
+impl<K1, K2, I, F1, F2> Iterator for ChunkBy2<K1, K2, I, F1, F2>
+    where I: Iterator,
+          F1: FnMut(&I::Item) -> K1,
+          F2: FnMut(&I::Item) -> K2,
+          K1: PartialEq,
+          K2: PartialEq,
+{
+    type Item = (K1, Vec<(K2, Vec<I::Item>)>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key1, group) = self.outer.next()?;
+        // an inner group never crosses the boundary of its outer group
+        Some((key1, chunk_by_owned(group.into_iter(), &mut self.key2).collect()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.outer.size_hint()
+    }
+}
+
+impl<K1, K2, I, F1, F2> FusedIterator for ChunkBy2<K1, K2, I, F1, F2>
+    where I: Iterator,
+          F1: FnMut(&I::Item) -> K1,
+          F2: FnMut(&I::Item) -> K2,
+          K1: PartialEq,
+          K2: PartialEq,
+{}
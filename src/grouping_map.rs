@@ -533,4 +533,178 @@ impl<I, K, V> GroupingMap<I>
     {
         self.fold_first(|acc, _, val| acc * val)
     }
+
+    /// Groups elements from the `GroupingMap` source by key and computes their arithmetic mean.
+    ///
+    /// The mean is computed in a single pass with Welford's online algorithm,
+    /// without collecting the elements of each group.
+    ///
+    /// `V: Into<f64>` only holds for types that convert to `f64` without loss, which excludes
+    /// `i64`, `u64`, `isize` and `usize`; use [`.mean_by()`](GroupingMap::mean_by) for those.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the mean of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .into_grouping_map_by(|&n| n % 3)
+    ///     .mean();
+    ///
+    /// assert_eq!(lookup[&0], 8.);
+    /// assert_eq!(lookup[&1], 4.);
+    /// assert_eq!(lookup[&2], 6.5);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn mean(self) -> HashMap<K, f64>
+        where V: Into<f64>,
+    {
+        self.mean_by(|_, val| val.into())
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and computes the arithmetic mean of
+    /// each group, converting every element to `f64` with `f` first, like
+    /// [`.mean()`](GroupingMap::mean).
+    ///
+    /// Returns a `HashMap` associating the key of each group with the mean of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1u64, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .into_grouping_map_by(|&n| n % 3)
+    ///     .mean_by(|_key, val| val as f64);
+    ///
+    /// assert_eq!(lookup[&0], 8.);
+    /// assert_eq!(lookup[&1], 4.);
+    /// assert_eq!(lookup[&2], 6.5);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn mean_by<F>(self, f: F) -> HashMap<K, f64>
+        where F: FnMut(&K, V) -> f64,
+    {
+        self.welford(f).into_iter()
+            .map(|(key, (_, mean, _))| (key, mean))
+            .collect()
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and computes their population
+    /// variance, that is the mean of the squared deviations from the group's mean.
+    ///
+    /// The variance is computed in a single pass with Welford's online algorithm,
+    /// without collecting the elements of each group.
+    ///
+    /// Like [`.mean()`](GroupingMap::mean), this requires `V: Into<f64>`; see
+    /// [`.variance_by()`](GroupingMap::variance_by) for other value types.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the variance of that group's
+    /// elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .into_grouping_map_by(|&n| n % 3)
+    ///     .variance();
+    ///
+    /// assert_eq!(lookup[&0], 14.);
+    /// assert_eq!(lookup[&1], 6.);
+    /// assert_eq!(lookup[&2], 2.25);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn variance(self) -> HashMap<K, f64>
+        where V: Into<f64>,
+    {
+        self.variance_by(|_, val| val.into())
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and computes the population variance
+    /// of each group, converting every element to `f64` with `f` first, like
+    /// [`.variance()`](GroupingMap::variance).
+    ///
+    /// Returns a `HashMap` associating the key of each group with the variance of that group's
+    /// elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1i64, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .into_grouping_map_by(|&n| n % 3)
+    ///     .variance_by(|_key, val| val as f64);
+    ///
+    /// assert_eq!(lookup[&0], 14.);
+    /// assert_eq!(lookup[&1], 6.);
+    /// assert_eq!(lookup[&2], 2.25);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn variance_by<F>(self, f: F) -> HashMap<K, f64>
+        where F: FnMut(&K, V) -> f64,
+    {
+        self.welford(f).into_iter()
+            .map(|(key, (count, _, m2))| (key, m2 / count as f64))
+            .collect()
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and computes their population
+    /// standard deviation, the square root of [`.variance()`](GroupingMap::variance).
+    ///
+    /// Returns a `HashMap` associating the key of each group with the standard deviation of that
+    /// group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .into_grouping_map_by(|&n| n % 3)
+    ///     .stddev();
+    ///
+    /// assert_eq!(lookup[&1], 6f64.sqrt());
+    /// assert_eq!(lookup[&2], 1.5);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn stddev(self) -> HashMap<K, f64>
+        where V: Into<f64>,
+    {
+        self.stddev_by(|_, val| val.into())
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and computes the population standard
+    /// deviation of each group, converting every element to `f64` with `f` first, like
+    /// [`.stddev()`](GroupingMap::stddev).
+    ///
+    /// Returns a `HashMap` associating the key of each group with the standard deviation of that
+    /// group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1usize, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .into_grouping_map_by(|&n| n % 3)
+    ///     .stddev_by(|_key, val| val as f64);
+    ///
+    /// assert_eq!(lookup[&1], 6f64.sqrt());
+    /// assert_eq!(lookup[&2], 1.5);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn stddev_by<F>(self, f: F) -> HashMap<K, f64>
+        where F: FnMut(&K, V) -> f64,
+    {
+        let mut variance = self.variance_by(f);
+        variance.values_mut().for_each(|v| *v = v.sqrt());
+        variance
+    }
+
+    /// Count, mean and sum of squared deviations from the mean of each group, after converting
+    /// the elements with `f`.
+    fn welford<F>(self, mut f: F) -> HashMap<K, (usize, f64, f64)>
+        where F: FnMut(&K, V) -> f64,
+    {
+        self.fold((0, 0., 0.), |(count, mean, m2), key, val| {
+            let val = f(key, val);
+            let count = count + 1;
+            let delta = val - mean;
+            let mean = mean + delta / count as f64;
+            (count, mean, m2 + delta * (val - mean))
+        })
+    }
 }
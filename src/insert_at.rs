@@ -0,0 +1,91 @@
+use std::fmt;
+use std::iter::{Fuse, FusedIterator};
+
+use super::size_hint;
+
+/// An iterator adaptor that inserts generated elements at given positions of
+/// the adapted iterator.
+///
+/// Iterator element type is `I::Item`.
+///
+/// This iterator is *fused*.
+///
+/// See [`.insert_at()`](crate::Itertools::insert_at) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct InsertAt<I, J, F> {
+    iter: Fuse<I>,
+    indices: J,
+    // the next index to insert at, if any
+    next_index: Option<usize>,
+    // the number of elements taken from `iter` so far
+    pos: usize,
+    f: F,
+}
+
+impl<I, J, F> fmt::Debug for InsertAt<I, J, F>
+    where I: fmt::Debug,
+          J: fmt::Debug,
+{
+    debug_fmt_fields!(InsertAt, iter, indices, next_index, pos);
+}
+
+/// Create a new `InsertAt` iterator.
+pub fn insert_at<I, J, F>(iter: I, indices: J, f: F) -> InsertAt<I, J::IntoIter, F>
+    where I: Iterator,
+          J: IntoIterator<Item = usize>,
+          F: FnMut(usize) -> I::Item,
+{
+    let mut indices = indices.into_iter();
+    InsertAt {
+        iter: iter.fuse(),
+        next_index: indices.next(),
+        indices,
+        pos: 0,
+        f,
+    }
+}
+
+impl<I, J, F> Iterator for InsertAt<I, J, F>
+    where I: Iterator,
+          J: Iterator<Item = usize>,
+          F: FnMut(usize) -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_index {
+            Some(index) if index <= self.pos => {
+                self.next_index = self.indices.next();
+                Some((self.f)(self.pos))
+            }
+            _ => {
+                let elt = self.iter.next()?;
+                self.pos += 1;
+                Some(elt)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = self.iter.size_hint();
+        match self.next_index {
+            None => (low, hi),
+            Some(index) => {
+                // only the next index is known to be reached for sure
+                let reached = index.saturating_sub(self.pos) <= low;
+                let low = low.saturating_add(reached as usize);
+                let hi = hi.and_then(|hi| {
+                    size_hint::add_scalar(self.indices.size_hint(), 1).1?.checked_add(hi)
+                });
+                (low, hi)
+            }
+        }
+    }
+}
+
+impl<I, J, F> FusedIterator for InsertAt<I, J, F>
+    where I: Iterator,
+          J: Iterator<Item = usize>,
+          F: FnMut(usize) -> I::Item,
+{}
@@ -1,5 +1,7 @@
-use std::iter::{Fuse, FusedIterator};
+use std::fmt;
+use std::iter::{Fuse, FusedIterator, Peekable};
 use super::size_hint;
+use either::Either;
 
 pub trait IntersperseElement<Item> {
     fn generate(&mut self) -> Item;
@@ -117,3 +119,240 @@ impl<I, ElemF> FusedIterator for IntersperseWith<I, ElemF>
     where I: Iterator,
           ElemF: IntersperseElement<I::Item>
 {}
+
+/// An iterator adaptor to insert a value created from its position between
+/// each element of the adapted iterator.
+///
+/// Iterator element type is `I::Item`
+///
+/// This iterator is *fused*.
+///
+/// See [`.intersperse_with_indexed()`](crate::Itertools::intersperse_with_indexed)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct IntersperseWithIndexed<I, F>
+    where I: Iterator,
+{
+    element: F,
+    iter: Peekable<Fuse<I>>,
+    peek: Option<I::Item>,
+    // the index of the next separator
+    index: usize,
+}
+
+impl<I, F> fmt::Debug for IntersperseWithIndexed<I, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(IntersperseWithIndexed, iter, peek, index);
+}
+
+impl<I, F> Clone for IntersperseWithIndexed<I, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          F: Clone,
+{
+    clone_fields!(element, iter, peek, index);
+}
+
+/// Create a new IntersperseWithIndexed iterator
+pub fn intersperse_with_indexed<I, F>(iter: I, element: F) -> IntersperseWithIndexed<I, F>
+    where I: Iterator,
+          F: FnMut(usize, bool) -> I::Item,
+{
+    let mut iter = iter.fuse().peekable();
+    IntersperseWithIndexed {
+        peek: iter.next(),
+        iter,
+        element,
+        index: 0,
+    }
+}
+
+impl<I, F> Iterator for IntersperseWithIndexed<I, F>
+    where I: Iterator,
+          F: FnMut(usize, bool) -> I::Item,
+{
+    type Item = I::Item;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.peek.is_some() {
+            self.peek.take()
+        } else {
+            self.peek = self.iter.next();
+            self.peek.as_ref()?;
+            let is_last = self.iter.peek().is_none();
+            let separator = (self.element)(self.index, is_last);
+            self.index += 1;
+            Some(separator)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // 2 * SH + { 1 or 0 }
+        let has_peek = self.peek.is_some() as usize;
+        let sh = self.iter.size_hint();
+        size_hint::add_scalar(size_hint::add(sh, sh), has_peek)
+    }
+}
+
+impl<I, F> FusedIterator for IntersperseWithIndexed<I, F>
+    where I: Iterator,
+          F: FnMut(usize, bool) -> I::Item,
+{}
+
+/// An iterator adaptor to insert a value computed from the two surrounding
+/// elements between each element of the adapted iterator.
+///
+/// Iterator element type is `I::Item`
+///
+/// This iterator is *fused*.
+///
+/// See [`.intersperse_between()`](crate::Itertools::intersperse_between) for
+/// more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct IntersperseBetween<I, F>
+    where I: Iterator,
+{
+    element: F,
+    iter: Fuse<I>,
+    // the next element, and the separator that follows it
+    peek: Option<I::Item>,
+    separator: Option<I::Item>,
+}
+
+/// Create a new IntersperseBetween iterator
+pub fn intersperse_between<I, F>(iter: I, element: F) -> IntersperseBetween<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item, &I::Item) -> I::Item,
+{
+    let mut iter = iter.fuse();
+    IntersperseBetween {
+        peek: iter.next(),
+        iter,
+        element,
+        separator: None,
+    }
+}
+
+impl<I, F> Iterator for IntersperseBetween<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item, &I::Item) -> I::Item,
+{
+    type Item = I::Item;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(separator) = self.separator.take() {
+            return Some(separator);
+        }
+        let elt = self.peek.take()?;
+        // the separator is made while both its neighbors are at hand
+        self.peek = self.iter.next();
+        if let Some(right) = &self.peek {
+            self.separator = Some((self.element)(&elt, right));
+        }
+        Some(elt)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let has_separator = self.separator.is_some() as usize;
+        if self.peek.is_some() {
+            // 2 * SH + 1 + { 1 or 0 }
+            let sh = self.iter.size_hint();
+            size_hint::add_scalar(size_hint::add(sh, sh), 1 + has_separator)
+        } else {
+            (has_separator, Some(has_separator))
+        }
+    }
+}
+
+impl<I, F> FusedIterator for IntersperseBetween<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item, &I::Item) -> I::Item,
+{}
+
+/// An iterator adaptor to insert a reference to a separator between each
+/// element of the adapted iterator.
+///
+/// Iterator element type is `Either<&'a S, I::Item>`
+///
+/// This iterator is *fused*.
+///
+/// See [`.intersperse_ref()`](crate::Itertools::intersperse_ref) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug)]
+pub struct IntersperseRef<'a, I, S: ?Sized>
+    where I: Iterator,
+{
+    separator: &'a S,
+    iter: Fuse<I>,
+    peek: Option<I::Item>,
+}
+
+impl<'a, I, S: ?Sized> Clone for IntersperseRef<'a, I, S>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+{
+    clone_fields!(separator, iter, peek);
+}
+
+/// Create a new IntersperseRef iterator
+pub fn intersperse_ref<I, S: ?Sized>(iter: I, separator: &S) -> IntersperseRef<'_, I, S>
+    where I: Iterator,
+{
+    let mut iter = iter.fuse();
+    IntersperseRef {
+        peek: iter.next(),
+        iter,
+        separator,
+    }
+}
+
+impl<'a, I, S: ?Sized> Iterator for IntersperseRef<'a, I, S>
+    where I: Iterator,
+{
+    type Item = Either<&'a S, I::Item>;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(x) = self.peek.take() {
+            Some(Either::Right(x))
+        } else {
+            self.peek = self.iter.next();
+            if self.peek.is_some() {
+                Some(Either::Left(self.separator))
+            } else {
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // 2 * SH + { 1 or 0 }
+        let has_peek = self.peek.is_some() as usize;
+        let sh = self.iter.size_hint();
+        size_hint::add_scalar(size_hint::add(sh, sh), has_peek)
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B where
+        Self: Sized, F: FnMut(B, Self::Item) -> B,
+    {
+        let mut accum = init;
+
+        if let Some(x) = self.peek.take() {
+            accum = f(accum, Either::Right(x));
+        }
+
+        let separator = self.separator;
+
+        self.iter.fold(accum,
+            |accum, x| {
+                let accum = f(accum, Either::Left(separator));
+                f(accum, Either::Right(x))
+        })
+    }
+}
+
+impl<'a, I, S: ?Sized> FusedIterator for IntersperseRef<'a, I, S>
+    where I: Iterator,
+{}
@@ -1,5 +1,6 @@
 use alloc::collections::BinaryHeap;
-use core::cmp::Ord;
+use alloc::vec::Vec;
+use core::cmp::{Ord, Ordering};
 
 pub(crate) fn k_smallest<T: Ord, I: Iterator<Item = T>>(mut iter: I, k: usize) -> BinaryHeap<T> {
     if k == 0 { return BinaryHeap::new(); }
@@ -18,3 +19,59 @@ pub(crate) fn k_smallest<T: Ord, I: Iterator<Item = T>>(mut iter: I, k: usize) -
 
     heap
 }
+
+/// Consumes `iter` and returns its `k` smallest elements according to
+/// `comparator`, sorted in ascending order.
+pub(crate) fn k_smallest_general<I, F>(iter: I, k: usize, mut comparator: F) -> Vec<I::Item>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    if k == 0 { return Vec::new(); }
+
+    let mut iter = iter.fuse();
+    let mut storage: Vec<I::Item> = iter.by_ref().take(k).collect();
+
+    let mut is_less_than = |a: &I::Item, b: &I::Item| comparator(a, b) == Ordering::Less;
+
+    // Turn `storage` into a max-heap
+    for i in (0..storage.len() / 2).rev() {
+        sift_down(&mut storage, &mut is_less_than, i);
+    }
+
+    if storage.len() == k {
+        iter.for_each(|val| {
+            if is_less_than(&val, &storage[0]) {
+                storage[0] = val;
+                sift_down(&mut storage, &mut is_less_than, 0);
+            }
+        });
+    }
+
+    storage.sort_by(comparator);
+    storage
+}
+
+/// Restore the max-heap property of `heap` for the subtree rooted at `origin`.
+fn sift_down<T, L>(heap: &mut [T], is_less_than: &mut L, mut origin: usize)
+where
+    L: FnMut(&T, &T) -> bool,
+{
+    loop {
+        let left = 2 * origin + 1;
+        let right = left + 1;
+        if left >= heap.len() {
+            return;
+        }
+        let child = if right >= heap.len() || is_less_than(&heap[right], &heap[left]) {
+            left
+        } else {
+            right
+        };
+        if !is_less_than(&heap[origin], &heap[child]) {
+            return;
+        }
+        heap.swap(origin, child);
+        origin = child;
+    }
+}
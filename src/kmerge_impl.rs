@@ -225,3 +225,191 @@ impl<I, F> FusedIterator for KMergeBy<I, F>
     where I: Iterator,
           F: KMergePredicate<I::Item>
 {}
+
+/// An iterator adaptor that merges an abitrary number of base iterators in
+/// ascending order, tagging each element with the index of its iterator.
+///
+/// Iterator element type is `(usize, I::Item)`.
+///
+/// See [`.kmerge_tagged()`](crate::Itertools::kmerge_tagged) for more
+/// information.
+pub type KMergeTagged<I> = KMergeTaggedBy<I, KMergeByLt>;
+
+/// An iterator adaptor that merges an abitrary number of base iterators
+/// according to an ordering function, tagging each element with the index of
+/// its iterator.
+///
+/// Iterator element type is `(usize, I::Item)`.
+///
+/// See [`.kmerge_tagged_by()`](crate::Itertools::kmerge_tagged_by) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct KMergeTaggedBy<I, F>
+    where I: Iterator,
+{
+    heap: Vec<(usize, HeadTail<I>)>,
+    less_than: F,
+}
+
+impl<I, F> fmt::Debug for KMergeTaggedBy<I, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(KMergeTaggedBy, heap);
+}
+
+impl<I, F> Clone for KMergeTaggedBy<I, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          F: Clone,
+{
+    clone_fields!(heap, less_than);
+}
+
+/// Create an iterator that merges elements of the contained iterators,
+/// tagging each with the index of its iterator.
+///
+/// Equivalent to `iterable.into_iter().kmerge_tagged_by(less_than)`.
+pub fn kmerge_tagged_by<I, F>(iterable: I, mut less_than: F)
+    -> KMergeTaggedBy<<I::Item as IntoIterator>::IntoIter, F>
+    where I: IntoIterator,
+          I::Item: IntoIterator,
+          F: KMergePredicate<<<I as IntoIterator>::Item as IntoIterator>::Item>,
+{
+    let iter = iterable.into_iter();
+    let (lower, _) = iter.size_hint();
+    let mut heap: Vec<_> = Vec::with_capacity(lower);
+    heap.extend(iter.enumerate()
+                    .filter_map(|(index, it)| HeadTail::new(it.into_iter()).map(|ht| (index, ht))));
+    heapify(&mut heap, |a, b| less_than.kmerge_pred(&a.1.head, &b.1.head));
+    KMergeTaggedBy { heap, less_than }
+}
+
+impl<I, F> Iterator for KMergeTaggedBy<I, F>
+    where I: Iterator,
+          F: KMergePredicate<I::Item>
+{
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let result = if let Some(next) = self.heap[0].1.next() {
+            (self.heap[0].0, next)
+        } else {
+            let (index, ht) = self.heap.swap_remove(0);
+            (index, ht.head)
+        };
+        let less_than = &mut self.less_than;
+        sift_down(&mut self.heap, 0, |a, b| less_than.kmerge_pred(&a.1.head, &b.1.head));
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.heap.iter()
+                 .map(|(_, i)| i.size_hint())
+                 .reduce(size_hint::add)
+                 .unwrap_or((0, Some(0)))
+    }
+}
+
+impl<I, F> FusedIterator for KMergeTaggedBy<I, F>
+    where I: Iterator,
+          F: KMergePredicate<I::Item>
+{}
+
+/// An iterator adaptor that merges an abitrary number of base iterators,
+/// always taking the next element from the iterator whose head has the least
+/// priority.
+///
+/// Iterator element type is `I::Item`.
+///
+/// See [`.merge_by_priority()`](crate::Itertools::merge_by_priority) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct KMergeByPriority<I, F, P>
+    where I: Iterator,
+{
+    // Each head is keyed by its priority and the order in which it became a
+    // head, so that ties are taken first-come, first-served.
+    heap: Vec<((P, usize), HeadTail<I>)>,
+    priority: F,
+    seq: usize,
+}
+
+impl<I, F, P> fmt::Debug for KMergeByPriority<I, F, P>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+          P: fmt::Debug,
+{
+    debug_fmt_fields!(KMergeByPriority, heap, seq);
+}
+
+impl<I, F, P> Clone for KMergeByPriority<I, F, P>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          F: Clone,
+          P: Clone,
+{
+    clone_fields!(heap, priority, seq);
+}
+
+/// Create an iterator that merges elements of the contained iterators by the
+/// priority of their heads.
+///
+/// Equivalent to `iterable.into_iter().merge_by_priority(priority)`.
+pub fn merge_by_priority<I, F, P>(iterable: I, mut priority: F)
+    -> KMergeByPriority<<I::Item as IntoIterator>::IntoIter, F, P>
+    where I: IntoIterator,
+          I::Item: IntoIterator,
+          F: FnMut(&<<I as IntoIterator>::Item as IntoIterator>::Item) -> P,
+          P: Ord,
+{
+    let iter = iterable.into_iter();
+    let (lower, _) = iter.size_hint();
+    let mut heap: Vec<_> = Vec::with_capacity(lower);
+    heap.extend(iter.filter_map(|it| HeadTail::new(it.into_iter()))
+                    .enumerate()
+                    .map(|(seq, ht)| ((priority(&ht.head), seq), ht)));
+    heapify(&mut heap, |a, b| a.0 < b.0);
+    let seq = heap.len();
+    KMergeByPriority { heap, priority, seq }
+}
+
+impl<I, F, P> Iterator for KMergeByPriority<I, F, P>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> P,
+          P: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let result = if let Some(next) = self.heap[0].1.next() {
+            let (key, ht) = &mut self.heap[0];
+            *key = ((self.priority)(&ht.head), self.seq);
+            self.seq += 1;
+            next
+        } else {
+            self.heap.swap_remove(0).1.head
+        };
+        sift_down(&mut self.heap, 0, |a, b| a.0 < b.0);
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.heap.iter()
+                 .map(|(_, i)| i.size_hint())
+                 .reduce(size_hint::add)
+                 .unwrap_or((0, Some(0)))
+    }
+}
+
+impl<I, F, P> FusedIterator for KMergeByPriority<I, F, P>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> P,
+          P: Ord,
+{}
@@ -1,9 +1,30 @@
 use std::ops::Index;
 use alloc::vec::Vec;
 
+use crate::size_hint;
+
+/// A buffer that pulls elements from an iterator only when they are needed,
+/// and keeps every element it has pulled so far.
+///
+/// This is the building block of [`.combinations()`](crate::Itertools::combinations),
+/// [`.permutations()`](crate::Itertools::permutations) and friends, which need
+/// random access to an iterator's elements without collecting it up front.
+/// Buffered elements are accessed by indexing.
+///
+/// ```
+/// use itertools::structs::LazyBuffer;
+///
+/// let mut buffer = LazyBuffer::new(1..);
+/// buffer.prefill(3);
+/// assert_eq!(buffer.len(), 3);
+/// assert_eq!(&buffer[..], &[1, 2, 3]);
+///
+/// assert!(buffer.get_next());
+/// assert_eq!(buffer[3], 4);
+/// ```
 #[derive(Debug, Clone)]
 pub struct LazyBuffer<I: Iterator> {
-    pub it: I,
+    pub(crate) it: I,
     done: bool,
     buffer: Vec<I::Item>,
 }
@@ -12,6 +33,7 @@ impl<I> LazyBuffer<I>
 where
     I: Iterator,
 {
+    /// Create an empty buffer over `it`.
     pub fn new(it: I) -> LazyBuffer<I> {
         LazyBuffer {
             it,
@@ -20,10 +42,44 @@ where
         }
     }
 
+    /// Return the number of buffered elements.
     pub fn len(&self) -> usize {
         self.buffer.len()
     }
 
+    /// Return `true` if no elements are buffered.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Return `true` once the underlying iterator is known to be exhausted.
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Return the buffered elements.
+    pub fn as_slice(&self) -> &[I::Item] {
+        &self.buffer
+    }
+
+    /// Return a reference to the underlying iterator.
+    pub fn inner(&self) -> &I {
+        &self.it
+    }
+
+    /// Return the bounds on the total number of elements, buffered and not
+    /// yet pulled from the underlying iterator.
+    pub fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (self.buffer.len(), Some(self.buffer.len()))
+        } else {
+            size_hint::add_scalar(self.it.size_hint(), self.buffer.len())
+        }
+    }
+
+    /// Pull one more element into the buffer.
+    ///
+    /// Return `false` if the underlying iterator is exhausted.
     pub fn get_next(&mut self) -> bool {
         if self.done {
             return false;
@@ -41,6 +97,8 @@ where
         }
     }
 
+    /// Pull elements until `len` are buffered or the underlying iterator is
+    /// exhausted.
     pub fn prefill(&mut self, len: usize) {
         let buffer_len = self.buffer.len();
 
@@ -51,6 +109,21 @@ where
             self.done = self.buffer.len() < len;
         }
     }
+
+    /// Consume the buffer and return the total number of elements, buffered
+    /// and not yet pulled from the underlying iterator.
+    pub fn total_len(self) -> usize {
+        if self.done {
+            self.buffer.len()
+        } else {
+            self.buffer.len() + self.it.count()
+        }
+    }
+
+    /// Split the buffer into the buffered elements and the underlying iterator.
+    pub fn into_parts(self) -> (Vec<I::Item>, I) {
+        (self.buffer, self.it)
+    }
 }
 
 impl<I, J> Index<J> for LazyBuffer<I>
@@ -39,10 +39,22 @@
 //!   - Disable to compile itertools using `#![no_std]`. This disables
 //!     any items that depend on collections (like `group_by`, `unique`,
 //!     `kmerge`, `join` and many more).
+//! - `arbitrary`
+//!   - Enables the `arbitrary` module: [`arbitrary`](https://docs.rs/arbitrary)
+//!     inputs and reference models for the combinatorial adaptors, for
+//!     property testing code that consumes them. Requires `use_alloc`, and
+//!     the Rust version required by the `arbitrary` crate (1.63 or later).
+//! - `oorandom`
+//!   - Enables `permute_by_seed`, a reproducible shuffle backed by the small
+//!     [`oorandom`](https://docs.rs/oorandom) PRNG.
+//! - `unique_approx`
+//!   - Enables `unique_approx`, a variant of `unique` that uses a fixed-size
+//!     Bloom filter instead of a hash set. Implies `use_std`.
 //!
 //! ## Rust Version
 //!
-//! This version of itertools requires Rust 1.32 or later.
+//! This version of itertools requires Rust 1.55 or later, for the const
+//! generics used by the array based adaptors.
 #![doc(html_root_url="https://docs.rs/itertools/0.8/")]
 
 #[cfg(not(feature = "use_std"))]
@@ -53,6 +65,7 @@ extern crate alloc;
 
 #[cfg(feature = "use_alloc")]
 use alloc::{
+    collections::{BTreeMap, VecDeque},
     string::String,
     vec::Vec,
 };
@@ -68,7 +81,7 @@ use std::fmt;
 #[cfg(feature = "use_std")]
 use std::collections::HashSet;
 #[cfg(feature = "use_std")]
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash};
 #[cfg(feature = "use_alloc")]
 use std::fmt::Write;
 #[cfg(feature = "use_alloc")]
@@ -83,26 +96,42 @@ mod impl_macros;
 #[doc(hidden)]
 pub use std::iter as __std_iter;
 
+#[doc(hidden)]
+pub mod fuse_ops;
+
 /// The concrete iterator types.
 pub mod structs {
     pub use crate::adaptors::{
         Dedup,
         DedupBy,
+        DedupByKey,
         DedupWithCount,
         DedupByWithCount,
+        RunLengths,
+        RunLengthsBy,
+        RunLengthsByKey,
         Interleave,
         InterleaveShortest,
+        InterleaveTail,
+        StopAtShortest,
+        DrainLongest,
+        PadWith,
+        AndThenOk,
         FilterMapOk,
         FilterOk,
+        MapWhileOk,
         Product,
         PutBack,
         Batching,
+        MapEither,
         MapInto,
         MapOk,
+        MergeEither,
         Merge,
         MergeBy,
         TakeWhileRef,
         WhileSome,
+        WhileSomeComplete,
         Coalesce,
         TupleCombinations,
         Positions,
@@ -110,25 +139,53 @@ pub mod structs {
     };
     #[allow(deprecated)]
     pub use crate::adaptors::{MapResults, Step};
+    pub use crate::array_impl::{ArrayChunks, ArrayChunksRemainder, ArrayWindows};
     #[cfg(feature = "use_alloc")]
     pub use crate::adaptors::MultiProduct;
     #[cfg(feature = "use_alloc")]
+    pub use crate::batch_while::BatchWhile;
+    #[cfg(feature = "use_alloc")]
+    pub use crate::chunk_while::ChunkWhile;
+    #[cfg(feature = "use_alloc")]
+    pub use crate::chunks_owned::ChunksOwned;
+    #[cfg(feature = "use_alloc")]
     pub use crate::combinations::Combinations;
     #[cfg(feature = "use_alloc")]
     pub use crate::combinations_with_replacement::CombinationsWithReplacement;
     pub use crate::cons_tuples_impl::ConsTuples;
+    pub use crate::cumsum::{CumSum, CumSumBy};
+    pub use crate::deltas::{Deltas, DiffBy};
+    #[cfg(feature = "use_alloc")]
+    pub use crate::diagonal_product::DiagonalProduct;
+    pub use crate::digits::Digits;
     pub use crate::exactly_one_err::ExactlyOneError;
-    pub use crate::format::{Format, FormatWith};
+    pub use crate::exhaust::ExhaustArray;
+    pub use crate::format::{Format, FormatFilterWith, FormatTruncated, FormatWith, FormatWithIndexed, FormatWrapped};
     pub use crate::flatten_ok::FlattenOk;
     #[cfg(feature = "use_std")]
     pub use crate::grouping_map::{GroupingMap, GroupingMapBy};
     #[cfg(feature = "use_alloc")]
+    pub use crate::groupbyeager::{ChunkBy2, ChunkByOwned, GroupByEager};
+    #[cfg(feature = "use_alloc")]
     pub use crate::groupbylazy::{IntoChunks, Chunk, Chunks, GroupBy, Group, Groups};
-    pub use crate::intersperse::{Intersperse, IntersperseWith};
+    pub use crate::insert_at::InsertAt;
+    pub use crate::intersperse::{Intersperse, IntersperseBetween, IntersperseRef, IntersperseWith, IntersperseWithIndexed};
+    #[cfg(feature = "use_alloc")]
+    pub use crate::kmerge_impl::{KMerge, KMergeBy, KMergeByPriority, KMergeTagged, KMergeTaggedBy};
     #[cfg(feature = "use_alloc")]
-    pub use crate::kmerge_impl::{KMerge, KMergeBy};
+    pub use crate::lazy_buffer::LazyBuffer;
+    #[cfg(feature = "use_alloc")]
+    pub use crate::map_windowed::{MapWindowed, ZipWindows};
+    #[cfg(feature = "use_std")]
+    pub use crate::map_parallel::MapParallel;
+    pub use crate::linspace::Linspace;
+    pub use crate::mark_boundaries::MarkBoundaries;
     pub use crate::merge_join::MergeJoinBy;
     #[cfg(feature = "use_alloc")]
+    pub use crate::multi_merge_join::MultiMergeJoinByKey;
+    #[cfg(feature = "use_std")]
+    pub use crate::multiset_diff::MultisetDiff;
+    #[cfg(feature = "use_alloc")]
     pub use crate::multipeek_impl::MultiPeek;
     #[cfg(feature = "use_alloc")]
     pub use crate::peek_nth::PeekNth;
@@ -136,31 +193,56 @@ pub mod structs {
     pub use crate::peeking_take_while::PeekingTakeWhile;
     #[cfg(feature = "use_alloc")]
     pub use crate::permutations::Permutations;
-    pub use crate::process_results_impl::ProcessResults;
+    pub use crate::process_results_impl::{ProcessOptions, ProcessResults};
     #[cfg(feature = "use_alloc")]
-    pub use crate::powerset::Powerset;
+    pub use crate::powerset::{Powerset, PowersetBfs};
     #[cfg(feature = "use_alloc")]
     pub use crate::put_back_n_impl::PutBackN;
     #[cfg(feature = "use_alloc")]
     pub use crate::rciter_impl::RcIter;
     pub use crate::repeatn::RepeatN;
+    pub use crate::run_length_decode::RunLengthDecode;
+    pub use crate::select_by_mask::SelectByMask;
+    pub use crate::select_indices::{RemoveAt, SelectIndices};
     #[allow(deprecated)]
-    pub use crate::sources::{RepeatCall, Unfold, Iterate};
+    pub use crate::sources::{RepeatCall, Unfold, Iterate, MemoizedRecurrence, Generate, TryGenerate};
+    pub use crate::skip_while_inclusive::SkipWhileInclusive;
+    #[cfg(feature = "use_std")]
+    pub use crate::route_by_key::TooManyKeysError;
+    #[cfg(feature = "use_alloc")]
+    pub use crate::split_when::SplitWhen;
+    pub use crate::take_while_inclusive::TakeWhileInclusive;
     #[cfg(feature = "use_alloc")]
-    pub use crate::tee::Tee;
-    pub use crate::tuple_impl::{TupleBuffer, TupleWindows, CircularTupleWindows, Tuples};
+    pub use crate::tee::{Tee, TeeBounded, TeeBufferFull, TeeN};
+    pub use crate::tuple_impl::{TupleBuffer, TupleWindows, CircularTupleWindows, Tuples, WindowsSplitBy};
     #[cfg(feature = "use_std")]
     pub use crate::duplicates_impl::{Duplicates, DuplicatesBy};
     #[cfg(feature = "use_std")]
     pub use crate::unique_impl::{Unique, UniqueBy};
+    #[cfg(feature = "unique_approx")]
+    pub use crate::unique_approx::UniqueApprox;
+    #[cfg(feature = "use_std")]
+    pub use crate::unique_map::DuplicateKeyError;
+    #[cfg(feature = "use_alloc")]
+    pub use crate::window_position_max::WindowPositionMax;
+    #[cfg(feature = "use_alloc")]
+    pub use crate::windows_step::WindowsStep;
     pub use crate::with_position::WithPosition;
-    pub use crate::zip_eq_impl::ZipEq;
+    pub use crate::zip_eq_impl::{TryZipEq, ZipEq, ZipLengthMismatch};
     pub use crate::zip_longest::ZipLongest;
     pub use crate::ziptuple::Zip;
+    pub use crate::ziptuple_longest::ZipLongestTuple;
 }
 
+#[cfg(all(feature = "arbitrary", feature = "use_alloc"))]
+pub mod arbitrary;
+#[cfg(feature = "use_alloc")]
+pub mod pipeline;
+
 /// Traits helpful for using certain `Itertools` methods in generic contexts.
 pub mod traits {
+    pub use crate::adaptors::TailPolicy;
+    pub use crate::linspace::LinspaceFloat;
     pub use crate::tuple_impl::HomogeneousTuple;
 }
 
@@ -170,32 +252,54 @@ pub use crate::concat_impl::concat;
 pub use crate::cons_tuples_impl::cons_tuples;
 pub use crate::diff::diff_with;
 pub use crate::diff::Diff;
+pub use crate::digits::{digits, digits_msb_first};
+pub use crate::exhaust::{all_values, Exhaust};
 #[cfg(feature = "use_alloc")]
-pub use crate::kmerge_impl::{kmerge_by};
+pub use crate::kmerge_impl::{kmerge_by, merge_by_priority};
+pub use crate::linspace::{arange, linspace, linspace_exclusive};
 pub use crate::minmax::MinMaxResult;
 pub use crate::peeking_take_while::PeekingNext;
-pub use crate::process_results_impl::process_results;
+pub use crate::process_results_impl::{process_options, process_results, process_results_partial};
 pub use crate::repeatn::repeat_n;
 #[allow(deprecated)]
-pub use crate::sources::{repeat_call, unfold, iterate};
+pub use crate::sources::{repeat_call, unfold, iterate, memoized_recurrence, generate, try_generate};
 pub use crate::with_position::Position;
+#[cfg(feature = "use_std")]
+pub use crate::unique_map::DuplicatePolicy;
+pub use crate::select_by_mask::MaskPolicy;
 pub use crate::unziptuple::{multiunzip, MultiUnzip};
 pub use crate::ziptuple::multizip;
+pub use crate::ziptuple_longest::multizip_longest;
 mod adaptors;
+mod array_impl;
 mod either_or_both;
 pub use crate::either_or_both::EitherOrBoth;
 #[doc(hidden)]
 pub mod free;
 #[doc(inline)]
 pub use crate::free::*;
+#[cfg(feature = "use_alloc")]
+mod batch_while;
 mod concat_impl;
 mod cons_tuples_impl;
+mod cumsum;
+mod deltas;
+#[cfg(feature = "use_alloc")]
+mod diagonal_product;
+#[cfg(feature = "use_alloc")]
+mod chunk_while;
+#[cfg(feature = "use_alloc")]
+mod chunks_owned;
 #[cfg(feature = "use_alloc")]
 mod combinations;
 #[cfg(feature = "use_alloc")]
 mod combinations_with_replacement;
 mod exactly_one_err;
+mod exhaust;
+#[cfg(feature = "use_alloc")]
+mod extrema_set;
 mod diff;
+mod digits;
 mod flatten_ok;
 mod format;
 #[cfg(feature = "use_std")]
@@ -203,7 +307,10 @@ mod grouping_map;
 #[cfg(feature = "use_alloc")]
 mod group_map;
 #[cfg(feature = "use_alloc")]
+mod groupbyeager;
+#[cfg(feature = "use_alloc")]
 mod groupbylazy;
+mod insert_at;
 mod intersperse;
 #[cfg(feature = "use_alloc")]
 mod k_smallest;
@@ -211,9 +318,19 @@ mod k_smallest;
 mod kmerge_impl;
 #[cfg(feature = "use_alloc")]
 mod lazy_buffer;
+mod linspace;
+#[cfg(feature = "use_alloc")]
+mod map_windowed;
+#[cfg(feature = "use_std")]
+mod map_parallel;
+mod mark_boundaries;
 mod merge_join;
 mod minmax;
 #[cfg(feature = "use_alloc")]
+mod multi_merge_join;
+#[cfg(feature = "use_std")]
+mod multiset_diff;
+#[cfg(feature = "use_alloc")]
 mod multipeek_impl;
 mod pad_tail;
 #[cfg(feature = "use_alloc")]
@@ -229,8 +346,17 @@ mod put_back_n_impl;
 #[cfg(feature = "use_alloc")]
 mod rciter_impl;
 mod repeatn;
+#[cfg(feature = "use_std")]
+mod route_by_key;
+mod run_length_decode;
+mod select_by_mask;
+mod select_indices;
 mod size_hint;
 mod sources;
+mod skip_while_inclusive;
+#[cfg(feature = "use_alloc")]
+mod split_when;
+mod take_while_inclusive;
 #[cfg(feature = "use_alloc")]
 mod tee;
 mod tuple_impl;
@@ -238,11 +364,20 @@ mod tuple_impl;
 mod duplicates_impl;
 #[cfg(feature = "use_std")]
 mod unique_impl;
+#[cfg(feature = "unique_approx")]
+mod unique_approx;
+#[cfg(feature = "use_std")]
+mod unique_map;
 mod unziptuple;
+#[cfg(feature = "use_alloc")]
+mod window_position_max;
+#[cfg(feature = "use_alloc")]
+mod windows_step;
 mod with_position;
 mod zip_eq_impl;
 mod zip_longest;
 mod ziptuple;
+mod ziptuple_longest;
 
 #[macro_export]
 /// Create an iterator over the “cartesian product” of iterators.
@@ -350,6 +485,38 @@ macro_rules! izip {
     };
 }
 
+#[macro_export]
+/// Create an iterator running multiple iterators in lockstep until all of
+/// them are exhausted.
+///
+/// The `izip_longest!` iterator yields a tuple with an `Option` of an element
+/// from each of the input iterators, using `None` for the inputs that already
+/// reached their end, until all of them return `None`.
+///
+/// This is a shorthand for [`multizip_longest`] that takes the iterables as
+/// separate arguments.
+///
+/// ```
+/// # use itertools::izip_longest;
+/// #
+/// # fn main() {
+///
+/// let names = ["a", "b", "c"];
+/// let mut sums = Vec::new();
+///
+/// for (name, x, y) in izip_longest!(&names, vec![1, 2], 10..11) {
+///     sums.push((name, x.unwrap_or(0) + y.unwrap_or(0)));
+/// }
+///
+/// assert_eq!(sums, [(Some(&"a"), 11), (Some(&"b"), 2), (Some(&"c"), 0)]);
+/// # }
+/// ```
+macro_rules! izip_longest {
+    ( $( $iter:expr ),+ $(,)* ) => {
+        $crate::multizip_longest(( $( $iter, )+ ))
+    };
+}
+
 #[macro_export]
 /// [Chain][`chain`] zero or more iterators together into one sequence.
 ///
@@ -413,6 +580,43 @@ macro_rules! chain {
     };
 }
 
+#[macro_export]
+/// Fuse a chain of `map`, `filter`, `filter_map` and `inspect` steps into a
+/// single [`.filter_map()`](Iterator::filter_map) adaptor.
+///
+/// The first argument must implement [`IntoIterator`], and each following
+/// argument names a step and its closure, which take the same arguments as
+/// the [`Iterator`] method of the same name. The steps are applied in order.
+///
+/// Instead of one adaptor per step, each nested in the next, the steps are
+/// composed into one closure. This keeps the iterator type small and avoids
+/// the per-adaptor overhead of unoptimized builds.
+///
+/// ```
+/// use itertools::fuse_ops;
+///
+/// let words = ["apple", "", "kiwi", "banana", "fig"];
+/// let lengths = fuse_ops!(&words,
+///     filter(|w| !w.is_empty()),
+///     map(|w| w.len()),
+///     filter_map(|n| n.checked_sub(3)),
+///     map(|n| n * 10),
+/// );
+/// itertools::assert_equal(lengths, vec![20, 10, 30, 0]);
+/// ```
+macro_rules! fuse_ops {
+    ($iterable:expr $(, $step:ident ( $f:expr ) )* $(,)?) => {
+        {
+            let iter = $crate::__std_iter::IntoIterator::into_iter($iterable);
+            let ops = $crate::fuse_ops::start(&iter);
+            $(
+                let ops = $crate::fuse_ops::$step(ops, $f);
+            )*
+            $crate::__std_iter::Iterator::filter_map(iter, ops)
+        }
+    };
+}
+
 /// An [`Iterator`] blanket implementation that provides extra adaptors and
 /// methods.
 ///
@@ -466,6 +670,42 @@ pub trait Itertools : Iterator {
         adaptors::interleave_shortest(self, other.into_iter())
     }
 
+    /// Alternate elements from two iterators, with `policy` deciding what
+    /// happens once the iterator whose turn it is has run out:
+    ///
+    /// - [`StopAtShortest`] stops, like [`.interleave_shortest()`](Itertools::interleave_shortest).
+    /// - [`DrainLongest`] yields the rest of the other iterator, like
+    ///   [`.interleave()`](Itertools::interleave).
+    /// - [`PadWith(f)`](PadWith) keeps alternating strictly, calling `f` in
+    ///   place of the exhausted iterator until both have run out.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::{Itertools, DrainLongest, PadWith, StopAtShortest};
+    ///
+    /// let it = (1..4).interleave_tail(vec![-1], StopAtShortest);
+    /// itertools::assert_equal(it, vec![1, -1, 2]);
+    ///
+    /// let it = (1..4).interleave_tail(vec![-1], DrainLongest);
+    /// itertools::assert_equal(it, vec![1, -1, 2, 3]);
+    ///
+    /// let it = (1..4).interleave_tail(vec![-1], PadWith(|| 0));
+    /// itertools::assert_equal(it, vec![1, -1, 2, 0, 3]);
+    ///
+    /// let it = (1..2).interleave_tail(vec![-1, -2, -3], PadWith(|| 0));
+    /// itertools::assert_equal(it, vec![1, -1, 0, -2, 0, -3]);
+    /// ```
+    fn interleave_tail<J, P>(self, other: J, policy: P) -> InterleaveTail<Self, J::IntoIter, P>
+        where J: IntoIterator<Item = Self::Item>,
+              P: traits::TailPolicy<Self::Item>,
+              Self: Sized
+    {
+        adaptors::interleave_tail(self, other.into_iter(), policy)
+    }
+
     /// An iterator adaptor to insert a particular value
     /// between each element of the adapted iterator.
     ///
@@ -506,6 +746,231 @@ pub trait Itertools : Iterator {
         intersperse::intersperse_with(self, element)
     }
 
+    /// An iterator adaptor to insert a particular value created by a function
+    /// between each element of the adapted iterator, where the function
+    /// knows the position of the separator.
+    ///
+    /// `element` is called with the index of the separator, counting from
+    /// 0, and whether it is the last one, i.e. precedes the last element.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = ["a", "b", "c"].iter().copied()
+    ///     .intersperse_with_indexed(|_, last| if last { " and " } else { ", " });
+    /// assert_eq!(it.collect::<String>(), "a, b and c");
+    ///
+    /// let it = (0..3).map(|x| x * 10).intersperse_with_indexed(|i, _| -(i as i32) - 1);
+    /// itertools::assert_equal(it, vec![0, -1, 10, -2, 20]);
+    /// ```
+    fn intersperse_with_indexed<F>(self, element: F) -> IntersperseWithIndexed<Self, F>
+        where Self: Sized,
+              F: FnMut(usize, bool) -> Self::Item
+    {
+        intersperse::intersperse_with_indexed(self, element)
+    }
+
+    /// An iterator adaptor to insert a value computed from the two
+    /// surrounding elements between each element of the adapted iterator.
+    ///
+    /// `element` is called with references to the elements before and after
+    /// the separator. It is called when the element before the separator is
+    /// yielded, so no element needs to be cloned.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // a blank line between sections only
+    /// let lines = ["# a", "1", "2", "# b", "3"];
+    /// let it = lines.iter().copied().intersperse_between(|_, next| {
+    ///     if next.starts_with('#') { "\n" } else { "" }
+    /// });
+    /// itertools::assert_equal(it.filter(|s| !s.is_empty()), vec!["# a", "1", "2", "\n", "# b", "3"]);
+    ///
+    /// let it = vec![1, 4, 9].into_iter().intersperse_between(|a, b| b - a);
+    /// itertools::assert_equal(it, vec![1, 3, 4, 5, 9]);
+    /// ```
+    fn intersperse_between<F>(self, element: F) -> IntersperseBetween<Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> Self::Item
+    {
+        intersperse::intersperse_between(self, element)
+    }
+
+    /// An iterator adaptor to insert a reference to `separator` between each
+    /// element of the adapted iterator.
+    ///
+    /// Unlike [`.intersperse()`](Itertools::intersperse), this neither
+    /// requires the separator to be of the element type nor to be `Clone`:
+    /// the elements are yielded as `Either::Right` and the separator, by
+    /// reference, as `Either::Left`.
+    ///
+    /// Iterator element type is `Either<&S, Self::Item>`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::Either::{Left, Right};
+    ///
+    /// let separator = String::from("--");
+    /// itertools::assert_equal((0..3).intersperse_ref(&separator),
+    ///                         vec![Right(0), Left(&separator), Right(1), Left(&separator), Right(2)]);
+    ///
+    /// // `Either` forwards `Display` to either side
+    /// assert_eq!((0..3).intersperse_ref(" + ").join(""), "0 + 1 + 2");
+    /// ```
+    fn intersperse_ref<S>(self, separator: &S) -> IntersperseRef<'_, Self, S>
+        where Self: Sized,
+              S: ?Sized
+    {
+        intersperse::intersperse_ref(self, separator)
+    }
+
+    /// An iterator adaptor to insert elements generated by `f` at the given
+    /// positions of the adapted iterator.
+    ///
+    /// `indices` must be sorted in ascending order. An index `i` inserts
+    /// `f(i)` right before the element at position `i` of the adapted
+    /// iterator, or at the end if `i` is its length; indices past the end
+    /// are ignored. Repeated indices insert several elements at the same
+    /// position. This generalizes [`.intersperse_with()`](Itertools::intersperse_with)
+    /// to irregular insertion points.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (1..6).insert_at(vec![0, 2, 2, 5], |i| i * 100);
+    /// itertools::assert_equal(it, vec![0, 1, 2, 200, 200, 3, 4, 5, 500]);
+    ///
+    /// // page breaks at precomputed offsets
+    /// let lines = ["a", "b", "c", "d"];
+    /// let pages = lines.iter().copied().insert_at([2, 7], |_| "---").join(" ");
+    /// assert_eq!(pages, "a b --- c d");
+    /// ```
+    fn insert_at<J, F>(self, indices: J, f: F) -> InsertAt<Self, J::IntoIter, F>
+        where Self: Sized,
+              J: IntoIterator<Item = usize>,
+              F: FnMut(usize) -> Self::Item
+    {
+        insert_at::insert_at(self, indices, f)
+    }
+
+    /// Return an iterator adaptor that yields only the elements at the given
+    /// positions of the adapted iterator.
+    ///
+    /// `indices` must be sorted in ascending order; repeated indices are
+    /// yielded once. Elements between the indices are skipped with
+    /// [`Iterator::nth`], in a single forward pass.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = "abcdefg".chars().select_indices(vec![0, 2, 3, 6, 10]);
+    /// itertools::assert_equal(it, "acdg".chars());
+    /// ```
+    fn select_indices<J>(self, indices: J) -> SelectIndices<Self, J::IntoIter>
+        where Self: Sized,
+              J: IntoIterator<Item = usize>
+    {
+        select_indices::select_indices(self, indices)
+    }
+
+    /// Return an iterator adaptor that skips the elements at the given
+    /// positions of the adapted iterator.
+    ///
+    /// `indices` must be sorted in ascending order; repeated indices remove
+    /// one element.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = "abcdefg".chars().remove_at(vec![0, 2, 3, 6, 10]);
+    /// itertools::assert_equal(it, "bef".chars());
+    /// ```
+    fn remove_at<J>(self, indices: J) -> RemoveAt<Self, J::IntoIter>
+        where Self: Sized,
+              J: IntoIterator<Item = usize>
+    {
+        select_indices::remove_at(self, indices)
+    }
+
+    /// Return an iterator adaptor that yields the elements whose
+    /// corresponding value in `mask` is `true`, like boolean masking of
+    /// arrays.
+    ///
+    /// `policy` decides what happens if `mask` and the adapted iterator are
+    /// not of the same length, see [`MaskPolicy`].
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::{Itertools, MaskPolicy};
+    ///
+    /// let mask = vec![true, false, true];
+    /// let it = (0..5).select_by_mask(mask.iter().copied(), MaskPolicy::Truncate);
+    /// itertools::assert_equal(it, vec![0, 2]);
+    ///
+    /// let it = (0..5).select_by_mask(mask, MaskPolicy::KeepRest);
+    /// itertools::assert_equal(it, vec![0, 2, 3, 4]);
+    /// ```
+    fn select_by_mask<M>(self, mask: M, policy: MaskPolicy) -> SelectByMask<Self, M::IntoIter>
+        where Self: Sized,
+              M: IntoIterator<Item = bool>
+    {
+        select_by_mask::select_by_mask(self, mask, policy)
+    }
+
+    /// Split the elements into those whose corresponding value in `mask` is
+    /// `true` and those where it is `false`, in one pass.
+    ///
+    /// `policy` decides what happens if `mask` and the adapted iterator are
+    /// not of the same length, see [`MaskPolicy`]; under
+    /// [`MaskPolicy::KeepRest`] the elements past the end of the mask are
+    /// selected.
+    ///
+    /// ```
+    /// use itertools::{Itertools, MaskPolicy};
+    ///
+    /// let words = ["apple", "bob", "kayak", "pear"];
+    /// let is_palindrome = words.iter().map(|w| w.chars().eq(w.chars().rev()));
+    /// let (palindromes, others): (Vec<&str>, Vec<&str>) =
+    ///     words.iter().copied().partition_by_mask(is_palindrome, MaskPolicy::Strict);
+    ///
+    /// assert_eq!(palindromes, ["bob", "kayak"]);
+    /// assert_eq!(others, ["apple", "pear"]);
+    /// ```
+    fn partition_by_mask<A, B, M>(self, mask: M, policy: MaskPolicy) -> (A, B)
+        where Self: Sized,
+              M: IntoIterator<Item = bool>,
+              A: Default + Extend<Self::Item>,
+              B: Default + Extend<Self::Item>,
+    {
+        select_by_mask::partition_by_mask(self, mask, policy)
+    }
+
     /// Create an iterator which iterates over both this and the specified
     /// iterator simultaneously, yielding pairs of two optional elements.
     ///
@@ -526,6 +991,9 @@ pub trait Itertools : Iterator {
     /// Iterator element type is
     /// [`EitherOrBoth<Self::Item, J::Item>`](EitherOrBoth).
     ///
+    /// To run three or more iterators until all of them are exhausted, see
+    /// [`izip_longest!()`].
+    ///
     /// ```rust
     /// use itertools::EitherOrBoth::{Both, Right};
     /// use itertools::Itertools;
@@ -553,6 +1021,59 @@ pub trait Itertools : Iterator {
         zip_eq(self, other)
     }
 
+    /// Create an iterator which iterates over both this and the specified
+    /// iterator simultaneously, yielding pairs of elements wrapped in `Ok`.
+    ///
+    /// Unlike [`.zip_eq()`](Itertools::zip_eq), this does not panic if the
+    /// iterators are not of equal lengths: it yields a single
+    /// [`ZipLengthMismatch`] error instead, and then ends.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let pairs: Result<Vec<_>, _> = (0..3).try_zip_eq("abc".chars()).collect();
+    /// assert_eq!(pairs, Ok(vec![(0, 'a'), (1, 'b'), (2, 'c')]));
+    ///
+    /// let err = (0..3).try_zip_eq("ab".chars()).collect::<Result<Vec<_>, _>>().unwrap_err();
+    /// assert_eq!(err.index(), 2);
+    /// assert!(err.first_longer());
+    /// ```
+    #[inline]
+    fn try_zip_eq<J>(self, other: J) -> TryZipEq<Self, J::IntoIter>
+        where J: IntoIterator,
+              Self: Sized
+    {
+        zip_eq_impl::try_zip_eq(self, other)
+    }
+
+    /// Compare the elements of `self` and `other` with `is_equal` in lock-step, and return a
+    /// [`Diff`] describing where they first diverge, or `None` if they are equal.
+    ///
+    /// This is the method form of [`diff_with`], see there for more information. Not to be
+    /// confused with [`.diff_by()`](Itertools::diff_by), which maps consecutive pairs of elements
+    /// of a single iterator.
+    ///
+    /// ```
+    /// use itertools::{Diff, Itertools};
+    ///
+    /// let diff = (0..5).first_divergence(vec![0, 1, 7, 3], |a, b| a == b).unwrap();
+    /// assert_eq!(diff.index(), 2);
+    /// assert_eq!(diff.elements(), (Some(&2), Some(&7)));
+    /// if let Diff::FirstMismatch(_, left, right) = diff {
+    ///     itertools::assert_equal(left, 2..5);
+    ///     itertools::assert_equal(right, vec![7, 3]);
+    /// }
+    ///
+    /// assert!((0..3).first_divergence(0..3, |a, b| a == b).is_none());
+    /// ```
+    fn first_divergence<J, F>(self, other: J, is_equal: F) -> Option<Diff<Self, J::IntoIter>>
+        where J: IntoIterator,
+              F: Fn(&Self::Item, &J::Item) -> bool,
+              Self: Sized
+    {
+        diff::diff_with(self, other, is_equal)
+    }
+
     /// A “meta iterator adaptor”. Its closure receives a reference to the
     /// iterator and may pick off as many elements as it likes, to produce the
     /// next iterator element.
@@ -583,6 +1104,40 @@ pub trait Itertools : Iterator {
         adaptors::batching(self, f)
     }
 
+    /// Like [`.batching()`](Itertools::batching), but the closure receives
+    /// the iterator wrapped in a [`PutBack`], so an element that was read
+    /// but belongs to the next batch can be put back.
+    ///
+    /// The put back slot holds a single element, and it is kept from one
+    /// batch to the next.
+    ///
+    /// Iterator element type is `B`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // Gather runs of increasing elements
+    /// let runs = vec![1, 3, 4, 2, 5, 0].into_iter().batching_put_back(|it| {
+    ///     let mut run = vec![it.next()?];
+    ///     for x in it.by_ref() {
+    ///         if x < *run.last().unwrap() {
+    ///             it.put_back(x);
+    ///             break;
+    ///         }
+    ///         run.push(x);
+    ///     }
+    ///     Some(run)
+    /// });
+    ///
+    /// itertools::assert_equal(runs, vec![vec![1, 3, 4], vec![2, 5], vec![0]]);
+    /// ```
+    fn batching_put_back<B, F>(self, f: F) -> Batching<PutBack<Self>, F>
+        where F: FnMut(&mut PutBack<Self>) -> Option<B>,
+              Self: Sized
+    {
+        adaptors::batching(adaptors::put_back(self), f)
+    }
+
     /// Return an *iterable* that can group iterator elements.
     /// Consecutive elements that map to the same key (“runs”), are assigned
     /// to the same group.
@@ -602,6 +1157,10 @@ pub trait Itertools : Iterator {
     /// Iterator element type is `(K, Group)`: the group's key and the
     /// group iterator.
     ///
+    /// To collect every group into a `Vec` anyway, use
+    /// [`.chunk_by_owned()`](Itertools::chunk_by_owned), a regular iterator
+    /// of `(K, Vec<Self::Item>)`.
+    ///
     /// ```
     /// use itertools::Itertools;
     ///
@@ -626,28 +1185,161 @@ pub trait Itertools : Iterator {
         groupbylazy::new(self, key)
     }
 
-    /// Return an *iterable* that can chunk the iterator.
-    ///
-    /// Yield subiterators (chunks) that each yield a fixed number elements,
-    /// determined by `size`. The last chunk will be shorter if there aren't
-    /// enough elements.
+    /// Return an iterator adaptor that groups consecutive elements that map
+    /// to the same key, like [`.group_by()`](Itertools::group_by), but
+    /// collects each group before yielding it.
     ///
-    /// `IntoChunks` is based on `GroupBy`: it is iterable (implements
-    /// `IntoIterator`, **not** `Iterator`), and it only buffers if several
-    /// chunk iterators are alive at the same time.
+    /// Each group is a [`vec::IntoIter`](alloc::vec::IntoIter), which knows
+    /// its length: it can preallocate when collected, report progress, or be
+    /// iterated in reverse. In exchange, every element is moved into a
+    /// buffer, and a whole group is read from the adapted iterator before the
+    /// group is yielded. Unlike `GroupBy`, this is a regular iterator, and
+    /// since each group owns its elements, the groups are `Send` if the
+    /// elements and keys are, and can be handed to other threads.
     ///
-    /// Iterator element type is `Chunk`, each chunk's iterator.
+    /// Each group's size hint is exact. The number of groups is only known
+    /// once the adapted iterator is exhausted, so the adaptor's own size hint
+    /// is not exact: its lower bound is at most 1.
     ///
-    /// **Panics** if `size` is 0.
+    /// Iterator element type is `(K, vec::IntoIter<Self::Item>)`.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let data = vec![1, 1, 2, -2, 6, 0, 3, 1];
-    /// //chunk size=3 |------->|-------->|--->|
+    /// let data = vec![1, 3, -2, -2, 1, 0, 1, 2];
+    /// for (positive, group) in data.into_iter().group_by_eager(|elt| *elt >= 0) {
+    ///     println!("{} {} elements", group.len(), if positive { "positive" } else { "negative" });
+    /// }
     ///
-    /// // Note: The `&` is significant here, `IntoChunks` is iterable
-    /// // only by reference. You can also call `.into_iter()` explicitly.
+    /// let lengths = "aaabccdd".chars().group_by_eager(|&c| c).map(|(c, g)| (c, g.len()));
+    /// itertools::assert_equal(lengths, vec![('a', 3), ('b', 1), ('c', 2), ('d', 2)]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    #[doc(alias = "into_chunk_by_owned")]
+    fn group_by_eager<K, F>(self, key: F) -> GroupByEager<K, Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> K,
+              K: PartialEq,
+    {
+        groupbyeager::group_by_eager(self, key)
+    }
+
+    /// Return an iterator adaptor that splits the iterator into segments
+    /// separated by the elements for which `is_separator` returns `true`.
+    ///
+    /// This is like [`slice::split`]: the separators are dropped, and
+    /// consecutive, leading or trailing separators produce empty segments,
+    /// so an iterator with `n` separators always yields `n + 1` segments.
+    /// See [`.split_when_inclusive()`](Itertools::split_when_inclusive) to
+    /// keep the separators.
+    ///
+    /// Iterator element type is `Vec<Self::Item>`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let records = "id=1;;id=2;".split(';').split_when(|field| field.is_empty());
+    /// itertools::assert_equal(records, vec![vec!["id=1"], vec!["id=2"], vec![]]);
+    ///
+    /// itertools::assert_equal((0..0).split_when(|_| true), vec![vec![]]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn split_when<F>(self, is_separator: F) -> SplitWhen<Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> bool,
+    {
+        split_when::split_when(self, is_separator, false)
+    }
+
+    /// Return an iterator adaptor that splits the iterator into segments,
+    /// each ending with an element for which `is_terminator` returns `true`.
+    ///
+    /// This is like [`slice::split_inclusive`]: the terminators are kept at
+    /// the end of their segments, a trailing terminator doesn't start another
+    /// segment, and an empty iterator yields no segments.
+    ///
+    /// Iterator element type is `Vec<Self::Item>`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lines = "ab\ncd\n\nef".chars().split_when_inclusive(|&c| c == '\n');
+    /// itertools::assert_equal(lines.map(|line| line.into_iter().collect::<String>()),
+    ///                         vec!["ab\n", "cd\n", "\n", "ef"]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn split_when_inclusive<F>(self, is_terminator: F) -> SplitWhen<Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> bool,
+    {
+        split_when::split_when(self, is_terminator, true)
+    }
+
+    /// Return an iterator adaptor that groups consecutive elements into
+    /// chunks, starting a new chunk between two neighbors for which
+    /// `same_chunk` returns `false`.
+    ///
+    /// `same_chunk` is called with the last element of the current chunk and
+    /// the next element. The chunks are collected into vectors, so unlike
+    /// [`.group_by()`](Itertools::group_by), this is a regular iterator that
+    /// doesn't need to be stored before being iterated.
+    ///
+    /// Iterator element type is `Vec<Self::Item>`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // runs of consecutive integers
+    /// let data = vec![1, 2, 4, 9, 10, 11, 12, 15, 16, 19];
+    /// itertools::assert_equal(data.into_iter().chunk_while(|&a, &b| a + 1 == b),
+    ///                         vec![vec![1, 2], vec![4], vec![9, 10, 11, 12], vec![15, 16], vec![19]]);
+    ///
+    /// // non-decreasing runs
+    /// let data = vec![0, 9, 2, 2, 3, 1];
+    /// itertools::assert_equal(data.into_iter().chunk_while(|a, b| a <= b),
+    ///                         vec![vec![0, 9], vec![2, 2, 3], vec![1]]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn chunk_while<F>(self, same_chunk: F) -> ChunkWhile<Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        chunk_while::chunk_while(self, same_chunk)
+    }
+
+    /// Return an *iterable* that can chunk the iterator.
+    ///
+    /// Yield subiterators (chunks) that each yield a fixed number elements,
+    /// determined by `size`. The last chunk will be shorter if there aren't
+    /// enough elements.
+    ///
+    /// `IntoChunks` is based on `GroupBy`: it is iterable (implements
+    /// `IntoIterator`, **not** `Iterator`), and it only buffers if several
+    /// chunk iterators are alive at the same time.
+    ///
+    /// To pass the chunks on as an `impl Iterator`, store them, or send them
+    /// to another thread, use
+    /// [`.into_chunks_owned()`](Itertools::into_chunks_owned) instead: it is
+    /// a regular iterator that yields each chunk as a `Vec`.
+    ///
+    /// Iterator element type is `Chunk`, each chunk's iterator.
+    ///
+    /// **Panics** if `size` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 1, 2, -2, 6, 0, 3, 1];
+    /// //chunk size=3 |------->|-------->|--->|
+    ///
+    /// // Note: The `&` is significant here, `IntoChunks` is iterable
+    /// // only by reference. You can also call `.into_iter()` explicitly.
     /// for chunk in &data.into_iter().chunks(3) {
     ///     // Check that the sum of each chunk is 4.
     ///     assert_eq!(4, chunk.sum());
@@ -661,6 +1353,172 @@ pub trait Itertools : Iterator {
         groupbylazy::new_chunks(self, size)
     }
 
+    /// Return an iterator adaptor that collects elements into owned chunks
+    /// of `size` elements, like [`.chunks()`](Itertools::chunks) but with
+    /// each chunk in a `Vec`. The last chunk may be shorter.
+    ///
+    /// Unlike `IntoChunks`, this is a regular iterator whose chunks own their
+    /// elements, so both are `Send` if the elements are. The size hint counts
+    /// chunks exactly when the adapted iterator's does, which helps splitting
+    /// work across threads, for example with rayon's `par_bridge`.
+    ///
+    /// Iterator element type is `Vec<Self::Item>`.
+    ///
+    /// **Panics** if `size` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let chunks = (1..9).into_chunks_owned(3);
+    /// assert_eq!(chunks.len(), 3);
+    /// itertools::assert_equal(chunks, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8]]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn into_chunks_owned(self, size: usize) -> ChunksOwned<Self>
+        where Self: Sized,
+    {
+        chunks_owned::chunks_owned(self, size)
+    }
+
+    /// Fill successive sinks with up to `size` elements each, creating each
+    /// sink with `make_sink`, and return all the sinks.
+    ///
+    /// `make_sink` is called with the index of the sink to create, and only
+    /// when there is at least one more element for it. The elements are
+    /// passed to the sinks via [`Extend`], without collecting each chunk
+    /// first, which makes this a building block for splitting one pass into
+    /// size-capped buffers or files.
+    ///
+    /// **Panics** if `size` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let sinks = (1..=7).collect_chunked_into(3, |i| vec![i * 100]);
+    /// assert_eq!(sinks, vec![vec![0, 1, 2, 3], vec![100, 4, 5, 6], vec![200, 7]]);
+    ///
+    /// let lines = ["a", "b", "c"].iter().copied().collect_chunked_into(2, |_| String::new());
+    /// assert_eq!(lines, ["ab", "c"]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn collect_chunked_into<C, F>(self, size: usize, mut make_sink: F) -> Vec<C>
+        where Self: Sized,
+              C: Extend<Self::Item>,
+              F: FnMut(usize) -> C,
+    {
+        assert!(size != 0, "chunk size must be non-zero");
+        let mut iter = self.fuse();
+        let mut sinks = Vec::new();
+        while let Some(first) = iter.next() {
+            let mut sink = make_sink(sinks.len());
+            sink.extend(once(first).chain(iter.by_ref().take(size - 1)));
+            sinks.push(sink);
+        }
+        sinks
+    }
+
+    /// Return an iterator adaptor that collects elements into batches, adding
+    /// elements to the current batch for as long as `more` returns `true`.
+    ///
+    /// `more` is called with the batch so far, which always holds at least
+    /// one element, before each element is added. This covers batching by
+    /// count, by total weight, by deadline, or by anything else that can be
+    /// decided from the batch itself. Each batch is preallocated to the
+    /// length of the largest batch so far.
+    ///
+    /// Iterator element type is `Vec<Self::Item>`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // batches of up to three elements
+    /// itertools::assert_equal((1..=7).batch_while(|batch| batch.len() < 3),
+    ///                         vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+    ///
+    /// // batches of strings filled until they hold at least 8 bytes
+    /// let words = ["one", "two", "three", "four", "five", "sixteen", "x"];
+    /// let batches = words.iter().batch_while(|batch| {
+    ///     batch.iter().map(|w| w.len()).sum::<usize>() < 8
+    /// });
+    /// itertools::assert_equal(batches, vec![
+    ///     vec![&"one", &"two", &"three"],
+    ///     vec![&"four", &"five"],
+    ///     vec![&"sixteen", &"x"],
+    /// ]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn batch_while<F>(self, more: F) -> BatchWhile<Self, F>
+        where Self: Sized,
+              F: FnMut(&[Self::Item]) -> bool,
+    {
+        batch_while::batch_while(self, more)
+    }
+
+    /// Return an iterator adaptor that groups consecutive elements with
+    /// equal keys and yields each group as a `Vec`, together with its key.
+    ///
+    /// This is [`.group_by_eager()`](Itertools::group_by_eager) for when
+    /// every group is collected anyway: a plain iterator with no borrowing
+    /// between the groups, which is `Send` if the adapted iterator, the key
+    /// function, the keys and the elements are.
+    ///
+    /// Iterator element type is `(K, Vec<Self::Item>)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 3, -2, -2, 1, 0, 1, 2];
+    /// itertools::assert_equal(data.into_iter().chunk_by_owned(|elt| *elt >= 0), vec![
+    ///     (true, vec![1, 3]),
+    ///     (false, vec![-2, -2]),
+    ///     (true, vec![1, 0, 1, 2]),
+    /// ]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    #[doc(alias = "group_adjacent_map")]
+    fn chunk_by_owned<K, F>(self, key: F) -> ChunkByOwned<K, Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> K,
+              K: PartialEq,
+    {
+        groupbyeager::chunk_by_owned(self, key)
+    }
+
+    /// Return an iterator adaptor that groups consecutive elements by
+    /// `key1`, and the elements of each of those groups by `key2`.
+    ///
+    /// Each outer group is yielded with its key, together with its inner
+    /// groups and their keys, all owned, like with
+    /// [`.chunk_by_owned()`](Itertools::chunk_by_owned). An inner group
+    /// never crosses the boundary of its outer group, even if `key2` is the
+    /// same on both sides. This replaces nesting two
+    /// [`.group_by()`](Itertools::group_by) calls in a single pass.
+    ///
+    /// Iterator element type is `(K1, Vec<(K2, Vec<Self::Item>)>)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let log = vec![(1, "a", 10), (1, "a", 11), (1, "b", 12), (2, "b", 13)];
+    /// let by_day_and_user = log.into_iter().chunk_by2(|e| e.0, |e| e.1);
+    /// itertools::assert_equal(by_day_and_user, vec![
+    ///     (1, vec![("a", vec![(1, "a", 10), (1, "a", 11)]), ("b", vec![(1, "b", 12)])]),
+    ///     (2, vec![("b", vec![(2, "b", 13)])]),
+    /// ]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn chunk_by2<K1, K2, F1, F2>(self, key1: F1, key2: F2) -> ChunkBy2<K1, K2, Self, F1, F2>
+        where Self: Sized,
+              F1: FnMut(&Self::Item) -> K1,
+              F2: FnMut(&Self::Item) -> K2,
+              K1: PartialEq,
+              K2: PartialEq,
+    {
+        groupbyeager::chunk_by2(self, key1, key2)
+    }
+
     /// Return an iterator over all contiguous windows producing tuples of
     /// a specific size (up to 12).
     ///
@@ -736,6 +1594,214 @@ pub trait Itertools : Iterator {
     {
         tuple_impl::circular_tuple_windows(self)
     }
+
+    /// Return an iterator over all contiguous windows producing arrays of
+    /// size `N`.
+    ///
+    /// Unlike [`.tuple_windows()`](Itertools::tuple_windows), the window size
+    /// is not limited to 12. The window is kept in an internal array that is
+    /// rotated by one element per step, and each window yielded is a clone of
+    /// it: every step clones all `N` elements, so each element is cloned `N`
+    /// times over the whole iteration. This makes it most suited for
+    /// iterators of references and other values that are cheap to copy; to
+    /// borrow the windows without cloning, use
+    /// [`.map_windowed()`](Itertools::map_windowed).
+    ///
+    /// Iterator element type is `[Self::Item; N]`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// **Panics** if `N` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (1..6).array_windows::<3>();
+    /// itertools::assert_equal(it, vec![[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+    ///
+    /// // the size is often inferred from how the windows are used
+    /// let increasing = [1, 3, 4, 4].iter().array_windows().all(|[a, b]| a < b);
+    /// assert!(!increasing);
+    ///
+    /// let long = (0..20).array_windows::<16>().map(|w| w.iter().sum::<i32>());
+    /// itertools::assert_equal(long, vec![120, 136, 152, 168, 184]);
+    /// ```
+    fn array_windows<const N: usize>(self) -> ArrayWindows<Self, N>
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        array_impl::array_windows(self)
+    }
+
+    /// Return an iterator adaptor that calls a closure on every pair of
+    /// contiguous windows of size `N` of this and the specified iterator in
+    /// lock step, ending with the shorter one.
+    ///
+    /// The windows are borrowed from a reusable buffer per iterator, like
+    /// [`.map_windowed()`](Itertools::map_windowed), so no element is cloned.
+    /// The window size is usually inferred from the types of the closure's
+    /// arguments.
+    ///
+    /// Iterator element type is `U`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// **Panics** if `N` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let signal = [1, 2, 3, 4, 5];
+    /// let kernel_weights = [1, 0, -1, 0, 1];
+    /// let it = signal.iter().zip_windows(&kernel_weights, |xs: &[&i32; 3], ws: &[&i32; 3]| {
+    ///     xs.iter().zip(ws).map(|(&x, &w)| x * w).sum::<i32>()
+    /// });
+    /// itertools::assert_equal(it, vec![-2, -3, 2]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn zip_windows<J, F, U, const N: usize>(self, other: J, f: F) -> ZipWindows<Self, J::IntoIter, F, N>
+        where Self: Sized,
+              J: IntoIterator,
+              F: FnMut(&[Self::Item; N], &[J::Item; N]) -> U
+    {
+        map_windowed::zip_windows(self, other.into_iter(), f)
+    }
+
+    /// Return an iterator over all contiguous windows producing tuples of a
+    /// specific size (up to 12), where no window spans a boundary element.
+    ///
+    /// Elements for which `is_boundary` returns `true` are dropped and split
+    /// the iterator into segments; windows are produced within each segment
+    /// only, without allocating per segment. Segments shorter than the window
+    /// produce no windows.
+    ///
+    /// Like [`.tuple_windows()`](Itertools::tuple_windows), this clones the
+    /// elements so that they can be part of successive windows.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // bigrams that don't cross sentence boundaries
+    /// let words = "the cat sat . a dog . ran away".split(' ');
+    /// itertools::assert_equal(words.windows_split_by::<(_, _), _>(|w| *w == "."),
+    ///                         vec![("the", "cat"), ("cat", "sat"), ("a", "dog"), ("ran", "away")]);
+    /// ```
+    fn windows_split_by<T, F>(self, is_boundary: F) -> WindowsSplitBy<Self, T, F>
+        where Self: Sized + Iterator<Item = T::Item>,
+              T: traits::HomogeneousTuple,
+              T::Item: Clone,
+              F: FnMut(&T::Item) -> bool
+    {
+        tuple_impl::windows_split_by(self, is_boundary)
+    }
+
+    /// Return an iterator adaptor that yields, for every window of `size`
+    /// consecutive elements, the position of the window's maximum in the
+    /// adapted iterator.
+    ///
+    /// If several elements are equally maximum, the position of the last of
+    /// them is returned, like [`.position_max()`](Itertools::position_max).
+    /// Only the positions are yielded, so the elements are never cloned; the
+    /// running maxima are kept in a monotonic queue, which makes this
+    /// *O(1)* amortized per element.
+    ///
+    /// Iterator element type is `usize`.
+    ///
+    /// **Panics** if `size` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let signal = vec![1, 3, 2, 5, 4, 4, 1];
+    /// itertools::assert_equal(signal.iter().window_position_max(3),
+    ///                         vec![1, 3, 3, 3, 5]);
+    ///
+    /// // fewer elements than the window size produce no windows
+    /// itertools::assert_equal(signal.iter().window_position_max(8), vec![]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn window_position_max(self, size: usize) -> WindowPositionMax<Self>
+        where Self: Sized,
+              Self::Item: Ord
+    {
+        window_position_max::window_position_max(self, size)
+    }
+
+    /// Return an iterator adaptor over windows of `size` consecutive
+    /// elements, where each window starts `step` elements after the previous
+    /// one.
+    ///
+    /// With `step` smaller than `size` the windows overlap, and with `step`
+    /// larger than `size` the elements between windows are skipped. The
+    /// elements of the current window are kept in an internal buffer and
+    /// cloned into every window that contains them. Trailing elements that
+    /// don't fill a whole window are not yielded.
+    ///
+    /// Iterator element type is `Vec<Self::Item>`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// **Panics** if `size` or `step` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// itertools::assert_equal((0..7).windows_step(3, 2),
+    ///                         vec![vec![0, 1, 2], vec![2, 3, 4], vec![4, 5, 6]]);
+    ///
+    /// // gapped windows
+    /// itertools::assert_equal((0..10).windows_step(2, 4),
+    ///                         vec![vec![0, 1], vec![4, 5], vec![8, 9]]);
+    ///
+    /// // 1024-sample frames every 256 samples
+    /// let frames = (0..4096).windows_step(1024, 256);
+    /// assert_eq!(frames.len(), 13);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn windows_step(self, size: usize, step: usize) -> WindowsStep<Self>
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        windows_step::windows_step(self, size, step)
+    }
+
+    /// Return an iterator adaptor that calls `f` on every window of `size`
+    /// consecutive elements and yields its results.
+    ///
+    /// The window is passed to `f` as a slice borrowed from an internal
+    /// buffer that is reused for all windows, so unlike
+    /// [`.tuple_windows()`](Itertools::tuple_windows) and
+    /// [`.windows_step()`](Itertools::windows_step), the elements are never
+    /// cloned and need not implement `Clone`. The buffer holds at most
+    /// `2 * size` elements and is allocated once.
+    ///
+    /// Iterator element type is `U`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// **Panics** if `size` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // a moving-average filter
+    /// let signal = vec![1., 2., 6., 3., 3.];
+    /// itertools::assert_equal(signal.into_iter().map_windowed(3, |w| w.iter().sum::<f64>() / 3.),
+    ///                         vec![3., 11. / 3., 4.]);
+    ///
+    /// // elements that are not `Clone`
+    /// struct Reading(u32);
+    /// let rises = (0..5).map(|i| Reading(i * i))
+    ///                   .map_windowed(2, |w| w[1].0 - w[0].0);
+    /// itertools::assert_equal(rises, vec![1, 3, 5, 7]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn map_windowed<F, U>(self, size: usize, f: F) -> MapWindowed<Self, F>
+        where Self: Sized,
+              F: FnMut(&[Self::Item]) -> U
+    {
+        map_windowed::map_windowed(self, size, f)
+    }
     /// Return an iterator that groups the items in tuples of a specific size
     /// (up to 12).
     ///
@@ -774,6 +1840,37 @@ pub trait Itertools : Iterator {
         tuple_impl::tuples(self)
     }
 
+    /// Return an iterator that groups the items in arrays of size `N`.
+    ///
+    /// Unlike [`.tuples()`](Itertools::tuples), the size is not limited to
+    /// 12, and unlike [`.chunks()`](Itertools::chunks), no allocation is
+    /// needed.
+    ///
+    /// Iterator element type is `[Self::Item; N]`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// **Panics** if `N` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut it = (1..8).arrays();
+    /// assert_eq!(Some([1, 2, 3]), it.next());
+    /// assert_eq!(Some([4, 5, 6]), it.next());
+    /// assert_eq!(None, it.next());
+    /// itertools::assert_equal(it.into_remainder(), vec![7]);
+    /// ```
+    ///
+    /// See also [`ArrayChunks::into_remainder`]. This method is not called
+    /// `array_chunks`, to not collide with the unstable
+    /// `Iterator::array_chunks` of the standard library.
+    fn arrays<const N: usize>(self) -> ArrayChunks<Self, N>
+        where Self: Sized
+    {
+        array_impl::arrays(self)
+    }
+
     /// Split into an iterator pair that both yield all elements from
     /// the original iterator.
     ///
@@ -799,6 +1896,71 @@ pub trait Itertools : Iterator {
         tee::new(self)
     }
 
+    /// Split into an iterator pair that both yield all elements from the
+    /// original iterator, where neither half may get more than `max_buffer`
+    /// elements ahead of the other.
+    ///
+    /// Like with [`.tee()`](Itertools::tee), the elements that one half has
+    /// read but the other hasn't yet are buffered. Once `max_buffer` elements
+    /// are buffered, the half that is ahead yields `Err(TeeBufferFull)`
+    /// instead of reading more, until the other half catches up. No element
+    /// is lost: the next call after the other half has advanced yields the
+    /// next element as `Ok`. Calling `next` on the leading half in a loop
+    /// without advancing the other one yields errors forever.
+    ///
+    /// Iterator element type is `Result<Self::Item, TeeBufferFull>`.
+    ///
+    /// **Panics** if `max_buffer` is 0.
+    ///
+    /// ```
+    /// use itertools::{Itertools, TeeBufferFull};
+    ///
+    /// let (mut t1, mut t2) = (0..4).tee_bounded(2);
+    /// assert_eq!(t1.next(), Some(Ok(0)));
+    /// assert_eq!(t1.next(), Some(Ok(1)));
+    /// assert_eq!(t1.next(), Some(Err(TeeBufferFull)));
+    /// assert_eq!(t2.next(), Some(Ok(0)));
+    /// assert_eq!(t1.next(), Some(Ok(2)));
+    /// itertools::assert_equal(t2, vec![Ok(1), Ok(2), Ok(3)]);
+    /// itertools::assert_equal(t1, vec![Ok(3)]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn tee_bounded(self, max_buffer: usize) -> (TeeBounded<Self>, TeeBounded<Self>)
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        tee::new_bounded(self, max_buffer)
+    }
+
+    /// Split into `n` iterators that all yield all elements from the
+    /// original iterator.
+    ///
+    /// This generalizes [`.tee()`](Itertools::tee) to any number of
+    /// handles. The elements that some handle has read but another hasn't
+    /// yet are buffered, so memory use is bounded by how far the fastest
+    /// handle is ahead of the slowest one. A dropped handle no longer holds
+    /// elements back.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut tees = (0..4).tee_n(3);
+    /// assert_eq!(tees[0].next(), Some(0));
+    /// assert_eq!(tees[1].next(), Some(0));
+    /// let last = tees.pop().unwrap();
+    /// itertools::assert_equal(last, 0..4);
+    /// itertools::assert_equal(tees.remove(0), 1..4);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn tee_n(self, n: usize) -> Vec<TeeN<Self>>
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        tee::new_n(self, n)
+    }
+
     /// Return an iterator adaptor that steps `n` elements in the base iterator
     /// for each iteration.
     ///
@@ -837,6 +1999,84 @@ pub trait Itertools : Iterator {
         adaptors::map_into(self)
     }
 
+    /// Return an iterator adaptor that applies `f_true` to every element for
+    /// which `pred` returns `true`, and `f_false` to every other element.
+    ///
+    /// Iterator element type is `Either<L, R>`: the results of `f_true` are
+    /// wrapped in `Left`, those of `f_false` in `Right`. Use
+    /// [`.merge_either()`](Itertools::merge_either) to collapse them back
+    /// when both closures return the same type.
+    ///
+    /// ```
+    /// use itertools::{Either, Itertools};
+    ///
+    /// let it = vec![1, -2, 3].into_iter()
+    ///     .map_either(|&x| x > 0, |x| x * 10, |x| format!("neg {}", -x));
+    /// itertools::assert_equal(it, vec![
+    ///     Either::Left(10),
+    ///     Either::Right("neg 2".to_string()),
+    ///     Either::Left(30),
+    /// ]);
+    /// ```
+    fn map_either<P, F, G, L, R>(self, pred: P, f_true: F, f_false: G) -> MapEither<Self, P, F, G>
+        where Self: Sized,
+              P: FnMut(&Self::Item) -> bool,
+              F: FnMut(Self::Item) -> L,
+              G: FnMut(Self::Item) -> R,
+    {
+        adaptors::map_either(self, pred, f_true, f_false)
+    }
+
+    /// Return an iterator adaptor that unwraps every `Either<T, T>` element
+    /// into its `T`, whichever side it is on.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..5)
+    ///     .map_either(|x| x % 2 == 0, |x| x / 2, |x| x * 100)
+    ///     .merge_either();
+    /// itertools::assert_equal(it, vec![0, 100, 1, 300, 2]);
+    /// ```
+    fn merge_either<T>(self) -> MergeEither<Self>
+        where Self: Sized + Iterator<Item = Either<T, T>>,
+    {
+        adaptors::merge_either(self)
+    }
+
+    /// Return an iterator adaptor that applies `f` to every element on a
+    /// pool of `n` worker threads, yielding the results in input order.
+    ///
+    /// At most `2 * n` elements are in flight at any time; the adapted
+    /// iterator is only advanced as results are consumed. This is meant for
+    /// blocking, IO-bound functions such as DNS lookups or file system
+    /// queries.
+    ///
+    /// If `f` panics, the panic is resumed on the consuming thread when its
+    /// result is due. Dropping the adaptor waits for the elements in flight
+    /// to finish.
+    ///
+    /// Iterator element type is `U`.
+    ///
+    /// **Panics** if `n` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lengths = vec!["a", "bbb", "cc"].into_iter()
+    ///     .map_parallel(2, |s| s.len());
+    /// itertools::assert_equal(lengths, vec![1, 3, 2]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn map_parallel<F, U>(self, n: usize, f: F) -> MapParallel<Self, U>
+        where Self: Sized,
+              Self::Item: Send + 'static,
+              F: Fn(Self::Item) -> U + Send + Sync + 'static,
+              U: Send + 'static,
+    {
+        map_parallel::map_parallel(self, n, f)
+    }
+
     /// See [`.map_ok()`](Itertools::map_ok).
     #[deprecated(note="Use .map_ok() instead", since="0.10.0")]
     fn map_results<F, T, U, E>(self, f: F) -> MapOk<Self, F>
@@ -889,15 +2129,56 @@ pub trait Itertools : Iterator {
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let input = vec![Ok(22), Err(false), Ok(11)];
-    /// let it = input.into_iter().filter_map_ok(|i| if i > 20 { Some(i * 2) } else { None });
-    /// itertools::assert_equal(it, vec![Ok(44), Err(false)]);
+    /// let input = vec![Ok(22), Err(false), Ok(11)];
+    /// let it = input.into_iter().filter_map_ok(|i| if i > 20 { Some(i * 2) } else { None });
+    /// itertools::assert_equal(it, vec![Ok(44), Err(false)]);
+    /// ```
+    fn filter_map_ok<F, T, U, E>(self, f: F) -> FilterMapOk<Self, F>
+        where Self: Iterator<Item = Result<T, E>> + Sized,
+              F: FnMut(T) -> Option<U>,
+    {
+        adaptors::filter_map_ok(self, f)
+    }
+
+    /// Return an iterator adaptor that applies a fallible transformation to
+    /// every `Result::Ok` value. `Result::Err` values are unchanged, and an
+    /// `Err` returned by the closure is yielded in place of its value.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec![Ok("1"), Err("io"), Ok("x"), Ok("3")];
+    /// let it = input.into_iter()
+    ///     .and_then_ok(|s| s.parse::<i32>().map_err(|_| "parse"));
+    /// itertools::assert_equal(it, vec![Ok(1), Err("io"), Err("parse"), Ok(3)]);
+    /// ```
+    fn and_then_ok<F, T, U, E>(self, f: F) -> AndThenOk<Self, F>
+        where Self: Iterator<Item = Result<T, E>> + Sized,
+              F: FnMut(T) -> Result<U, E>,
+    {
+        adaptors::and_then_ok(self, f)
+    }
+
+    /// Return an iterator adaptor that transforms every `Result::Ok` value
+    /// with the provided closure, and stops at the first one for which it
+    /// returns `None`. `Result::Err` values are unchanged.
+    ///
+    /// This is the `Result` counterpart of [`Iterator::map_while`]. Like it,
+    /// the adaptor is not fused: after a `None`, it may yield more elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec![Ok(1), Err(false), Ok(2), Ok(-3), Ok(4)];
+    /// let it = input.into_iter()
+    ///     .map_while_ok(|i| if i > 0 { Some(i * 10) } else { None });
+    /// itertools::assert_equal(it, vec![Ok(10), Err(false), Ok(20)]);
     /// ```
-    fn filter_map_ok<F, T, U, E>(self, f: F) -> FilterMapOk<Self, F>
+    fn map_while_ok<F, T, U, E>(self, f: F) -> MapWhileOk<Self, F>
         where Self: Iterator<Item = Result<T, E>> + Sized,
               F: FnMut(T) -> Option<U>,
     {
-        adaptors::filter_map_ok(self, f)
+        adaptors::map_while_ok(self, f)
     }
 
     /// Return an iterator adaptor that flattens every `Result::Ok` value into
@@ -906,6 +2187,9 @@ pub trait Itertools : Iterator {
     /// This is useful when you have some common error type for your crate and
     /// need to propogate it upwards, but the `Result::Ok` case needs to be flattened.
     ///
+    /// The adaptor is double-ended if both the iterator and the `Result::Ok`
+    /// values' iterators are, so it can be reversed with `.rev()`.
+    ///
     /// ```
     /// use itertools::Itertools;
     ///
@@ -914,8 +2198,10 @@ pub trait Itertools : Iterator {
     /// itertools::assert_equal(it.clone(), vec![Ok(0), Ok(1), Err(false), Ok(2), Ok(3)]);
     /// 
     /// // This can also be used to propogate errors when collecting.
-    /// let output_result: Result<Vec<i32>, bool> = it.collect();
+    /// let output_result: Result<Vec<i32>, bool> = it.clone().collect();
     /// assert_eq!(output_result, Err(false));
+    ///
+    /// itertools::assert_equal(it.rev(), vec![Ok(3), Ok(2), Err(false), Ok(1), Ok(0)]);
     /// ```
     fn flatten_ok<T, E>(self) -> FlattenOk<Self, T, E>
         where Self: Iterator<Item = Result<T, E>> + Sized,
@@ -1062,6 +2348,99 @@ pub trait Itertools : Iterator {
         kmerge_by(self, first)
     }
 
+    /// Return an iterator adaptor that flattens an iterator of iterators by
+    /// merging them in ascending order, like [`.kmerge()`](Itertools::kmerge),
+    /// but pairs each element with the index of the iterator it came from.
+    ///
+    /// Indices count every base iterator, including empty ones, in the order
+    /// they are yielded by `self`.
+    ///
+    /// Iterator element type is `(usize, <Self::Item as IntoIterator>::Item)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = vec![1, 4];
+    /// let b = vec![];
+    /// let c = vec![2, 3, 5];
+    /// let it = vec![a, b, c].into_iter().kmerge_tagged();
+    /// itertools::assert_equal(it, vec![(0, 1), (2, 2), (2, 3), (0, 4), (2, 5)]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn kmerge_tagged(self) -> KMergeTagged<<Self::Item as IntoIterator>::IntoIter>
+        where Self: Sized,
+              Self::Item: IntoIterator,
+              <Self::Item as IntoIterator>::Item: PartialOrd,
+    {
+        kmerge_impl::kmerge_tagged_by(self, kmerge_impl::KMergeByLt)
+    }
+
+    /// Return an iterator adaptor that flattens an iterator of iterators by
+    /// merging them according to the given closure, like
+    /// [`.kmerge_by()`](Itertools::kmerge_by), but pairs each element with the
+    /// index of the iterator it came from.
+    ///
+    /// The closure `first` is called with two elements *a*, *b* (without
+    /// their indices) and should return `true` if *a* is ordered before *b*.
+    ///
+    /// Iterator element type is `(usize, <Self::Item as IntoIterator>::Item)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = vec![-1, 3, -6];
+    /// let b = vec![2, -4, 5];
+    /// let it = vec![a, b].into_iter().kmerge_tagged_by(|a: &i32, b: &i32| a.abs() < b.abs());
+    /// itertools::assert_equal(it.map(|(index, _)| index), vec![0, 1, 0, 1, 1, 0]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn kmerge_tagged_by<F>(self, first: F)
+        -> KMergeTaggedBy<<Self::Item as IntoIterator>::IntoIter, F>
+        where Self: Sized,
+              Self::Item: IntoIterator,
+              F: FnMut(&<Self::Item as IntoIterator>::Item,
+                       &<Self::Item as IntoIterator>::Item) -> bool
+    {
+        kmerge_impl::kmerge_tagged_by(self, first)
+    }
+
+    /// Return an iterator adaptor that flattens an iterator of iterators by
+    /// always taking the next element from the iterator whose current head
+    /// has the least priority.
+    ///
+    /// `priority` is called once on every element when it becomes the head
+    /// of its iterator. Unlike [`.kmerge_by()`](Itertools::kmerge_by), the
+    /// priorities need not be consistent with the order of the elements
+    /// within each iterator: this chooses between the heads, it doesn't sort.
+    /// Heads of equal priority are taken in the order they became heads, so
+    /// with a constant priority the iterators are taken from in turn.
+    ///
+    /// Iterator element type is `<Self::Item as IntoIterator>::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // deadline-based merging of event queues
+    /// let net = vec![("net", 5), ("net", 1)];
+    /// let disk = vec![("disk", 3), ("disk", 9)];
+    /// let events = vec![net, disk].into_iter().merge_by_priority(|&(_, deadline)| deadline);
+    /// itertools::assert_equal(events, vec![("disk", 3), ("net", 5), ("net", 1), ("disk", 9)]);
+    ///
+    /// // a fair round-robin
+    /// let it = vec![0..3, 10..12, 20..21].into_iter().merge_by_priority(|_| ());
+    /// itertools::assert_equal(it, vec![0, 10, 20, 1, 11, 2]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn merge_by_priority<P, F>(self, priority: F)
+        -> KMergeByPriority<<Self::Item as IntoIterator>::IntoIter, F, P>
+        where Self: Sized,
+              Self::Item: IntoIterator,
+              F: FnMut(&<Self::Item as IntoIterator>::Item) -> P,
+              P: Ord,
+    {
+        merge_by_priority(self, priority)
+    }
+
     /// Return an iterator adaptor that iterates over the cartesian product of
     /// the element sets of two iterators `self` and `J`.
     ///
@@ -1082,6 +2461,41 @@ pub trait Itertools : Iterator {
         adaptors::cartesian_product(self, other.into_iter())
     }
 
+    /// Return an iterator adaptor that iterates over the cartesian product of
+    /// the element sets of two iterators `self` and `J`, in diagonal order.
+    ///
+    /// The pairs are enumerated by increasing sum of their indices, as in
+    /// Cantor's pairing: `(a0, b0)`, then `(a0, b1)`, `(a1, b0)`, then
+    /// `(a0, b2)`, `(a1, b1)`, `(a2, b0)`, and so on. Unlike
+    /// [`.cartesian_product()`](Itertools::cartesian_product), which never
+    /// gets past the first element of `self` when `other` is infinite, this
+    /// reaches every pair eventually even if either or both iterators are
+    /// infinite. The elements of both iterators are buffered as they are
+    /// read and cloned into the pairs.
+    ///
+    /// Iterator element type is `(Self::Item, J::Item)`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..3).cartesian_product_diagonal("ab".chars());
+    /// itertools::assert_equal(it, vec![(0, 'a'), (0, 'b'), (1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+    ///
+    /// let pairs = (0..).cartesian_product_diagonal(0..).take(6);
+    /// itertools::assert_equal(pairs, vec![(0, 0), (0, 1), (1, 0), (0, 2), (1, 1), (2, 0)]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn cartesian_product_diagonal<J>(self, other: J) -> DiagonalProduct<Self, J::IntoIter>
+        where Self: Sized,
+              Self::Item: Clone,
+              J: IntoIterator,
+              J::Item: Clone,
+    {
+        diagonal_product::diagonal_product(self, other.into_iter())
+    }
+
     /// Return an iterator adaptor that iterates over the cartesian product of
     /// all subiterators returned by meta-iterator `self`.
     ///
@@ -1117,6 +2531,52 @@ pub trait Itertools : Iterator {
         adaptors::multi_cartesian_product(self)
     }
 
+    /// Return an iterator adaptor that merge-joins all subiterators returned
+    /// by meta-iterator `self`, which must each be sorted by `key`: like a
+    /// full outer join, each element is a row of the items whose keys are
+    /// the smallest that remain, with `None` for every subiterator that has
+    /// no item with that key.
+    ///
+    /// Rows are yielded in ascending order of key, and a row holds the item
+    /// of the subiterator at the same position. If a subiterator has several
+    /// items with the same key, they are joined in successive rows.
+    ///
+    /// This generalizes [`.merge_join_by()`](Itertools::merge_join_by) to any
+    /// number of iterators, which must yield the same `Item` type.
+    ///
+    /// The iterator element type is `Vec<Option<T>>`, where `T` is the
+    /// iterator element of the subiterators.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let names = vec![(1, "ann"), (2, "bob"), (4, "dee")];
+    /// let ages = vec![(1, "31"), (3, "27"), (4, "45")];
+    /// let towns = vec![(2, "Oslo"), (4, "Lima")];
+    ///
+    /// let rows = vec![names, ages, towns]
+    ///     .into_iter()
+    ///     .multi_merge_join_by_key(|&(id, _)| id)
+    ///     .map(|row| row.into_iter().map(|col| col.map(|(_, value)| value)).collect_vec());
+    ///
+    /// itertools::assert_equal(rows, vec![
+    ///     vec![Some("ann"), Some("31"), None],
+    ///     vec![Some("bob"), None, Some("Oslo")],
+    ///     vec![None, Some("27"), None],
+    ///     vec![Some("dee"), Some("45"), Some("Lima")],
+    /// ]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn multi_merge_join_by_key<K, F>(self, key: F)
+        -> MultiMergeJoinByKey<<Self::Item as IntoIterator>::IntoIter, K, F>
+        where Self: Sized,
+              Self::Item: IntoIterator,
+              F: FnMut(&<Self::Item as IntoIterator>::Item) -> K,
+              K: Ord,
+    {
+        multi_merge_join::multi_merge_join_by_key(self, key)
+    }
+
     /// Return an iterator adaptor that uses the passed-in closure to
     /// optionally merge together consecutive elements.
     ///
@@ -1196,6 +2656,32 @@ pub trait Itertools : Iterator {
         adaptors::dedup_by(self, cmp)
     }
 
+    /// Remove duplicates from sections of consecutive identical elements,
+    /// determining equality by comparing the keys computed by `key`.
+    /// If the iterator is sorted by key, all keys will be unique.
+    ///
+    /// To also count the duplicates, use
+    /// [`.run_lengths_by_key()`](Itertools::run_lengths_by_key).
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![(0, 1.), (1, 1.), (0, 2.), (0, 3.), (1, 3.), (1, 2.), (2, 2.)];
+    /// itertools::assert_equal(data.into_iter().dedup_by_key(|x| x.1),
+    ///                         vec![(0, 1.), (0, 2.), (0, 3.), (1, 2.)]);
+    /// ```
+    fn dedup_by_key<K, F>(self, key: F) -> DedupByKey<Self, F>
+        where Self: Sized,
+              K: PartialEq,
+              F: FnMut(&Self::Item) -> K,
+    {
+        adaptors::dedup_by_key(self, key)
+    }
+
     /// Remove duplicates from sections of consecutive identical elements, while keeping a count of
     /// how many repeated elements were present.
     /// If the iterator is sorted, all elements will be unique.
@@ -1242,6 +2728,110 @@ pub trait Itertools : Iterator {
         adaptors::dedup_by_with_count(self, cmp)
     }
 
+    /// Return an iterator adaptor that run-length encodes the iterator:
+    /// every run of equal consecutive elements is yielded as its length and
+    /// its first element.
+    ///
+    /// This is the same as [`.dedup_with_count()`](Itertools::dedup_with_count);
+    /// see [`.run_lengths_by()`](Itertools::run_lengths_by) and
+    /// [`.run_lengths_by_key()`](Itertools::run_lengths_by_key) for other
+    /// notions of equality.
+    ///
+    /// Iterator element type is `(usize, Self::Item)`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// itertools::assert_equal("aaabccaa".chars().run_lengths(),
+    ///                         vec![(3, 'a'), (1, 'b'), (2, 'c'), (2, 'a')]);
+    /// ```
+    fn run_lengths(self) -> RunLengths<Self>
+        where Self: Sized,
+              Self::Item: PartialEq,
+    {
+        adaptors::dedup_with_count(self)
+    }
+
+    /// Return an iterator adaptor that run-length encodes the iterator,
+    /// determining equality of consecutive elements using a comparison
+    /// function.
+    ///
+    /// Each run is yielded as its length and its first element.
+    ///
+    /// Iterator element type is `(usize, Self::Item)`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = vec!["Apple", "apple", "APPLE", "pear"];
+    /// itertools::assert_equal(words.into_iter().run_lengths_by(|a, b| a.eq_ignore_ascii_case(b)),
+    ///                         vec![(3, "Apple"), (1, "pear")]);
+    /// ```
+    fn run_lengths_by<Cmp>(self, cmp: Cmp) -> RunLengthsBy<Self, Cmp>
+        where Self: Sized,
+              Cmp: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        adaptors::dedup_by_with_count(self, cmp)
+    }
+
+    /// Return an iterator adaptor that run-length encodes the iterator,
+    /// treating consecutive elements as equal when they map to equal keys.
+    ///
+    /// Each run is yielded as its length and its first element.
+    ///
+    /// Iterator element type is `(usize, Self::Item)`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 3, 5, 2, 4, 7];
+    /// itertools::assert_equal(data.into_iter().run_lengths_by_key(|x| x % 2),
+    ///                         vec![(3, 1), (2, 2), (1, 7)]);
+    /// ```
+    #[doc(alias = "dedup_by_key_with_count")]
+    fn run_lengths_by_key<K, F>(self, key: F) -> RunLengthsByKey<Self, F>
+        where Self: Sized,
+              K: PartialEq,
+              F: FnMut(&Self::Item) -> K,
+    {
+        adaptors::run_lengths_by_key(self, key)
+    }
+
+    /// Return an iterator adaptor that run-length decodes the iterator: for
+    /// every `(count, element)` pair, `element` is yielded `count` times.
+    ///
+    /// This is the inverse of [`.run_lengths()`](Itertools::run_lengths).
+    /// Pairs with a count of zero yield nothing. The length of a run is only
+    /// known once its pair is read, so the size hint covers the current run
+    /// and is only exact once all pairs have been read.
+    ///
+    /// Iterator element type is `T`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let runs = vec![(3, 'a'), (0, 'x'), (1, 'b'), (2, 'c')];
+    /// itertools::assert_equal(runs.into_iter().run_length_decode(), "aaabcc".chars());
+    ///
+    /// let text = "aaaabbbcca";
+    /// let decoded: String = text.chars().run_lengths().run_length_decode().collect();
+    /// assert_eq!(decoded, text);
+    /// ```
+    fn run_length_decode<T>(self) -> RunLengthDecode<Self, T>
+        where Self: Iterator<Item = (usize, T)> + Sized,
+              T: Clone,
+    {
+        run_length_decode::run_length_decode(self)
+    }
+
     /// Return an iterator adaptor that produces elements that appear more than once during the
     /// iteration. Duplicates are detected using hash and equality.
     ///
@@ -1264,6 +2854,31 @@ pub trait Itertools : Iterator {
         duplicates_impl::duplicates(self)
     }
 
+    /// Return an iterator adaptor that produces elements that appear more than once during the
+    /// iteration, using `hash_builder` to hash the elements.
+    ///
+    /// This behaves like [`.duplicates()`](Itertools::duplicates), but allows plugging in a
+    /// faster or deterministic hasher.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// let data = vec![10, 20, 30, 20, 40, 10, 50];
+    /// let hasher = BuildHasherDefault::<DefaultHasher>::default();
+    /// itertools::assert_equal(data.into_iter().duplicates_with_hasher(hasher),
+    ///                         vec![20, 10]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn duplicates_with_hasher<S>(self, hash_builder: S) -> Duplicates<Self, S>
+        where Self: Sized,
+              Self::Item: Eq + Hash,
+              S: BuildHasher
+    {
+        duplicates_impl::duplicates_with_hasher(self, hash_builder)
+    }
+
     /// Return an iterator adaptor that produces elements that appear more than once during the
     /// iteration. Duplicates are detected using hash and equality.
     ///
@@ -1290,6 +2905,32 @@ pub trait Itertools : Iterator {
         duplicates_impl::duplicates_by(self, f)
     }
 
+    /// Return an iterator adaptor that produces elements that appear more than once during the
+    /// iteration, comparing the keys produced by `f` and using `hash_builder` to hash them.
+    ///
+    /// This behaves like [`.duplicates_by()`](Itertools::duplicates_by), but allows plugging in
+    /// a faster or deterministic hasher.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// let data = vec!["a", "bb", "aa", "c", "ccc"];
+    /// let hasher = BuildHasherDefault::<DefaultHasher>::default();
+    /// itertools::assert_equal(data.into_iter().duplicates_by_with_hasher(|s| s.len(), hasher),
+    ///                         vec!["aa", "c"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn duplicates_by_with_hasher<V, F, S>(self, f: F, hash_builder: S) -> DuplicatesBy<Self, V, F, S>
+        where Self: Sized,
+              V: Eq + Hash,
+              F: FnMut(&Self::Item) -> V,
+              S: BuildHasher
+    {
+        duplicates_impl::duplicates_by_with_hasher(self, f, hash_builder)
+    }
+
     /// Return an iterator adaptor that filters out elements that have
     /// already been produced once during the iteration. Duplicates
     /// are detected using hash and equality.
@@ -1316,6 +2957,32 @@ pub trait Itertools : Iterator {
         unique_impl::unique(self)
     }
 
+    /// Return an iterator adaptor that filters out elements that have
+    /// already been produced once during the iteration, using `hash_builder`
+    /// to hash the elements.
+    ///
+    /// This behaves like [`.unique()`](Itertools::unique), but allows
+    /// plugging in a faster or deterministic hasher.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// let data = vec![10, 20, 30, 20, 40, 10, 50];
+    /// let hasher = BuildHasherDefault::<DefaultHasher>::default();
+    /// itertools::assert_equal(data.into_iter().unique_with_hasher(hasher),
+    ///                         vec![10, 20, 30, 40, 50]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn unique_with_hasher<S>(self, hash_builder: S) -> Unique<Self, S>
+        where Self: Sized,
+              Self::Item: Clone + Eq + Hash,
+              S: BuildHasher
+    {
+        unique_impl::unique_with_hasher(self, hash_builder)
+    }
+
     /// Return an iterator adaptor that filters out elements that have
     /// already been produced once during the iteration.
     ///
@@ -1334,13 +3001,106 @@ pub trait Itertools : Iterator {
     /// itertools::assert_equal(data.into_iter().unique_by(|s| s.len()),
     ///                         vec!["a", "bb", "ccc"]);
     /// ```
-    #[cfg(feature = "use_std")]
-    fn unique_by<V, F>(self, f: F) -> UniqueBy<Self, V, F>
+    #[cfg(feature = "use_std")]
+    fn unique_by<V, F>(self, f: F) -> UniqueBy<Self, V, F>
+        where Self: Sized,
+              V: Eq + Hash,
+              F: FnMut(&Self::Item) -> V
+    {
+        unique_impl::unique_by(self, f)
+    }
+
+    /// Return an iterator adaptor that filters out elements that have
+    /// already been produced once during the iteration, comparing the keys
+    /// produced by `f` and using `hash_builder` to hash them.
+    ///
+    /// This behaves like [`.unique_by()`](Itertools::unique_by), but allows
+    /// plugging in a faster or deterministic hasher.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// let data = vec!["a", "bb", "aa", "c", "ccc"];
+    /// let hasher = BuildHasherDefault::<DefaultHasher>::default();
+    /// itertools::assert_equal(data.into_iter().unique_by_with_hasher(|s| s.len(), hasher),
+    ///                         vec!["a", "bb", "ccc"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn unique_by_with_hasher<V, F, S>(self, f: F, hash_builder: S) -> UniqueBy<Self, V, F, S>
+        where Self: Sized,
+              V: Eq + Hash,
+              F: FnMut(&Self::Item) -> V,
+              S: BuildHasher
+    {
+        unique_impl::unique_by_with_hasher(self, f, hash_builder)
+    }
+
+    /// Return an iterator adaptor that filters out elements that have
+    /// already been produced once during the iteration, using a fixed amount
+    /// of memory.
+    ///
+    /// Unlike [`.unique()`](Itertools::unique), which stores every distinct
+    /// element, the elements seen so far are recorded in a Bloom filter sized
+    /// for `expected_items` distinct elements with a `false_positive_rate`
+    /// chance of mistaking a new element for a duplicate. This makes it
+    /// usable on huge or unbounded streams, with this tradeoff:
+    ///
+    /// - Duplicates are always filtered out.
+    /// - A distinct element is wrongly filtered out with a probability of
+    ///   about `false_positive_rate` while at most `expected_items` distinct
+    ///   elements have been seen, and increasingly often after that.
+    ///
+    /// The filter takes about `-1.44 * log2(false_positive_rate)` bits per
+    /// expected item; for example, 1.2 MB for a million items at 1%.
+    ///
+    /// The iterator is stable, returning the retained items in the order in
+    /// which they occur in the adapted iterator.
+    ///
+    /// **Panics** if `false_positive_rate` is not strictly between 0 and 1.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![10, 20, 30, 20, 40, 10, 50];
+    /// let unique = data.into_iter().unique_approx(100, 0.001).collect_vec();
+    /// // duplicates are never let through
+    /// assert!(unique.iter().all_unique());
+    /// ```
+    #[cfg(feature = "unique_approx")]
+    fn unique_approx(self, expected_items: usize, false_positive_rate: f64) -> UniqueApprox<Self>
+        where Self: Sized,
+              Self::Item: Hash
+    {
+        unique_approx::unique_approx(self, expected_items, false_positive_rate)
+    }
+
+    /// Return an iterator adaptor that filters out elements that have
+    /// already been produced once during the iteration, using a fixed amount
+    /// of memory and `hash_builder` to hash the elements.
+    ///
+    /// See [`.unique_approx()`](Itertools::unique_approx) for more
+    /// information.
+    ///
+    /// ```
+    /// use std::collections::hash_map::RandomState;
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![10, 20, 30, 20, 40, 10, 50];
+    /// let unique = data.into_iter()
+    ///     .unique_approx_with_hasher(100, 0.001, RandomState::new())
+    ///     .collect_vec();
+    /// assert!(unique.iter().all_unique());
+    /// ```
+    #[cfg(feature = "unique_approx")]
+    fn unique_approx_with_hasher<S>(self, expected_items: usize, false_positive_rate: f64, hash_builder: S)
+        -> UniqueApprox<Self, S>
         where Self: Sized,
-              V: Eq + Hash,
-              F: FnMut(&Self::Item) -> V
+              Self::Item: Hash,
+              S: BuildHasher
     {
-        unique_impl::unique_by(self, f)
+        unique_approx::unique_approx_with_hasher(self, expected_items, false_positive_rate, hash_builder)
     }
 
     /// Return an iterator adaptor that borrows from this iterator and
@@ -1387,6 +3147,66 @@ pub trait Itertools : Iterator {
         adaptors::take_while_ref(self, accept)
     }
 
+    /// Return an iterator adaptor that consumes elements while `accept`
+    /// returns `true`, and also yields the first element for which it
+    /// returns `false`, then stops.
+    ///
+    /// This is the "take until and including the terminator" pattern, which
+    /// [`Iterator::take_while`] cannot express because it drops the first
+    /// rejected element. Use [`.by_ref()`](Iterator::by_ref) to continue with
+    /// the rest of the iterator afterwards.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut tokens = "let x = 1 ; let y = 2 ;".split(' ');
+    /// let first = tokens.by_ref().take_while_inclusive(|t| *t != ";").join(" ");
+    /// assert_eq!(first, "let x = 1 ;");
+    /// let second = tokens.take_while_inclusive(|t| *t != ";").join(" ");
+    /// assert_eq!(second, "let y = 2 ;");
+    ///
+    /// // all elements are taken if none is rejected
+    /// itertools::assert_equal((1..4).take_while_inclusive(|x| *x < 10), vec![1, 2, 3]);
+    /// ```
+    fn take_while_inclusive<F>(self, accept: F) -> TakeWhileInclusive<Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> bool
+    {
+        take_while_inclusive::take_while_inclusive(self, accept)
+    }
+
+    /// Return an iterator adaptor that skips elements while `reject` returns
+    /// `true`, also skips the first element for which it returns `false`,
+    /// and then yields all remaining elements.
+    ///
+    /// This is the counterpart of
+    /// [`.take_while_inclusive()`](Itertools::take_while_inclusive): it drops
+    /// a prefix together with its terminator, such as a header and the line
+    /// that ends it.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let text = "From: me\nTo: you\n\nHello!\nBye.";
+    /// let body = text.lines().skip_while_inclusive(|line| !line.is_empty());
+    /// itertools::assert_equal(body, vec!["Hello!", "Bye."]);
+    ///
+    /// // nothing is yielded if no element is rejected
+    /// assert_eq!((1..4).skip_while_inclusive(|x| *x < 10).next(), None);
+    /// ```
+    fn skip_while_inclusive<F>(self, reject: F) -> SkipWhileInclusive<Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> bool
+    {
+        skip_while_inclusive::skip_while_inclusive(self, reject)
+    }
+
     /// Return an iterator adaptor that filters `Option<A>` iterator elements
     /// and produces `A`. Stops on the first `None` encountered.
     ///
@@ -1407,6 +3227,35 @@ pub trait Itertools : Iterator {
         adaptors::while_some(self)
     }
 
+    /// Return an iterator adaptor that filters `Option<A>` iterator elements
+    /// and produces `A`, stopping on the first `None` encountered, like
+    /// [`.while_some()`](Itertools::while_some).
+    ///
+    /// Once it has ended, the adaptor reports whether the adapted iterator
+    /// was exhausted ([`is_complete`](WhileSomeComplete::is_complete)) or
+    /// yielded a `None` ([`stopped_at_none`](WhileSomeComplete::stopped_at_none)).
+    ///
+    /// Iterator element type is `A`, the unwrapped element.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut digits = "12x3".chars().map(|c| c.to_digit(10)).while_some_complete();
+    /// assert_eq!(digits.by_ref().sum::<u32>(), 3);
+    /// assert!(digits.stopped_at_none());
+    ///
+    /// let mut digits = "123".chars().map(|c| c.to_digit(10)).while_some_complete();
+    /// assert_eq!(digits.by_ref().sum::<u32>(), 6);
+    /// assert!(digits.is_complete());
+    /// ```
+    fn while_some_complete<A>(self) -> WhileSomeComplete<Self>
+        where Self: Sized + Iterator<Item = Option<A>>
+    {
+        adaptors::while_some_complete(self)
+    }
+
     /// Return an iterator adaptor that iterates over the combinations of the
     /// elements from an iterator.
     ///
@@ -1594,6 +3443,48 @@ pub trait Itertools : Iterator {
         powerset::powerset(self)
     }
 
+    /// Return an iterator that iterates through the powerset of the elements
+    /// from an iterator, reading the elements only as they are needed.
+    ///
+    /// The subsets are yielded in binary counting order: the `k`-th subset
+    /// holds the elements whose positions are the set bits of `k`. All
+    /// subsets of the first `n` elements come before any subset that
+    /// contains a later element, so the `n + 1`-th element is only read once
+    /// the `2^n` subsets of the first `n` are exhausted. Unlike
+    /// [`.powerset()`](Itertools::powerset), which yields the subsets by
+    /// size and needs all elements to yield the subsets of size two, this
+    /// works on infinite iterators, and every finite subset eventually
+    /// appears.
+    ///
+    /// Iterator element type is `Vec<Self::Item>`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let sets = (1..4).powerset_bfs().collect::<Vec<_>>();
+    /// itertools::assert_equal(sets, vec![
+    ///     vec![],
+    ///     vec![1],
+    ///     vec![2],
+    ///     vec![1, 2],
+    ///     vec![3],
+    ///     vec![1, 3],
+    ///     vec![2, 3],
+    ///     vec![1, 2, 3],
+    /// ]);
+    ///
+    /// // the first subset of naturals summing to 10
+    /// let found = (1..).powerset_bfs().find(|s| s.iter().sum::<u32>() == 10);
+    /// assert_eq!(found, Some(vec![1, 2, 3, 4]));
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn powerset_bfs(self) -> PowersetBfs<Self>
+        where Self: Sized,
+              Self::Item: Clone,
+    {
+        powerset::powerset_bfs(self)
+    }
+
     /// Return an iterator adaptor that pads the sequence to a minimum length of
     /// `min` by filling missing elements using a closure `f`.
     ///
@@ -1643,6 +3534,36 @@ pub trait Itertools : Iterator {
         with_position::with_position(self)
     }
 
+    /// Return an iterator adaptor that flags the first and the last element
+    /// of every run of consecutive elements with the same key.
+    ///
+    /// Iterator element type is `(is_first_of_group, is_last_of_group,
+    /// Self::Item)`. The groups are the same as with
+    /// [`.group_by()`](Itertools::group_by), but nothing is buffered beyond
+    /// one element of lookahead, which makes this suited for emitting group
+    /// headers and footers while streaming.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut out = String::new();
+    /// for (first, last, word) in vec!["ant", "ape", "bee", "cat", "cow"].into_iter()
+    ///     .mark_boundaries(|word| word.chars().next())
+    /// {
+    ///     if first { out.push('['); }
+    ///     out.push_str(word);
+    ///     out.push(if last { ']' } else { ' ' });
+    /// }
+    /// assert_eq!(out, "[ant ape][bee][cat cow]");
+    /// ```
+    fn mark_boundaries<K, F>(self, key: F) -> MarkBoundaries<Self, K, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> K,
+              K: PartialEq,
+    {
+        mark_boundaries::mark_boundaries(self, key)
+    }
+
     /// Return an iterator adaptor that yields the indices of all elements
     /// satisfying a predicate, counted from the start of the iterator.
     ///
@@ -1680,6 +3601,109 @@ pub trait Itertools : Iterator {
         adaptors::update(self, updater)
     }
 
+    /// Return an iterator adaptor that yields the running totals of the
+    /// iterator: the first element, then the sum of the first two, and so on.
+    ///
+    /// The first total is the first element itself, so no zero value is
+    /// needed and any type with `Add<Output = Self::Item>` works. Each total
+    /// is cloned: one copy is yielded and the other is kept for the next sum.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// itertools::assert_equal((1..=5).cumsum(), vec![1, 3, 6, 10, 15]);
+    ///
+    /// let balance = vec![10.0, -2.5, 4.0].into_iter().cumsum();
+    /// itertools::assert_equal(balance, vec![10.0, 7.5, 11.5]);
+    /// ```
+    fn cumsum(self) -> CumSum<Self>
+        where Self: Sized,
+              Self::Item: Clone + std::ops::Add<Output = Self::Item>,
+    {
+        cumsum::cumsum(self)
+    }
+
+    /// Return an iterator adaptor that yields the running totals of the
+    /// iterator, combining the total so far with the next element using `f`.
+    ///
+    /// The first total is the first element itself.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let running_max = vec![3, 1, 4, 1, 5].into_iter().cumsum_by(|a, b| a.max(b));
+    /// itertools::assert_equal(running_max, vec![3, 3, 4, 4, 5]);
+    ///
+    /// let wrapping = vec![200u8, 100, 10].into_iter().cumsum_by(u8::wrapping_add);
+    /// itertools::assert_equal(wrapping, vec![200, 44, 54]);
+    /// ```
+    fn cumsum_by<F>(self, f: F) -> CumSumBy<Self, F>
+        where Self: Sized,
+              Self::Item: Clone,
+              F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        cumsum::cumsum_by(self, f)
+    }
+
+    /// Return an iterator adaptor that yields the difference `b - a` of
+    /// every pair of consecutive elements `a`, `b`.
+    ///
+    /// This is the inverse of [`.cumsum()`](Itertools::cumsum), apart from
+    /// the first element. Every element is cloned once, to be kept for the
+    /// next difference, which is free for `Copy` types such as numbers and
+    /// references to them.
+    ///
+    /// Iterator element type is `<Self::Item as Sub>::Output`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let timestamps = vec![100, 130, 145, 210];
+    /// itertools::assert_equal(timestamps.iter().deltas(), vec![30, 15, 65]);
+    ///
+    /// let increasing = timestamps.into_iter().deltas().all(|d| d > 0);
+    /// assert!(increasing);
+    /// ```
+    fn deltas(self) -> Deltas<Self>
+        where Self: Sized,
+              Self::Item: Clone + std::ops::Sub,
+    {
+        deltas::deltas(self)
+    }
+
+    /// Return an iterator adaptor that yields `f(a, b)` for every pair of
+    /// consecutive elements `a`, `b`.
+    ///
+    /// The elements are passed by reference, and never cloned. To compare
+    /// two iterators instead, see
+    /// [`.first_divergence()`](Itertools::first_divergence).
+    ///
+    /// Iterator element type is `U`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let prices = vec![10.0, 12.0, 9.0];
+    /// let returns = prices.into_iter().diff_by(|a, b| (b - a) / a);
+    /// itertools::assert_equal(returns, vec![0.2, -0.25]);
+    ///
+    /// let words = vec!["apple", "apricot", "banana"];
+    /// let shared_prefix = words.iter().diff_by(|a, b| {
+    ///     a.chars().zip(b.chars()).take_while(|(x, y)| x == y).count()
+    /// });
+    /// itertools::assert_equal(shared_prefix, vec![2, 0]);
+    /// ```
+    fn diff_by<U, F>(self, f: F) -> DiffBy<Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> U,
+    {
+        deltas::diff_by(self, f)
+    }
+
     // non-adaptor methods
     /// Advances the iterator and returns the next items grouped in a tuple of
     /// a specific size (up to 12).
@@ -1755,6 +3779,82 @@ pub trait Itertools : Iterator {
         }
         None
     }
+
+    /// Find the position and value of the first element satisfying a
+    /// fallible predicate.
+    ///
+    /// Returns the first error of `pred`, if any comes before an element is
+    /// found. The iterator is not advanced past the first element found or
+    /// the first error.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = ["1", "22", "x", "333"];
+    /// let is_even = |s: &&str| s.parse::<u32>().map(|n| n % 2 == 0);
+    /// assert_eq!(data.iter().copied().try_find_position(is_even), Ok(Some((1, "22"))));
+    /// assert!(data.iter().copied().skip(2).try_find_position(is_even).is_err());
+    /// ```
+    fn try_find_position<P, E>(&mut self, mut pred: P) -> Result<Option<(usize, Self::Item)>, E>
+        where P: FnMut(&Self::Item) -> Result<bool, E>
+    {
+        for (index, elt) in self.enumerate() {
+            if pred(&elt)? {
+                return Ok(Some((index, elt)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Test whether any element satisfies a fallible predicate, like
+    /// [`Iterator::any`].
+    ///
+    /// Stops at the first element for which `pred` returns `Ok(true)`, or at
+    /// the first error, which is returned.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let is_big = |s: &str| s.parse::<u32>().map(|n| n > 100);
+    /// assert_eq!(["1", "200", "x"].iter().copied().try_any(is_big), Ok(true));
+    /// assert_eq!(["1", "2"].iter().copied().try_any(is_big), Ok(false));
+    /// assert!(["1", "x", "200"].iter().copied().try_any(is_big).is_err());
+    /// ```
+    fn try_any<P, E>(&mut self, mut pred: P) -> Result<bool, E>
+        where P: FnMut(Self::Item) -> Result<bool, E>
+    {
+        for elt in self {
+            if pred(elt)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Test whether all elements satisfy a fallible predicate, like
+    /// [`Iterator::all`].
+    ///
+    /// Stops at the first element for which `pred` returns `Ok(false)`, or at
+    /// the first error, which is returned.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let is_small = |s: &str| s.parse::<u32>().map(|n| n < 100);
+    /// assert_eq!(["1", "2"].iter().copied().try_all(is_small), Ok(true));
+    /// assert_eq!(["1", "200", "x"].iter().copied().try_all(is_small), Ok(false));
+    /// assert!(["1", "x", "200"].iter().copied().try_all(is_small).is_err());
+    /// ```
+    fn try_all<P, E>(&mut self, mut pred: P) -> Result<bool, E>
+        where P: FnMut(Self::Item) -> Result<bool, E>
+    {
+        for elt in self {
+            if !pred(elt)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
     /// Find the value of the first element satisfying a predicate or return the last element, if any.
     ///
     /// The iterator is not advanced past the first element found.
@@ -2014,6 +4114,28 @@ pub trait Itertools : Iterator {
         self.collect()
     }
 
+    /// `.try_collect_vec()` is a type specialization of
+    /// [`.try_collect()`](Itertools::try_collect), for convenience: it stops
+    /// at the first error, and otherwise collects the `Ok` values into a
+    /// `Vec`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let parsed = "1 2 3".split(' ').map(str::parse::<i32>).try_collect_vec();
+    /// assert_eq!(parsed, Ok(vec![1, 2, 3]));
+    ///
+    /// let parsed = "1 x 3".split(' ').map(str::parse::<i32>).try_collect_vec();
+    /// assert!(parsed.is_err());
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn try_collect_vec<T, E>(self) -> Result<Vec<T>, E>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+    {
+        self.collect()
+    }
+
     /// Assign to each reference in `self` from the `from` iterator,
     /// stopping at the shortest of the two iterators.
     ///
@@ -2049,6 +4171,10 @@ pub trait Itertools : Iterator {
     ///
     /// Use the `Display` implementation of each element.
     ///
+    /// Only the separators are preallocated for; use
+    /// [`.join_with_capacity()`](Itertools::join_with_capacity) to give an
+    /// estimate of the element lengths.
+    ///
     /// ```
     /// use itertools::Itertools;
     ///
@@ -2075,10 +4201,105 @@ pub trait Itertools : Iterator {
         }
     }
 
+    /// Combine all iterator elements into one String, separated by `sep`,
+    /// like [`.join()`](Itertools::join), preallocating the string for
+    /// elements of `estimate_per_item` bytes each.
+    ///
+    /// The number of elements is estimated by the lower bound of the size
+    /// hint. The string still grows as needed if the estimate is too low.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let ids = (0..1000).map(|i| format!("id{:04}", i));
+    /// let joined = ids.clone().join_with_capacity(",", 6);
+    /// assert_eq!(joined.len(), 1000 * 6 + 999);
+    /// assert_eq!(joined, ids.collect::<Vec<_>>().join(","));
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn join_with_capacity(&mut self, sep: &str, estimate_per_item: usize) -> String
+        where Self::Item: std::fmt::Display
+    {
+        let (lower, _) = self.size_hint();
+        let capacity = estimate_per_item.saturating_mul(lower)
+            .saturating_add(sep.len().saturating_mul(lower.saturating_sub(1)));
+        let mut result = String::with_capacity(capacity);
+        if let Some(first_elt) = self.next() {
+            write!(&mut result, "{}", first_elt).unwrap();
+            self.for_each(|elt| {
+                result.push_str(sep);
+                write!(&mut result, "{}", elt).unwrap();
+            });
+        }
+        result
+    }
+
+    /// Write all iterator elements into `writer`, separated by `sep`, like
+    /// [`.join()`](Itertools::join) but without building a `String`.
+    ///
+    /// Use the `Display` implementation of each element. Stops at the first
+    /// error returned by `writer`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut csv = Vec::new();
+    /// for row in &[[1, 2, 3], [4, 5, 6]] {
+    ///     row.iter().write_join(&mut csv, ",").unwrap();
+    ///     csv.push(b'\n');
+    /// }
+    /// assert_eq!(csv, b"1,2,3\n4,5,6\n");
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn write_join<W>(&mut self, writer: &mut W, sep: &str) -> std::io::Result<()>
+        where Self::Item: std::fmt::Display,
+              W: std::io::Write + ?Sized,
+    {
+        if let Some(first_elt) = self.next() {
+            write!(writer, "{}", first_elt)?;
+            for elt in self {
+                writer.write_all(sep.as_bytes())?;
+                write!(writer, "{}", elt)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write all iterator elements into the `fmt::Write` sink `writer`,
+    /// separated by `sep`, like [`.join()`](Itertools::join) but without
+    /// building a separate `String`.
+    ///
+    /// Use the `Display` implementation of each element. Stops at the first
+    /// error returned by `writer`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut line = String::from("values: ");
+    /// [1, 2, 3].iter().write_join_fmt(&mut line, ", ").unwrap();
+    /// assert_eq!(line, "values: 1, 2, 3");
+    /// ```
+    fn write_join_fmt<W>(&mut self, writer: &mut W, sep: &str) -> fmt::Result
+        where Self::Item: std::fmt::Display,
+              W: fmt::Write + ?Sized,
+    {
+        if let Some(first_elt) = self.next() {
+            write!(writer, "{}", first_elt)?;
+            for elt in self {
+                writer.write_str(sep)?;
+                write!(writer, "{}", elt)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Format all iterator elements, separated by `sep`.
     ///
     /// All elements are formatted (any formatting trait)
-    /// with `sep` inserted between each element.
+    /// with `sep` inserted between each element. The formatter, including
+    /// its width, precision and alternate flags, is passed on to each
+    /// element, so for example `{:?}` formats elements that only implement
+    /// `Debug` without allocating.
     ///
     /// **Panics** if the formatter helper is formatted more than once.
     ///
@@ -2089,11 +4310,67 @@ pub trait Itertools : Iterator {
     /// assert_eq!(
     ///     format!("{:.2}", data.iter().format(", ")),
     ///            "1.10, 2.72, -3.00");
+    ///
+    /// let data = [Some(1), None];
+    /// assert_eq!(format!("{:?}", data.iter().format(", ")), "Some(1), None");
+    /// ```
+    fn format(self, sep: &str) -> Format<Self>
+        where Self: Sized,
+    {
+        format::new_format_default(self, sep)
+    }
+
+    /// Format all iterator elements, separated by `sep` and enclosed in
+    /// `prefix` and `suffix`, like [`.format()`](Itertools::format).
+    ///
+    /// By default, no elements are formatted as just `prefix` and `suffix`;
+    /// use [`.with_empty()`](FormatWrapped::with_empty) to write something
+    /// else instead.
+    ///
+    /// **Panics** if the formatter helper is formatted more than once.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = [1.1, 2.71828, -3.];
+    /// assert_eq!(format!("{:.2}", data.iter().format_wrapped("[", ", ", "]")),
+    ///            "[1.10, 2.72, -3.00]");
+    ///
+    /// let none = data.iter().filter(|&&x| x > 5.).format_wrapped("{", ", ", "}").with_empty("-");
+    /// assert_eq!(none.to_string(), "-");
+    /// ```
+    fn format_wrapped<'a>(self, prefix: &'a str, sep: &'a str, suffix: &'a str) -> FormatWrapped<'a, Self>
+        where Self: Sized,
+    {
+        format::new_format_wrapped(self, prefix, sep, suffix)
+    }
+
+    /// Format at most `max` iterator elements, separated by `sep`, like
+    /// [`.format()`](Itertools::format), followed by `suffix(rest)` if
+    /// `rest` elements were left out.
+    ///
+    /// The number of elements left out is taken from the size hint if it is
+    /// exact, and otherwise by counting the rest of the iterator, which then
+    /// must be finite.
+    ///
+    /// **Panics** if the formatter helper is formatted more than once.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let more = |n| format!(", … ({} more)", n);
+    /// assert_eq!((1..=10).format_truncated(3, ", ", more).to_string(), "1, 2, 3, … (7 more)");
+    /// assert_eq!((1..=3).format_truncated(3, ", ", more).to_string(), "1, 2, 3");
+    ///
+    /// let odd = (1..=10).filter(|x| x % 2 == 1);
+    /// assert_eq!(format!("{:?}", odd.format_truncated(2, " ", |n| format!(" +{}", n))), "1 3 +3");
     /// ```
-    fn format(self, sep: &str) -> Format<Self>
+    fn format_truncated<F, D>(self, max: usize, sep: &str, suffix: F) -> FormatTruncated<'_, Self, F>
         where Self: Sized,
+              F: FnMut(usize) -> D,
+              D: fmt::Display,
     {
-        format::new_format_default(self, sep)
+        format::new_format_truncated(self, max, sep, suffix)
     }
 
     /// Format all iterator elements, separated by `sep`.
@@ -2107,6 +4384,10 @@ pub trait Itertools : Iterator {
     /// Using `&format_args!(...)` is the most versatile way to apply custom
     /// element formatting. The callback can be called multiple times if needed.
     ///
+    /// The closure returns a `fmt::Result`: any error, whether it comes from
+    /// the callback, a nested formatter or the closure itself, stops the
+    /// formatting and is returned from the outer `fmt` call.
+    ///
     /// **Panics** if the formatter helper is formatted more than once.
     ///
     /// ```
@@ -2126,7 +4407,14 @@ pub trait Itertools : Iterator {
     /// assert_eq!(format!("{}", matrix_formatter),
     ///            "1, 2, 3\n4, 5, 6");
     ///
-    ///
+    /// // errors are propagated to the caller
+    /// use std::fmt::Write;
+    /// let mut out = String::new();
+    /// let failing = (1..4).format_with(", ", |elt, f| {
+    ///     if elt == 3 { Err(std::fmt::Error) } else { f(&elt) }
+    /// });
+    /// assert!(write!(out, "{}", failing).is_err());
+    /// assert_eq!(out, "1, 2, ");
     /// ```
     fn format_with<F>(self, sep: &str, format: F) -> FormatWith<Self, F>
         where Self: Sized,
@@ -2135,6 +4423,74 @@ pub trait Itertools : Iterator {
         format::new_format(self, sep, format)
     }
 
+    /// Format the iterator elements that the closure writes, separated by
+    /// `sep`.
+    ///
+    /// This is like [`.format_with()`](Itertools::format_with), except that
+    /// an element for which the closure returns without calling the callback
+    /// is skipped: no separator is written for it.
+    ///
+    /// **Panics** if the formatter helper is formatted more than once.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = [Some(1), None, Some(3), None];
+    /// let formatter = data.iter().format_filter_with(", ", |elt, f| match elt {
+    ///     Some(x) => f(x),
+    ///     None => Ok(()),
+    /// });
+    /// assert_eq!(formatter.to_string(), "1, 3");
+    /// ```
+    fn format_filter_with<F>(self, sep: &str, format: F) -> FormatFilterWith<'_, Self, F>
+        where Self: Sized,
+              F: FnMut(Self::Item, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result,
+    {
+        format::new_format_filter(self, sep, format)
+    }
+
+    /// Format all iterator elements, like
+    /// [`.format_with()`](Itertools::format_with), with separators that
+    /// depend on their position.
+    ///
+    /// `sep` is called with the index of the separator, counting from 0, and
+    /// whether it is the last one, i.e. precedes the last element. It can
+    /// return anything that implements `Display`.
+    ///
+    /// **Panics** if the formatter helper is formatted more than once.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // the Oxford comma
+    /// let list = |items: &[&str]| {
+    ///     items.iter().format_with_indexed(
+    ///         |i, last| match (i, last) {
+    ///             (0, true) => " and ",
+    ///             (_, true) => ", and ",
+    ///             _ => ", ",
+    ///         },
+    ///         |elt, f| f(elt),
+    ///     ).to_string()
+    /// };
+    /// assert_eq!(list(&["a"]), "a");
+    /// assert_eq!(list(&["a", "b"]), "a and b");
+    /// assert_eq!(list(&["a", "b", "c"]), "a, b, and c");
+    ///
+    /// // numbered separators
+    /// let steps = ["mix", "bake", "eat"].iter()
+    ///     .format_with_indexed(|i, _| format!(" {}) ", i + 2), |elt, f| f(elt));
+    /// assert_eq!(format!("1) {}", steps), "1) mix 2) bake 3) eat");
+    /// ```
+    fn format_with_indexed<S, F, D>(self, sep: S, format: F) -> FormatWithIndexed<Self, S, F>
+        where Self: Sized,
+              S: FnMut(usize, bool) -> D,
+              D: fmt::Display,
+              F: FnMut(Self::Item, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result,
+    {
+        format::new_format_indexed(self, sep, format)
+    }
+
     /// See [`.fold_ok()`](Itertools::fold_ok).
     #[deprecated(note="Use .fold_ok() instead", since="0.10.0")]
     fn fold_results<A, E, B, F>(&mut self, start: B, f: F) -> Result<B, E>
@@ -2199,6 +4555,36 @@ pub trait Itertools : Iterator {
         Ok(start)
     }
 
+    /// Reduce `Result` values from an iterator.
+    ///
+    /// Like [`fold_ok`](Itertools::fold_ok), but uses the first `Ok` value as
+    /// the starting accumulator. If no error is encountered, the reduced value
+    /// is returned as `Ok(Some(_))`, or `Ok(None)` if the iterator is empty.
+    /// Otherwise, the operation terminates and returns the first `Err` value
+    /// it encounters. No iterator elements are consumed after the first error.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let values = [Ok(3), Ok(1), Ok(4)];
+    /// assert_eq!(values.iter().cloned().reduce_ok(i32::max), Ok::<_, ()>(Some(4)));
+    ///
+    /// let values = [Ok(3), Err("oops"), Ok(4)];
+    /// assert_eq!(values.iter().cloned().reduce_ok(i32::max), Err("oops"));
+    ///
+    /// let values: [Result<i32, ()>; 0] = [];
+    /// assert_eq!(values.iter().cloned().reduce_ok(i32::max), Ok(None));
+    /// ```
+    fn reduce_ok<A, E, F>(&mut self, f: F) -> Result<Option<A>, E>
+        where Self: Iterator<Item = Result<A, E>>,
+              F: FnMut(A, A) -> A
+    {
+        match self.next() {
+            None => Ok(None),
+            Some(first) => self.fold_ok(first?, f).map(Some),
+        }
+    }
+
     /// Fold `Option` values from an iterator.
     ///
     /// Only `Some` values are folded. If no `None` is encountered, the folded
@@ -2640,106 +5026,373 @@ pub trait Itertools : Iterator {
     /// [`slice::sort_by_key`] method and returns the result as a new
     /// iterator that owns its elements.
     ///
-    /// The sorted iterator, if directly collected to a `Vec`, is converted
-    /// without any extra copying or allocation cost.
+    /// The sorted iterator, if directly collected to a `Vec`, is converted
+    /// without any extra copying or allocation cost.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // sort people in descending order by age
+    /// let people = vec![("Jane", 20), ("John", 18), ("Jill", 30), ("Jack", 27)];
+    ///
+    /// let oldest_people_first = people
+    ///     .into_iter()
+    ///     .sorted_by_key(|x| -x.1)
+    ///     .map(|(person, _age)| person);
+    ///
+    /// itertools::assert_equal(oldest_people_first,
+    ///                         vec!["Jill", "Jack", "Jane", "John"]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn sorted_by_key<K, F>(self, f: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              K: Ord,
+              F: FnMut(&Self::Item) -> K,
+    {
+        let mut v = Vec::from_iter(self);
+        v.sort_by_key(f);
+        v.into_iter()
+    }
+
+    /// Sort all iterator elements into a new iterator in ascending order. The key function is
+    /// called exactly once per key.
+    ///
+    /// **Note:** This consumes the entire iterator, uses the
+    /// [`slice::sort_by_cached_key`] method and returns the result as a new
+    /// iterator that owns its elements.
+    ///
+    /// The sorted iterator, if directly collected to a `Vec`, is converted
+    /// without any extra copying or allocation cost.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // sort people in descending order by age
+    /// let people = vec![("Jane", 20), ("John", 18), ("Jill", 30), ("Jack", 27)];
+    ///
+    /// let oldest_people_first = people
+    ///     .into_iter()
+    ///     .sorted_by_cached_key(|x| -x.1)
+    ///     .map(|(person, _age)| person);
+    ///
+    /// itertools::assert_equal(oldest_people_first,
+    ///                         vec!["Jill", "Jack", "Jane", "John"]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn sorted_by_cached_key<K, F>(self, f: F) -> VecIntoIter<Self::Item>
+    where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut v = Vec::from_iter(self);
+        v.sort_by_cached_key(f);
+        v.into_iter()
+    }
+
+    /// Sort all iterator elements into a new iterator in ascending order,
+    /// pairing each element with its index in the original iterator.
+    ///
+    /// **Note:** This consumes the entire iterator, uses the
+    /// [`slice::sort_by`] method and returns the result as a new
+    /// iterator that owns its elements.
+    ///
+    /// The sort is stable: equal elements stay in their original order,
+    /// and only the elements, never the indices, are compared.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let sorted = "bcab".chars().sorted_with_indices();
+    ///
+    /// itertools::assert_equal(sorted, vec![(2, 'a'), (0, 'b'), (3, 'b'), (1, 'c')]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn sorted_with_indices(self) -> VecIntoIter<(usize, Self::Item)>
+        where Self: Sized,
+              Self::Item: Ord
+    {
+        let mut v = Vec::from_iter(self.enumerate());
+        v.sort_by(|a, b| a.1.cmp(&b.1));
+        v.into_iter()
+    }
+
+    /// Sort all iterator elements into a new iterator in ascending order of
+    /// the keys computed by `f`, pairing each element with its index in the
+    /// original iterator.
+    ///
+    /// **Note:** This consumes the entire iterator, uses the
+    /// [`slice::sort_by_key`] method and returns the result as a new
+    /// iterator that owns its elements.
+    ///
+    /// The sort is stable: elements with equal keys stay in their original
+    /// order, and only the keys, never the indices, are compared.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // sort people in descending order by age
+    /// let people = vec![("Jane", 20), ("John", 30), ("Jill", 30), ("Jack", 27)];
+    ///
+    /// let oldest_people_first = people
+    ///     .into_iter()
+    ///     .sorted_with_indices_by_key(|x| -x.1)
+    ///     .map(|(index, (person, _age))| (index, person));
+    ///
+    /// itertools::assert_equal(oldest_people_first,
+    ///                         vec![(1, "John"), (2, "Jill"), (3, "Jack"), (0, "Jane")]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn sorted_with_indices_by_key<K, F>(self, mut f: F) -> VecIntoIter<(usize, Self::Item)>
+        where Self: Sized,
+              K: Ord,
+              F: FnMut(&Self::Item) -> K,
+    {
+        let mut v = Vec::from_iter(self.enumerate());
+        v.sort_by_key(|(_, elt)| f(elt));
+        v.into_iter()
+    }
+
+    /// Shuffle all iterator elements into a new iterator, in a pseudo-random
+    /// order that only depends on `seed` and the number of elements.
+    ///
+    /// This is meant for tests that check that a result does not depend on
+    /// the input order: the same seed always reproduces the same order, so
+    /// a failure can be replayed. It is not suitable for anything that needs
+    /// real randomness. The order for a given seed may change between
+    /// versions of `oorandom`.
+    ///
+    /// **Note:** This consumes the entire iterator and returns the result as
+    /// a new iterator that owns its elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = (0..10).permute_by_seed(42).collect_vec();
+    /// let b = (0..10).permute_by_seed(42).collect_vec();
+    /// assert_eq!(a, b);
+    /// assert_eq!(a.iter().copied().sorted().collect_vec(), (0..10).collect_vec());
+    /// ```
+    #[cfg(all(feature = "use_alloc", feature = "oorandom"))]
+    fn permute_by_seed(self, seed: u64) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+    {
+        let mut v = Vec::from_iter(self);
+        let mut rng = oorandom::Rand64::new(u128::from(seed));
+        // Fisher-Yates
+        for i in (1..v.len()).rev() {
+            let j = rng.rand_range(0..i as u64 + 1) as usize;
+            v.swap(i, j);
+        }
+        v.into_iter()
+    }
+
+    /// Sort the k smallest elements into a new iterator, in ascending order.
+    ///
+    /// **Note:** This consumes the entire iterator, and returns the result
+    /// as a new iterator that owns its elements.  If the input contains
+    /// less than k elements, the result is equivalent to `self.sorted()`.
+    ///
+    /// This is guaranteed to use `k * sizeof(Self::Item) + O(1)` memory
+    /// and `O(n log k)` time, with `n` the number of elements in the input.
+    ///
+    /// The sorted iterator, if directly collected to a `Vec`, is converted
+    /// without any extra copying or allocation cost.
+    ///
+    /// **Note:** This is functionally-equivalent to `self.sorted().take(k)`
+    /// but much more efficient.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // A random permutation of 0..15
+    /// let numbers = vec![6, 9, 1, 14, 0, 4, 8, 7, 11, 2, 10, 3, 13, 12, 5];
+    ///
+    /// let five_smallest = numbers
+    ///     .into_iter()
+    ///     .k_smallest(5);
+    ///
+    /// itertools::assert_equal(five_smallest, 0..5);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn k_smallest(self, k: usize) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              Self::Item: Ord
+    {
+        crate::k_smallest::k_smallest(self, k)
+            .into_sorted_vec()
+            .into_iter()
+    }
+
+    /// Sort the k smallest elements into a new iterator, in ascending order,
+    /// as determined by the specified comparison function.
+    ///
+    /// This works like [`.k_smallest()`](Itertools::k_smallest), with the
+    /// same memory and time guarantees. Elements that compare equal may be
+    /// returned in any order.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // A random permutation of 0..15
+    /// let numbers = vec![6, 9, 1, 14, 0, 4, 8, 7, 11, 2, 10, 3, 13, 12, 5];
+    ///
+    /// let five_largest = numbers
+    ///     .into_iter()
+    ///     .k_smallest_by(5, |a, b| b.cmp(a));
+    ///
+    /// itertools::assert_equal(five_largest, vec![14, 13, 12, 11, 10]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn k_smallest_by<F>(self, k: usize, cmp: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        crate::k_smallest::k_smallest_general(self, k, cmp).into_iter()
+    }
+
+    /// Sort the k smallest elements into a new iterator, in ascending order
+    /// of the keys computed by `key`.
+    ///
+    /// This works like [`.k_smallest()`](Itertools::k_smallest), with the
+    /// same memory and time guarantees. Elements with equal keys may be
+    /// returned in any order.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let scores = vec![("ann", 7), ("bob", 9), ("cid", 4), ("dee", 8)];
+    ///
+    /// let top_two = scores
+    ///     .into_iter()
+    ///     .k_smallest_by_key(2, |&(_, score)| std::cmp::Reverse(score));
+    ///
+    /// itertools::assert_equal(top_two, vec![("bob", 9), ("dee", 8)]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn k_smallest_by_key<F, K>(self, k: usize, mut key: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> K,
+              K: Ord,
+    {
+        self.k_smallest_by(k, |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Sort the k largest elements into a new iterator, in descending order.
+    ///
+    /// This works like [`.k_smallest()`](Itertools::k_smallest), with the
+    /// same memory and time guarantees, but keeps the largest elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // A random permutation of 0..15
+    /// let numbers = vec![6, 9, 1, 14, 0, 4, 8, 7, 11, 2, 10, 3, 13, 12, 5];
+    ///
+    /// let five_largest = numbers
+    ///     .into_iter()
+    ///     .k_largest(5);
+    ///
+    /// itertools::assert_equal(five_largest, vec![14, 13, 12, 11, 10]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn k_largest(self, k: usize) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              Self::Item: Ord
+    {
+        self.k_largest_by(k, Self::Item::cmp)
+    }
+
+    /// Sort the k largest elements into a new iterator, in descending order,
+    /// as determined by the specified comparison function.
+    ///
+    /// This works like [`.k_largest()`](Itertools::k_largest). Elements that
+    /// compare equal may be returned in any order.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// // sort people in descending order by age
-    /// let people = vec![("Jane", 20), ("John", 18), ("Jill", 30), ("Jack", 27)];
+    /// let words = vec!["pear", "fig", "banana", "kiwi", "apple"];
     ///
-    /// let oldest_people_first = people
+    /// let longest = words
     ///     .into_iter()
-    ///     .sorted_by_key(|x| -x.1)
-    ///     .map(|(person, _age)| person);
+    ///     .k_largest_by(2, |a, b| a.len().cmp(&b.len()));
     ///
-    /// itertools::assert_equal(oldest_people_first,
-    ///                         vec!["Jill", "Jack", "Jane", "John"]);
+    /// itertools::assert_equal(longest, vec!["banana", "apple"]);
     /// ```
     #[cfg(feature = "use_alloc")]
-    fn sorted_by_key<K, F>(self, f: F) -> VecIntoIter<Self::Item>
+    fn k_largest_by<F>(self, k: usize, mut cmp: F) -> VecIntoIter<Self::Item>
         where Self: Sized,
-              K: Ord,
-              F: FnMut(&Self::Item) -> K,
+              F: FnMut(&Self::Item, &Self::Item) -> Ordering,
     {
-        let mut v = Vec::from_iter(self);
-        v.sort_by_key(f);
-        v.into_iter()
+        self.k_smallest_by(k, move |a, b| cmp(b, a))
     }
 
-    /// Sort all iterator elements into a new iterator in ascending order. The key function is
-    /// called exactly once per key.
-    ///
-    /// **Note:** This consumes the entire iterator, uses the
-    /// [`slice::sort_by_cached_key`] method and returns the result as a new
-    /// iterator that owns its elements.
+    /// Sort the k largest elements into a new iterator, in descending order
+    /// of the keys computed by `key`.
     ///
-    /// The sorted iterator, if directly collected to a `Vec`, is converted
-    /// without any extra copying or allocation cost.
+    /// This works like [`.k_largest()`](Itertools::k_largest). Elements with
+    /// equal keys may be returned in any order.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// // sort people in descending order by age
-    /// let people = vec![("Jane", 20), ("John", 18), ("Jill", 30), ("Jack", 27)];
+    /// let scores = vec![("ann", 7), ("bob", 9), ("cid", 4), ("dee", 8)];
     ///
-    /// let oldest_people_first = people
+    /// let top_two = scores
     ///     .into_iter()
-    ///     .sorted_by_cached_key(|x| -x.1)
-    ///     .map(|(person, _age)| person);
+    ///     .k_largest_by_key(2, |&(_, score)| score);
     ///
-    /// itertools::assert_equal(oldest_people_first,
-    ///                         vec!["Jill", "Jack", "Jane", "John"]);
-    /// ```
+    /// itertools::assert_equal(top_two, vec![("bob", 9), ("dee", 8)]);
     /// ```
     #[cfg(feature = "use_alloc")]
-    fn sorted_by_cached_key<K, F>(self, f: F) -> VecIntoIter<Self::Item>
-    where
-        Self: Sized,
-        K: Ord,
-        F: FnMut(&Self::Item) -> K,
+    fn k_largest_by_key<F, K>(self, k: usize, mut key: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> K,
+              K: Ord,
     {
-        let mut v = Vec::from_iter(self);
-        v.sort_by_cached_key(f);
-        v.into_iter()
+        self.k_largest_by(k, |a, b| key(a).cmp(&key(b)))
     }
 
-    /// Sort the k smallest elements into a new iterator, in ascending order.
-    ///
-    /// **Note:** This consumes the entire iterator, and returns the result
-    /// as a new iterator that owns its elements.  If the input contains
-    /// less than k elements, the result is equivalent to `self.sorted()`.
-    ///
-    /// This is guaranteed to use `k * sizeof(Self::Item) + O(1)` memory
-    /// and `O(n log k)` time, with `n` the number of elements in the input.
+    /// Consume the iterator and return an iterator over its last `n`
+    /// elements, in their original order.
     ///
-    /// The sorted iterator, if directly collected to a `Vec`, is converted
-    /// without any extra copying or allocation cost.
-    ///
-    /// **Note:** This is functionally-equivalent to `self.sorted().take(k)`
-    /// but much more efficient.
+    /// Only the last `n` elements are kept while iterating, in a ring buffer,
+    /// so this uses `O(n)` memory regardless of the iterator's length. If the
+    /// iterator has fewer than `n` elements, all of them are returned.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// // A random permutation of 0..15
-    /// let numbers = vec![6, 9, 1, 14, 0, 4, 8, 7, 11, 2, 10, 3, 13, 12, 5];
-    ///
-    /// let five_smallest = numbers
-    ///     .into_iter()
-    ///     .k_smallest(5);
+    /// let lines = "one\ntwo\nthree\nfour".lines();
+    /// itertools::assert_equal(lines.tail(2), vec!["three", "four"]);
     ///
-    /// itertools::assert_equal(five_smallest, 0..5);
+    /// itertools::assert_equal((0..3).tail(5), 0..3);
     /// ```
     #[cfg(feature = "use_alloc")]
-    fn k_smallest(self, k: usize) -> VecIntoIter<Self::Item>
+    fn tail(self, n: usize) -> VecIntoIter<Self::Item>
         where Self: Sized,
-              Self::Item: Ord
     {
-        crate::k_smallest::k_smallest(self, k)
-            .into_sorted_vec()
-            .into_iter()
+        match n {
+            0 => {
+                self.last();
+                Vec::new()
+            }
+            1 => self.last().into_iter().collect(),
+            _ => {
+                let mut iter = self.fuse();
+                let mut data: Vec<_> = iter.by_ref().take(n).collect();
+                if data.len() == n {
+                    // overwrite the oldest element, which is at `i`
+                    let i = iter.fold(0, |i, elt| {
+                        data[i] = elt;
+                        if i + 1 == n { 0 } else { i + 1 }
+                    });
+                    data.rotate_left(i);
+                }
+                data
+            }
+        }.into_iter()
     }
 
     /// Collect all iterator elements into one of two
@@ -2807,6 +5460,147 @@ pub trait Itertools : Iterator {
         })
     }
 
+    /// Partition a sequence of `Either`s into one list of all the `Left`
+    /// elements and another list of all the `Right` elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::Either::{Left, Right};
+    ///
+    /// let mixed = vec![Left(1), Right('a'), Left(2), Right('b')];
+    ///
+    /// let (lefts, rights): (Vec<_>, Vec<_>) = mixed.into_iter().partition_eithers();
+    ///
+    /// assert_eq!(lefts, [1, 2]);
+    /// assert_eq!(rights, ['a', 'b']);
+    /// ```
+    fn partition_eithers<A, B, L, R>(self) -> (A, B)
+        where
+            Self: Iterator<Item = Either<L, R>> + Sized,
+            A: Default + Extend<L>,
+            B: Default + Extend<R>,
+    {
+        self.partition_map(|e| e)
+    }
+
+    /// Collect all iterator elements into one of three partitions. Unlike
+    /// [`.partition_map()`](Itertools::partition_map), `predicate` returns an
+    /// [`Either3`], so elements can be sorted into three kinds of containers
+    /// without nesting `Either`s.
+    ///
+    /// ```
+    /// use itertools::{Either3, Itertools};
+    ///
+    /// let codes = vec![200, 404, 201, 503, 301, 500];
+    ///
+    /// let (ok, client, server): (Vec<_>, Vec<_>, Vec<_>) = codes
+    ///     .into_iter()
+    ///     .filter(|&code| code >= 200)
+    ///     .partition3_map(|code| match code {
+    ///         200..=399 => Either3::First(code),
+    ///         400..=499 => Either3::Second(code.to_string()),
+    ///         _ => Either3::Third(code - 500),
+    ///     });
+    ///
+    /// assert_eq!(ok, [200, 201, 301]);
+    /// assert_eq!(client, ["404"]);
+    /// assert_eq!(server, [3, 0]);
+    /// ```
+    fn partition3_map<A, B, C, F, X, Y, Z>(self, mut predicate: F) -> (A, B, C)
+        where Self: Sized,
+              F: FnMut(Self::Item) -> Either3<X, Y, Z>,
+              A: Default + Extend<X>,
+              B: Default + Extend<Y>,
+              C: Default + Extend<Z>,
+    {
+        let mut first = A::default();
+        let mut second = B::default();
+        let mut third = C::default();
+
+        self.for_each(|val| match predicate(val) {
+            Either3::First(v) => first.extend(Some(v)),
+            Either3::Second(v) => second.extend(Some(v)),
+            Either3::Third(v) => third.extend(Some(v)),
+        });
+
+        (first, second, third)
+    }
+
+    /// Collect a sequence of `Result`s into a vector of the `Ok` values and a
+    /// vector of the `Err` values, in one pass.
+    ///
+    /// Both vectors are preallocated from the iterator's lower size hint (the
+    /// error vector never beyond `max_errors`). If `max_errors` is
+    /// `Some(n)`, iteration stops as soon as `n` errors have been collected;
+    /// use `.by_ref()` to keep the rest of the iterator.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let results = vec![Ok(1), Err("a"), Ok(2), Err("b"), Ok(3), Err("c")];
+    ///
+    /// let (oks, errs) = results.iter().cloned().collect_ok_vec_err_vec(None);
+    /// assert_eq!(oks, [1, 2, 3]);
+    /// assert_eq!(errs, ["a", "b", "c"]);
+    ///
+    /// let mut iter = results.into_iter();
+    /// let (oks, errs) = iter.by_ref().collect_ok_vec_err_vec(Some(2));
+    /// assert_eq!(oks, [1, 2]);
+    /// assert_eq!(errs, ["a", "b"]);
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn collect_ok_vec_err_vec<T, E>(self, max_errors: Option<usize>) -> (Vec<T>, Vec<E>)
+        where Self: Iterator<Item = Result<T, E>> + Sized,
+    {
+        let mut iter = self;
+        let (low, _) = iter.size_hint();
+        let max_errors = max_errors.unwrap_or(usize::MAX);
+        let mut oks = Vec::with_capacity(low);
+        let mut errs = Vec::with_capacity(low.min(max_errors));
+        while errs.len() < max_errors {
+            match iter.next() {
+                Some(Ok(v)) => oks.push(v),
+                Some(Err(e)) => errs.push(e),
+                None => break,
+            }
+        }
+        (oks, errs)
+    }
+
+    /// Collect the `Ok` values of a sequence of `Result`s up to the first
+    /// `Err`, returning them together with that error (if any) and the rest
+    /// of the iterator.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let results = vec![Ok(1), Ok(2), Err("bad"), Ok(3)];
+    ///
+    /// let (oks, err, rest) = results.into_iter().collect_until_err();
+    /// assert_eq!(oks, [1, 2]);
+    /// assert_eq!(err, Some("bad"));
+    /// itertools::assert_equal(rest, vec![Ok(3)]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn collect_until_err<T, E>(self) -> (Vec<T>, Option<E>, Self)
+        where Self: Iterator<Item = Result<T, E>> + Sized,
+    {
+        let mut iter = self;
+        let mut oks = Vec::with_capacity(iter.size_hint().0);
+        let mut err = None;
+        for result in iter.by_ref() {
+            match result {
+                Ok(v) => oks.push(v),
+                Err(e) => {
+                    err = Some(e);
+                    break;
+                }
+            }
+        }
+        (oks, err, iter)
+    }
+
     /// Return a `HashMap` of keys mapped to `Vec`s of values. Keys and values
     /// are taken from `(Key, Value)` tuple pairs yielded by the input iterator.
     ///
@@ -2868,6 +5662,78 @@ pub trait Itertools : Iterator {
         group_map::into_group_map_by(self, f)
     }
 
+    /// Route every element into the `Vec` of its key, returning a `HashMap`
+    /// of keys mapped to those queues. If `max_keys` is given, the iterator
+    /// may yield at most that many distinct keys.
+    ///
+    /// This is the routing counterpart of
+    /// [`.into_group_map_by()`](Itertools::into_group_map_by), for
+    /// dispatching work to named queues: the elements keep their order
+    /// within each queue, and with a bound the map and the queues are
+    /// preallocated from the iterator's size hint, assuming the elements are
+    /// spread evenly over the allowed keys.
+    ///
+    /// When a key past the limit occurs, it is returned in a
+    /// [`TooManyKeysError`] and the rest of the iterator is not consumed.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let jobs = vec!["mail:a", "db:b", "mail:c", "db:d", "log:e"];
+    /// fn queue(job: &&'static str) -> &'static str {
+    ///     job.split(':').next().unwrap()
+    /// }
+    ///
+    /// let routed = jobs.iter().copied().route_by_key(None, queue).unwrap();
+    /// assert_eq!(routed["mail"], vec!["mail:a", "mail:c"]);
+    /// assert_eq!(routed["log"], vec!["log:e"]);
+    ///
+    /// let err = jobs.iter().copied().route_by_key(Some(2), queue).unwrap_err();
+    /// assert_eq!(err.into_key(), "log");
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn route_by_key<K, F>(self, max_keys: Option<usize>, key: F)
+        -> Result<HashMap<K, Vec<Self::Item>>, TooManyKeysError<K>>
+        where Self: Sized,
+              K: Hash + Eq,
+              F: FnMut(&Self::Item) -> K,
+    {
+        route_by_key::route_by_key(self, max_keys, key)
+    }
+
+    /// Return a `HashMap` of keys mapped to a single value, with `policy`
+    /// deciding what happens when a key occurs more than once. Keys and
+    /// values are taken from `(Key, Value)` tuple pairs yielded by the input
+    /// iterator.
+    ///
+    /// Collecting into a `HashMap` silently keeps the last value of every
+    /// key; this makes the choice explicit. With
+    /// [`DuplicatePolicy::Error`], the first repeated key is returned in a
+    /// [`DuplicateKeyError`] and the rest of the iterator is not consumed.
+    ///
+    /// ```
+    /// use itertools::{DuplicatePolicy, Itertools};
+    ///
+    /// let data = vec![("a", 1), ("b", 2), ("a", 3)];
+    ///
+    /// let first = data.iter().cloned().into_unique_map(DuplicatePolicy::KeepFirst).unwrap();
+    /// assert_eq!(first["a"], 1);
+    ///
+    /// let last = data.iter().cloned().into_unique_map(DuplicatePolicy::KeepLast).unwrap();
+    /// assert_eq!(last["a"], 3);
+    ///
+    /// let err = data.iter().cloned().into_unique_map(DuplicatePolicy::Error).unwrap_err();
+    /// assert_eq!(err.into_key(), "a");
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn into_unique_map<K, V>(self, policy: DuplicatePolicy)
+        -> Result<HashMap<K, V>, DuplicateKeyError<K>>
+        where Self: Iterator<Item=(K, V)> + Sized,
+              K: Hash + Eq,
+    {
+        unique_map::into_unique_map(self, policy)
+    }
+
     /// Constructs a `GroupingMap` to be used later with one of the efficient 
     /// group-and-fold operations it allows to perform.
     /// 
@@ -2899,83 +5765,314 @@ pub trait Itertools : Iterator {
               K: Hash + Eq,
               F: FnMut(&V) -> K
     {
-        grouping_map::new(grouping_map::MapForGrouping::new(self, key_mapper))
+        grouping_map::new(grouping_map::MapForGrouping::new(self, key_mapper))
+    }
+
+    /// Return the minimum and maximum elements in the iterator.
+    ///
+    /// The return type `MinMaxResult` is an enum of three variants:
+    ///
+    /// - `NoElements` if the iterator is empty.
+    /// - `OneElement(x)` if the iterator has exactly one element.
+    /// - `MinMax(x, y)` is returned otherwise, where `x <= y`. Two
+    ///    values are equal if and only if there is more than one
+    ///    element in the iterator and all elements are equal.
+    ///
+    /// On an iterator of length `n`, `minmax` does `1.5 * n` comparisons,
+    /// and so is faster than calling `min` and `max` separately which does
+    /// `2 * n` comparisons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::{NoElements, OneElement, MinMax};
+    ///
+    /// let a: [i32; 0] = [];
+    /// assert_eq!(a.iter().minmax(), NoElements);
+    ///
+    /// let a = [1];
+    /// assert_eq!(a.iter().minmax(), OneElement(&1));
+    ///
+    /// let a = [1, 2, 3, 4, 5];
+    /// assert_eq!(a.iter().minmax(), MinMax(&1, &5));
+    ///
+    /// let a = [1, 1, 1, 1];
+    /// assert_eq!(a.iter().minmax(), MinMax(&1, &1));
+    /// ```
+    ///
+    /// The elements can be floats but no particular result is guaranteed
+    /// if an element is NaN.
+    fn minmax(self) -> MinMaxResult<Self::Item>
+        where Self: Sized, Self::Item: PartialOrd
+    {
+        minmax::minmax_impl(self, |_| (), |x, y, _, _| x < y)
+    }
+
+    /// Return the minimum and maximum element of an iterator, as determined by
+    /// the specified function.
+    ///
+    /// The return value is a variant of [`MinMaxResult`] like for [`.minmax()`](Itertools::minmax).
+    ///
+    /// For the minimum, the first minimal element is returned.  For the maximum,
+    /// the last maximal element wins.  This matches the behavior of the standard
+    /// [`Iterator::min`] and [`Iterator::max`] methods.
+    ///
+    /// The keys can be floats but no particular result is guaranteed
+    /// if a key is NaN.
+    fn minmax_by_key<K, F>(self, key: F) -> MinMaxResult<Self::Item>
+        where Self: Sized, K: PartialOrd, F: FnMut(&Self::Item) -> K
+    {
+        minmax::minmax_impl(self, key, |_, _, xk, yk| xk < yk)
+    }
+
+    /// Return the minimum and maximum element of an iterator, as determined by
+    /// the specified comparison function.
+    ///
+    /// The return value is a variant of [`MinMaxResult`] like for [`.minmax()`](Itertools::minmax).
+    ///
+    /// For the minimum, the first minimal element is returned.  For the maximum,
+    /// the last maximal element wins.  This matches the behavior of the standard
+    /// [`Iterator::min`] and [`Iterator::max`] methods.
+    fn minmax_by<F>(self, mut compare: F) -> MinMaxResult<Self::Item>
+        where Self: Sized, F: FnMut(&Self::Item, &Self::Item) -> Ordering
+    {
+        minmax::minmax_impl(
+            self,
+            |_| (),
+            |x, y, _, _| Ordering::Less == compare(x, y)
+        )
+    }
+
+    /// Return all minimum elements of an iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a: [i32; 0] = [];
+    /// assert_eq!(a.iter().min_set(), Vec::<&i32>::new());
+    ///
+    /// let a = [1];
+    /// assert_eq!(a.iter().min_set(), vec![&1]);
+    ///
+    /// let a = [1, 2, 3, 4, 5];
+    /// assert_eq!(a.iter().min_set(), vec![&1]);
+    ///
+    /// let a = [1, 1, 1, 1];
+    /// assert_eq!(a.iter().min_set(), vec![&1, &1, &1, &1]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn min_set(self) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        extrema_set::min_set_impl(self, |_| (), |a, b, _, _| a.cmp(b))
     }
 
-    /// Return the minimum and maximum elements in the iterator.
+    /// Return all minimum elements of an iterator, as determined by
+    /// the specified function.
     ///
-    /// The return type `MinMaxResult` is an enum of three variants:
+    /// # Examples
     ///
-    /// - `NoElements` if the iterator is empty.
-    /// - `OneElement(x)` if the iterator has exactly one element.
-    /// - `MinMax(x, y)` is returned otherwise, where `x <= y`. Two
-    ///    values are equal if and only if there is more than one
-    ///    element in the iterator and all elements are equal.
+    /// ```
+    /// use itertools::Itertools;
     ///
-    /// On an iterator of length `n`, `minmax` does `1.5 * n` comparisons,
-    /// and so is faster than calling `min` and `max` separately which does
-    /// `2 * n` comparisons.
+    /// let a: [(i32, i32); 0] = [];
+    /// assert_eq!(a.iter().min_set_by_key(|_| ()), Vec::<&(i32, i32)>::new());
+    ///
+    /// let a = [(1, 2)];
+    /// assert_eq!(a.iter().min_set_by_key(|&&(k,_)| k), vec![&(1, 2)]);
+    ///
+    /// let a = [(1, 2), (2, 2), (3, 9), (4, 8), (5, 9)];
+    /// assert_eq!(a.iter().min_set_by_key(|&&(_, k)| k), vec![&(1, 2), &(2, 2)]);
+    ///
+    /// let a = [(1, 2), (1, 3), (1, 4), (1, 5)];
+    /// assert_eq!(a.iter().min_set_by_key(|&&(k, _)| k), vec![&(1, 2), &(1, 3), &(1, 4), &(1, 5)]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn min_set_by_key<K, F>(self, key: F) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        extrema_set::min_set_impl(self, key, |_, _, kx, ky| kx.cmp(ky))
+    }
+
+    /// Return all minimum elements of an iterator, as determined by
+    /// the specified comparison function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cmp::Ordering;
+    /// use itertools::Itertools;
+    ///
+    /// let a: [(i32, i32); 0] = [];
+    /// assert_eq!(a.iter().min_set_by(|_, _| Ordering::Equal), Vec::<&(i32, i32)>::new());
+    ///
+    /// let a = [(1, 2), (2, 2), (3, 9), (4, 8), (5, 9)];
+    /// assert_eq!(a.iter().min_set_by(|&&(_,k1), &&(_,k2)| k1.cmp(&k2)), vec![&(1, 2), &(2, 2)]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn min_set_by<F>(self, mut compare: F) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        extrema_set::min_set_impl(self, |_| (), |x, y, _, _| compare(x, y))
+    }
+
+    /// Return all maximum elements of an iterator.
     ///
     /// # Examples
     ///
     /// ```
     /// use itertools::Itertools;
-    /// use itertools::MinMaxResult::{NoElements, OneElement, MinMax};
     ///
     /// let a: [i32; 0] = [];
-    /// assert_eq!(a.iter().minmax(), NoElements);
+    /// assert_eq!(a.iter().max_set(), Vec::<&i32>::new());
     ///
     /// let a = [1];
-    /// assert_eq!(a.iter().minmax(), OneElement(&1));
+    /// assert_eq!(a.iter().max_set(), vec![&1]);
     ///
     /// let a = [1, 2, 3, 4, 5];
-    /// assert_eq!(a.iter().minmax(), MinMax(&1, &5));
+    /// assert_eq!(a.iter().max_set(), vec![&5]);
     ///
     /// let a = [1, 1, 1, 1];
-    /// assert_eq!(a.iter().minmax(), MinMax(&1, &1));
+    /// assert_eq!(a.iter().max_set(), vec![&1, &1, &1, &1]);
     /// ```
-    ///
-    /// The elements can be floats but no particular result is guaranteed
-    /// if an element is NaN.
-    fn minmax(self) -> MinMaxResult<Self::Item>
-        where Self: Sized, Self::Item: PartialOrd
+    #[cfg(feature = "use_alloc")]
+    fn max_set(self) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
     {
-        minmax::minmax_impl(self, |_| (), |x, y, _, _| x < y)
+        extrema_set::max_set_impl(self, |_| (), |a, b, _, _| a.cmp(b))
     }
 
-    /// Return the minimum and maximum element of an iterator, as determined by
+    /// Return all maximum elements of an iterator, as determined by
     /// the specified function.
     ///
-    /// The return value is a variant of [`MinMaxResult`] like for [`.minmax()`](Itertools::minmax).
+    /// # Examples
     ///
-    /// For the minimum, the first minimal element is returned.  For the maximum,
-    /// the last maximal element wins.  This matches the behavior of the standard
-    /// [`Iterator::min`] and [`Iterator::max`] methods.
+    /// ```
+    /// use itertools::Itertools;
     ///
-    /// The keys can be floats but no particular result is guaranteed
-    /// if a key is NaN.
-    fn minmax_by_key<K, F>(self, key: F) -> MinMaxResult<Self::Item>
-        where Self: Sized, K: PartialOrd, F: FnMut(&Self::Item) -> K
+    /// let a: [(i32, i32); 0] = [];
+    /// assert_eq!(a.iter().max_set_by_key(|_| ()), Vec::<&(i32, i32)>::new());
+    ///
+    /// let a = [(1, 2)];
+    /// assert_eq!(a.iter().max_set_by_key(|&&(k,_)| k), vec![&(1, 2)]);
+    ///
+    /// let a = [(1, 2), (2, 2), (3, 9), (4, 8), (5, 9)];
+    /// assert_eq!(a.iter().max_set_by_key(|&&(_, k)| k), vec![&(3, 9), &(5, 9)]);
+    ///
+    /// let a = [(1, 2), (1, 3), (1, 4), (1, 5)];
+    /// assert_eq!(a.iter().max_set_by_key(|&&(k, _)| k), vec![&(1, 2), &(1, 3), &(1, 4), &(1, 5)]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn max_set_by_key<K, F>(self, key: F) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
     {
-        minmax::minmax_impl(self, key, |_, _, xk, yk| xk < yk)
+        extrema_set::max_set_impl(self, key, |_, _, kx, ky| kx.cmp(ky))
     }
 
-    /// Return the minimum and maximum element of an iterator, as determined by
+    /// Return all maximum elements of an iterator, as determined by
     /// the specified comparison function.
     ///
-    /// The return value is a variant of [`MinMaxResult`] like for [`.minmax()`](Itertools::minmax).
+    /// # Examples
     ///
-    /// For the minimum, the first minimal element is returned.  For the maximum,
-    /// the last maximal element wins.  This matches the behavior of the standard
-    /// [`Iterator::min`] and [`Iterator::max`] methods.
-    fn minmax_by<F>(self, mut compare: F) -> MinMaxResult<Self::Item>
-        where Self: Sized, F: FnMut(&Self::Item, &Self::Item) -> Ordering
+    /// ```
+    /// # use std::cmp::Ordering;
+    /// use itertools::Itertools;
+    ///
+    /// let a: [(i32, i32); 0] = [];
+    /// assert_eq!(a.iter().max_set_by(|_, _| Ordering::Equal), Vec::<&(i32, i32)>::new());
+    ///
+    /// let a = [(1, 2), (2, 2), (3, 9), (4, 8), (5, 9)];
+    /// assert_eq!(a.iter().max_set_by(|&&(_,k1), &&(_,k2)| k1.cmp(&k2)), vec![&(3, 9), &(5, 9)]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn max_set_by<F>(self, mut compare: F) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
     {
-        minmax::minmax_impl(
-            self,
-            |_| (),
-            |x, y, _, _| Ordering::Less == compare(x, y)
-        )
+        extrema_set::max_set_impl(self, |_| (), |x, y, _, _| compare(x, y))
+    }
+
+    /// Return the minimum key of the elements and how many elements have
+    /// it, or `None` if the iterator is empty.
+    ///
+    /// This counts the elements that [`.min_set_by_key()`](Itertools::min_set_by_key)
+    /// would return, without collecting them: it only keeps the current
+    /// minimum key.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = [3i32, -1, 2, 1, -3];
+    /// assert_eq!(a.iter().count_min_by_key(|x| x.abs()), Some((1, 2)));
+    /// assert_eq!(a.iter().count_min_by_key(|x| x.signum()), Some((-1, 2)));
+    /// assert_eq!((0..0).count_min_by_key(|&x| x), None);
+    /// ```
+    fn count_min_by_key<K, F>(self, mut key: F) -> Option<(K, usize)>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: Ord,
+    {
+        self.fold(None, |acc, elt| {
+            let k = key(&elt);
+            match acc {
+                Some((min, n)) => match k.cmp(&min) {
+                    Ordering::Less => Some((k, 1)),
+                    Ordering::Equal => Some((min, n + 1)),
+                    Ordering::Greater => Some((min, n)),
+                },
+                None => Some((k, 1)),
+            }
+        })
+    }
+
+    /// Return the maximum key of the elements and how many elements have
+    /// it, or `None` if the iterator is empty.
+    ///
+    /// This counts the elements that [`.max_set_by_key()`](Itertools::max_set_by_key)
+    /// would return, without collecting them: it only keeps the current
+    /// maximum key.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = [3i32, -1, 2, 1, -3];
+    /// assert_eq!(a.iter().count_max_by_key(|x| x.abs()), Some((3, 2)));
+    /// assert_eq!((0..0).count_max_by_key(|&x| x), None);
+    /// ```
+    fn count_max_by_key<K, F>(self, mut key: F) -> Option<(K, usize)>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: Ord,
+    {
+        self.fold(None, |acc, elt| {
+            let k = key(&elt);
+            match acc {
+                Some((max, n)) => match k.cmp(&max) {
+                    Ordering::Greater => Some((k, 1)),
+                    Ordering::Equal => Some((max, n + 1)),
+                    Ordering::Less => Some((max, n)),
+                },
+                None => Some((k, 1)),
+            }
+        })
     }
 
     /// Return the position of the maximum element in the iterator.
@@ -3328,6 +6425,10 @@ pub trait Itertools : Iterator {
     /// If your assumption that there should be at most one element yielded is false this provides
     /// the opportunity to detect and handle that, preventing errors at a distance.
     ///
+    /// This is the "zero or one" counterpart of
+    /// [`.exactly_one()`](Itertools::exactly_one), and returns the same
+    /// [`ExactlyOneError`], which can be iterated to inspect all the elements.
+    ///
     /// # Examples
     /// ```
     /// use itertools::Itertools;
@@ -3397,7 +6498,62 @@ pub trait Itertools : Iterator {
         Self: Sized,
         Self::Item: Eq + Hash,
     {
-        let mut counts = HashMap::new();
+        self.counts_with_hasher(Default::default())
+    }
+
+    /// Compare the elements of this iterator and `other` regardless of
+    /// their order, and return the elements that each one has more of.
+    ///
+    /// Unlike [`assert_equal`], which compares positionally, this is suited
+    /// for test assertions where the order doesn't matter; and unlike
+    /// comparing two [`.counts()`](Itertools::counts), the result tells
+    /// which side is short of what.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let diff = vec![1, 2, 2, 3].into_iter().diff_multiset(vec![3, 2, 4, 1]);
+    /// assert_eq!(diff.only_left.get(&2), Some(&1));
+    /// assert_eq!(diff.only_right.get(&4), Some(&1));
+    /// assert_eq!(diff.only_left.len() + diff.only_right.len(), 2);
+    ///
+    /// assert!("listen".chars().diff_multiset("silent".chars()).is_empty());
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn diff_multiset<J>(self, other: J) -> MultisetDiff<Self::Item>
+        where Self: Sized,
+              J: IntoIterator<Item = Self::Item>,
+              Self::Item: Eq + Hash,
+    {
+        multiset_diff::diff_multiset(self, other.into_iter())
+    }
+
+    /// Collect the items in this iterator and return a `HashMap` which
+    /// contains each item that appears in the iterator and the number
+    /// of times it appears, using `hash_builder` to hash the items.
+    ///
+    /// This behaves like [`.counts()`](Itertools::counts), but allows
+    /// plugging in a faster or deterministic hasher.
+    ///
+    /// ```
+    /// # use itertools::Itertools;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// let hasher = BuildHasherDefault::<DefaultHasher>::default();
+    /// let counts = [1, 1, 1, 3, 3, 5].iter().counts_with_hasher(hasher);
+    /// assert_eq!(counts[&1], 3);
+    /// assert_eq!(counts[&3], 2);
+    /// assert_eq!(counts[&5], 1);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn counts_with_hasher<S>(self, hash_builder: S) -> HashMap<Self::Item, usize, S>
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash,
+        S: BuildHasher,
+    {
+        let mut counts = HashMap::with_hasher(hash_builder);
         self.for_each(|item| *counts.entry(item).or_default() += 1);
         counts
     }
@@ -3444,6 +6600,96 @@ pub trait Itertools : Iterator {
         self.map(f).counts()
     }
 
+    /// Collect the items in this iterator and return a `HashMap` which
+    /// contains each key produced by `f` and the number of items that map
+    /// to it, using `hash_builder` to hash the keys.
+    ///
+    /// This behaves like [`.counts_by()`](Itertools::counts_by), but allows
+    /// plugging in a faster or deterministic hasher.
+    ///
+    /// ```
+    /// # use itertools::Itertools;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// let hasher = BuildHasherDefault::<DefaultHasher>::default();
+    /// let lengths = ["a", "bb", "cc", "d"].iter().counts_by_with_hasher(|s| s.len(), hasher);
+    /// assert_eq!(lengths[&1], 2);
+    /// assert_eq!(lengths[&2], 2);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn counts_by_with_hasher<K, F, S>(self, f: F, hash_builder: S) -> HashMap<K, usize, S>
+    where
+        Self: Sized,
+        K: Eq + Hash,
+        F: FnMut(Self::Item) -> K,
+        S: BuildHasher,
+    {
+        self.map(f).counts_with_hasher(hash_builder)
+    }
+
+    /// Collect the items in this iterator and return a `BTreeMap` which
+    /// contains each key produced by `f` and the number of items that map
+    /// to it.
+    ///
+    /// This behaves like [`.counts_by()`](Itertools::counts_by), but the
+    /// counts are sorted by key.
+    ///
+    /// ```
+    /// # use itertools::Itertools;
+    /// let lengths = ["ccc", "a", "bb", "dd", "e"].iter().counts_by_sorted(|s| s.len());
+    /// itertools::assert_equal(lengths, vec![(1, 2), (2, 2), (3, 1)]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn counts_by_sorted<K, F>(self, f: F) -> BTreeMap<K, usize>
+    where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(Self::Item) -> K,
+    {
+        let mut counts = BTreeMap::new();
+        self.map(f).for_each(|key| *counts.entry(key).or_default() += 1);
+        counts
+    }
+
+    /// Return the `n` most frequent items of the iterator together with
+    /// their counts, ordered from most to least frequent. Items with equal
+    /// counts are ordered by their first appearance.
+    ///
+    /// If there are fewer than `n` distinct items, all of them are returned.
+    /// After counting, only the `n` most frequent items are kept in a heap,
+    /// rather than sorting all the counts.
+    ///
+    /// ```
+    /// # use itertools::Itertools;
+    /// let most_common = "abracadabra".chars().most_common(3);
+    /// assert_eq!(most_common, vec![('a', 5), ('b', 2), ('r', 2)]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn most_common(self, n: usize) -> Vec<(Self::Item, usize)>
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash,
+    {
+        use std::cmp::Reverse;
+
+        // each item maps to the index of its first appearance and its count
+        let mut counts = HashMap::new();
+        for (i, item) in self.enumerate() {
+            counts.entry(item).or_insert((i, 0)).1 += 1;
+        }
+        let mut items = Vec::with_capacity(counts.len());
+        let entries = counts.into_iter().map(|(item, (first, count))| {
+            items.push(Some(item));
+            (Reverse(count), first, items.len() - 1)
+        });
+        let heap = crate::k_smallest::k_smallest(entries, n);
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|(Reverse(count), _, slot)| (items[slot].take().unwrap(), count))
+            .collect()
+    }
+
     /// Converts an iterator of tuples into a tuple of containers.
     ///
     /// `unzip()` consumes an entire iterator of n-ary tuples, producing `n` collections, one for each
@@ -3470,6 +6716,30 @@ pub trait Itertools : Iterator {
     {
         MultiUnzip::multiunzip(self)
     }
+
+    /// Converts an iterator of arrays into an array of `Vec`s, one for each
+    /// column.
+    ///
+    /// This is the array counterpart of
+    /// [`.multiunzip()`](Itertools::multiunzip), for rows of any width.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let inputs = vec![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    ///
+    /// let [a, b, c] = inputs.into_iter().unzip_array();
+    ///
+    /// assert_eq!(a, vec![1, 4, 7]);
+    /// assert_eq!(b, vec![2, 5, 8]);
+    /// assert_eq!(c, vec![3, 6, 9]);
+    /// ```
+    #[cfg(feature = "use_alloc")]
+    fn unzip_array<T, const N: usize>(self) -> [Vec<T>; N]
+        where Self: Sized + Iterator<Item = [T; N]>,
+    {
+        unziptuple::unzip_array(self)
+    }
 }
 
 impl<T: ?Sized> Itertools for T where T: Iterator { }
@@ -3538,6 +6808,67 @@ pub fn assert_equal<I, J>(a: I, b: J)
     }
 }
 
+/// Assert that two iterables produce sequences that are equal element by
+/// element according to `eq`.
+///
+/// **Panics** on assertion failure with a message that shows the index of
+/// the first mismatch, the two iteration elements, and up to three elements
+/// before and after the mismatch on both sides.
+///
+/// ```
+/// use itertools::assert_equal_by;
+///
+/// assert_equal_by(vec![0.1 + 0.2, 1.0], [0.3, 1.0], |a: &f64, b: &f64| (a - b).abs() < 1e-9);
+/// ```
+///
+/// ```ignore
+/// assert_equal_by(1..10, vec![1, 2, 3, 4, 0, 6], |a, b| a == b);
+/// // ^PANIC: panicked at 'Failed assertion Some(5) == Some(0) for iteration 4
+/// //   left: [.., 2, 3, 4] Some(5) [6, 7, 8, ..]
+/// //  right: [.., 2, 3, 4] Some(0) [6]',
+/// ```
+#[cfg(feature = "use_alloc")]
+pub fn assert_equal_by<I, J, F>(a: I, b: J, mut eq: F)
+    where I: IntoIterator,
+          J: IntoIterator,
+          I::Item: fmt::Debug,
+          J::Item: fmt::Debug,
+          F: FnMut(&I::Item, &J::Item) -> bool,
+{
+    const CONTEXT: usize = 3;
+    let mut ia = a.into_iter();
+    let mut ib = b.into_iter();
+    let mut before = VecDeque::with_capacity(CONTEXT);
+    let mut i = 0;
+    loop {
+        match (ia.next(), ib.next()) {
+            (None, None) => return,
+            (Some(a), Some(b)) if eq(&a, &b) => {
+                if before.len() == CONTEXT {
+                    before.pop_front();
+                }
+                before.push_back((a, b));
+                i += 1;
+            }
+            (a, b) => {
+                let skipped = if i > CONTEXT { ".., " } else { "" };
+                let after_a = ia.by_ref().take(CONTEXT).collect::<Vec<_>>();
+                let after_b = ib.by_ref().take(CONTEXT).collect::<Vec<_>>();
+                let more_a = if ia.next().is_some() { ", .." } else { "" };
+                let more_b = if ib.next().is_some() { ", .." } else { "" };
+                panic!("Failed assertion {a:?} == {b:?} for iteration {i}\n  \
+                        left: [{skipped}{before_a:?}] {a:?} [{after_a:?}{more_a}]\n \
+                        right: [{skipped}{before_b:?}] {b:?} [{after_b:?}{more_b}]",
+                       i=i, a=a, b=b, skipped=skipped,
+                       before_a=before.iter().map(|(a, _)| a).format(", "),
+                       before_b=before.iter().map(|(_, b)| b).format(", "),
+                       after_a=after_a.iter().format(", "), more_a=more_a,
+                       after_b=after_b.iter().format(", "), more_b=more_b);
+            }
+        }
+    }
+}
+
 /// Partition a sequence using predicate `pred` so that elements
 /// that map to `true` are placed before elements which map to `false`.
 ///
@@ -3579,6 +6910,18 @@ pub fn partition<'a, A: 'a, I, F>(iter: I, mut pred: F) -> usize
     split_index
 }
 
+/// A value of one of three types, used by
+/// [`.partition3_map()`](Itertools::partition3_map) to pick a partition.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Either3<A, B, C> {
+    /// A value of the first type.
+    First(A),
+    /// A value of the second type.
+    Second(B),
+    /// A value of the third type.
+    Third(C),
+}
+
 /// An enum used for controlling the execution of `fold_while`.
 ///
 /// See [`.fold_while()`](Itertools::fold_while) for more information.
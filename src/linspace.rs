@@ -0,0 +1,174 @@
+use std::iter::FusedIterator;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// A floating point type that [`linspace`] and [`arange`] can count in.
+///
+/// Implemented for `f32` and `f64`.
+pub trait LinspaceFloat
+    : Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// The additive identity.
+    fn zero() -> Self;
+    /// Convert an index, rounding to the nearest value.
+    fn from_usize(n: usize) -> Self;
+    /// Convert to an index, rounding towards zero; negative values and NaN
+    /// become 0.
+    fn to_usize(self) -> usize;
+}
+
+macro_rules! impl_linspace_float {
+    ($($T:ty),*) => {
+        $(
+            impl LinspaceFloat for $T {
+                fn zero() -> Self { 0. }
+                fn from_usize(n: usize) -> Self { n as $T }
+                fn to_usize(self) -> usize { self as usize }
+            }
+        )*
+    };
+}
+
+impl_linspace_float!(f32, f64);
+
+/// An iterator over evenly spaced floating point numbers.
+///
+/// Every point is computed from its index, so rounding errors do not
+/// accumulate along the way.
+///
+/// See [`linspace`], [`linspace_exclusive`] and [`arange`] for more
+/// information.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Linspace<T> {
+    start: T,
+    step: T,
+    // the exact value of the point with index `len - 1`, if it is the endpoint
+    stop: Option<T>,
+    len: usize,
+    // the indices of the points that have not been yielded yet
+    front: usize,
+    back: usize,
+}
+
+impl<T: LinspaceFloat> Linspace<T> {
+    fn new(start: T, step: T, stop: Option<T>, len: usize) -> Self {
+        Linspace { start, step, stop, len, front: 0, back: len }
+    }
+
+    fn point(&self, index: usize) -> T {
+        match self.stop {
+            Some(stop) if index + 1 == self.len => stop,
+            _ => self.start + self.step * T::from_usize(index),
+        }
+    }
+}
+
+/// Return an iterator over `n` evenly spaced numbers from `start` to `stop`,
+/// both included.
+///
+/// The first point is exactly `start` and, if `n` is at least 2, the last
+/// one is exactly `stop`. With `n == 1` the only point is `start`.
+///
+/// See also [`linspace_exclusive`] and [`arange`].
+///
+/// ```
+/// use itertools::linspace;
+///
+/// itertools::assert_equal(linspace(0., 1., 5), vec![0., 0.25, 0.5, 0.75, 1.]);
+/// itertools::assert_equal(linspace(1., -1., 3), vec![1., 0., -1.]);
+/// assert_eq!(linspace(0.1, 0.7, 7).last(), Some(0.7));
+/// ```
+pub fn linspace<T: LinspaceFloat>(start: T, stop: T, n: usize) -> Linspace<T> {
+    let step = if n > 1 { (stop - start) / T::from_usize(n - 1) } else { T::zero() };
+    Linspace::new(start, step, if n > 1 { Some(stop) } else { None }, n)
+}
+
+/// Return an iterator over `n` evenly spaced numbers from `start` to `stop`,
+/// with `stop` excluded.
+///
+/// The points are those of `linspace(start, stop, n + 1)` without the last
+/// one, which makes this suited for splitting an interval into `n` equal
+/// parts, or for periodic samples.
+///
+/// ```
+/// use itertools::linspace_exclusive;
+///
+/// itertools::assert_equal(linspace_exclusive(0., 1., 4), vec![0., 0.25, 0.5, 0.75]);
+/// ```
+pub fn linspace_exclusive<T: LinspaceFloat>(start: T, stop: T, n: usize) -> Linspace<T> {
+    let step = if n > 0 { (stop - start) / T::from_usize(n) } else { T::zero() };
+    Linspace::new(start, step, None, n)
+}
+
+/// Return an iterator over the numbers from `start` up to, but excluding,
+/// `stop`, spaced by `step`.
+///
+/// Point `i` is `start + step * i`. The number of points is decided up front
+/// as `(stop - start) / step` rounded up, so it is subject to rounding
+/// errors when that ratio is not exactly representable; prefer [`linspace`]
+/// when the number of points matters. A negative `step` counts down.
+///
+/// If `start` or `stop` is NaN there are no points. If the number of points
+/// does not fit in a `usize`, for example because `stop` is infinite, it is
+/// capped at `usize::MAX`.
+///
+/// **Panics** if `step` is zero or NaN.
+///
+/// ```
+/// use itertools::arange;
+///
+/// itertools::assert_equal(arange(0., 2., 0.5), vec![0., 0.5, 1., 1.5]);
+/// itertools::assert_equal(arange(1., 0., -0.25), vec![1., 0.75, 0.5, 0.25]);
+/// assert_eq!(arange(1., 0., 0.5).count(), 0);
+/// assert_eq!(arange(0., f64::INFINITY, 1.).len(), usize::MAX);
+/// ```
+pub fn arange<T: LinspaceFloat>(start: T, stop: T, step: T) -> Linspace<T> {
+    assert!(step > T::zero() || step < T::zero(), "arange: step must be non-zero");
+    let ratio = (stop - start) / step;
+    let mut len = ratio.to_usize();
+    if T::from_usize(len) < ratio {
+        len = len.saturating_add(1);
+    }
+    Linspace::new(start, step, None, len)
+}
+
+impl<T: LinspaceFloat> Iterator for Linspace<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.point(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front += n.min(self.back - self.front);
+        self.next()
+    }
+}
+
+impl<T: LinspaceFloat> DoubleEndedIterator for Linspace<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.point(self.back))
+    }
+}
+
+impl<T: LinspaceFloat> ExactSizeIterator for Linspace<T> {}
+
+impl<T: LinspaceFloat> FusedIterator for Linspace<T> {}
@@ -0,0 +1,182 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::iter::{Fuse, FusedIterator};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use crate::size_hint;
+
+type Job<T> = (usize, T);
+type JobResult<U> = (usize, thread::Result<U>);
+
+/// An iterator adaptor that maps the elements of the adapted iterator on a
+/// small pool of worker threads, yielding the results in input order.
+///
+/// Dropping the adaptor waits for the worker threads to finish the elements
+/// that are already in flight.
+///
+/// See [`.map_parallel()`](crate::Itertools::map_parallel) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MapParallel<I, U>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    jobs: Option<Sender<Job<I::Item>>>,
+    results: Receiver<JobResult<U>>,
+    workers: Vec<JoinHandle<()>>,
+    /// Finished results, starting at index `next`.
+    buffer: VecDeque<Option<U>>,
+    /// Index of the next element to send to the workers.
+    sent: usize,
+    /// Index of the next element to yield.
+    next: usize,
+    /// Maximum number of elements in flight.
+    window: usize,
+    /// Set once a worker panicked; no more elements are yielded after that.
+    done: bool,
+}
+
+impl<I, U> fmt::Debug for MapParallel<I, U>
+    where I: Iterator + fmt::Debug,
+{
+    debug_fmt_fields!(MapParallel, iter, sent, next, window);
+}
+
+/// Create a new `MapParallel` iterator.
+///
+/// **Panics** if `n` is 0.
+pub fn map_parallel<I, F, U>(iter: I, n: usize, f: F) -> MapParallel<I, U>
+    where I: Iterator,
+          I::Item: Send + 'static,
+          F: Fn(I::Item) -> U + Send + Sync + 'static,
+          U: Send + 'static,
+{
+    assert!(n != 0, "map_parallel: need at least one worker thread");
+    let (job_tx, job_rx) = mpsc::channel::<Job<I::Item>>();
+    let (result_tx, result_rx) = mpsc::channel();
+    let job_rx = Arc::new(Mutex::new(job_rx));
+    let f = Arc::new(f);
+    let workers = (0..n).map(|_| {
+        let job_rx = Arc::clone(&job_rx);
+        let result_tx = result_tx.clone();
+        let f = Arc::clone(&f);
+        thread::spawn(move || loop {
+            // the lock is released before the job runs
+            let job = match job_rx.lock() {
+                Ok(rx) => rx.recv(),
+                Err(_) => return,
+            };
+            let (index, elt) = match job {
+                Ok(job) => job,
+                Err(_) => return,
+            };
+            let res = panic::catch_unwind(AssertUnwindSafe(|| f(elt)));
+            if result_tx.send((index, res)).is_err() {
+                return;
+            }
+        })
+    }).collect();
+    MapParallel {
+        iter: iter.fuse(),
+        jobs: Some(job_tx),
+        results: result_rx,
+        workers,
+        buffer: VecDeque::new(),
+        sent: 0,
+        next: 0,
+        window: n.saturating_mul(2),
+        done: false,
+    }
+}
+
+impl<I, U> MapParallel<I, U>
+    where I: Iterator
+{
+    fn fill(&mut self) {
+        let jobs = match self.jobs {
+            Some(ref jobs) => jobs,
+            None => return,
+        };
+        while self.sent - self.next < self.window {
+            match self.iter.next() {
+                Some(elt) => {
+                    if jobs.send((self.sent, elt)).is_err() {
+                        break;
+                    }
+                    self.sent += 1;
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+impl<I, U> Iterator for MapParallel<I, U>
+    where I: Iterator
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        self.fill();
+        if self.next == self.sent {
+            return None;
+        }
+        loop {
+            if let Some(Some(_)) = self.buffer.front() {
+                self.next += 1;
+                return self.buffer.pop_front().and_then(|res| res);
+            }
+            // every element in flight has a live worker or a queued result
+            let (index, res) = self.results.recv()
+                .expect("map_parallel: worker threads stopped unexpectedly");
+            let res = match res {
+                Ok(res) => res,
+                Err(payload) => {
+                    // the panicked element never gets a result, so a later
+                    // call must not wait for it
+                    self.done = true;
+                    self.jobs = None;
+                    self.buffer.clear();
+                    panic::resume_unwind(payload)
+                }
+            };
+            let offset = index - self.next;
+            while self.buffer.len() <= offset {
+                self.buffer.push_back(None);
+            }
+            self.buffer[offset] = Some(res);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        size_hint::add_scalar(self.iter.size_hint(), self.sent - self.next)
+    }
+}
+
+impl<I, U> ExactSizeIterator for MapParallel<I, U>
+    where I: ExactSizeIterator
+{}
+
+impl<I, U> FusedIterator for MapParallel<I, U>
+    where I: Iterator
+{}
+
+impl<I, U> Drop for MapParallel<I, U>
+    where I: Iterator
+{
+    fn drop(&mut self) {
+        // closing the job queue lets the workers run out
+        self.jobs = None;
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
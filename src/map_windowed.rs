@@ -0,0 +1,208 @@
+use alloc::vec::Vec;
+use std::convert::TryFrom;
+use std::fmt;
+use std::iter::{Fuse, FusedIterator};
+
+use crate::size_hint::{self, SizeHint};
+
+/// A buffer of consecutive elements whose last `size` elements are the
+/// current window.
+///
+/// It holds up to twice that so that it only needs to be shifted every
+/// `size` elements.
+#[derive(Clone, Debug)]
+struct WindowBuf<T> {
+    size: usize,
+    buf: Vec<T>,
+}
+
+impl<T> WindowBuf<T> {
+    fn new(size: usize) -> Self {
+        WindowBuf { size, buf: Vec::new() }
+    }
+
+    /// Slide the window by one element of `iter`, filling it first if it is
+    /// not full yet; `None` if `iter` runs out.
+    fn advance<I>(&mut self, iter: &mut I) -> Option<()>
+        where I: Iterator<Item = T>,
+    {
+        if self.buf.len() < self.size {
+            self.buf.reserve_exact(2 * self.size);
+            while self.buf.len() < self.size {
+                self.buf.push(iter.next()?);
+            }
+        } else {
+            let elt = iter.next()?;
+            if self.buf.len() == 2 * self.size {
+                self.buf.drain(..self.size);
+            }
+            self.buf.push(elt);
+        }
+        Some(())
+    }
+
+    /// The current window; only valid after `advance` succeeded.
+    fn window(&self) -> &[T] {
+        &self.buf[self.buf.len() - self.size..]
+    }
+
+    /// The number of windows left, given the size hint of the iterator.
+    fn size_hint(&self, sh: SizeHint) -> SizeHint {
+        let unfilled = (self.size - 1).saturating_sub(self.buf.len());
+        size_hint::sub_scalar(sh, unfilled)
+    }
+}
+
+/// An iterator adaptor that calls a closure on every window of consecutive
+/// elements, borrowed from a reusable buffer.
+///
+/// See [`.map_windowed()`](crate::Itertools::map_windowed) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MapWindowed<I, F>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    f: F,
+    buf: WindowBuf<I::Item>,
+}
+
+impl<I, F> fmt::Debug for MapWindowed<I, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(MapWindowed, iter, buf);
+}
+
+impl<I, F> Clone for MapWindowed<I, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          F: Clone,
+{
+    clone_fields!(iter, f, buf);
+}
+
+/// Create a new `MapWindowed` iterator.
+pub fn map_windowed<I, F, U>(iter: I, size: usize, f: F) -> MapWindowed<I, F>
+    where I: Iterator,
+          F: FnMut(&[I::Item]) -> U,
+{
+    assert!(size != 0, "map_windowed: window size must be non-zero");
+    MapWindowed {
+        iter: iter.fuse(),
+        f,
+        buf: WindowBuf::new(size),
+    }
+}
+
+impl<I, F, U> Iterator for MapWindowed<I, F>
+    where I: Iterator,
+          F: FnMut(&[I::Item]) -> U,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        self.buf.advance(&mut self.iter)?;
+        Some((self.f)(self.buf.window()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.buf.size_hint(self.iter.size_hint())
+    }
+}
+
+impl<I, F, U> ExactSizeIterator for MapWindowed<I, F>
+    where I: ExactSizeIterator,
+          F: FnMut(&[I::Item]) -> U,
+{}
+
+impl<I, F, U> FusedIterator for MapWindowed<I, F>
+    where I: Iterator,
+          F: FnMut(&[I::Item]) -> U,
+{}
+
+/// An iterator adaptor that calls a closure on every pair of windows of two
+/// iterators in lock step, borrowed from reusable buffers.
+///
+/// See [`.zip_windows()`](crate::Itertools::zip_windows) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ZipWindows<I, J, F, const N: usize>
+    where I: Iterator,
+          J: Iterator,
+{
+    a: Fuse<I>,
+    b: Fuse<J>,
+    f: F,
+    a_buf: WindowBuf<I::Item>,
+    b_buf: WindowBuf<J::Item>,
+}
+
+impl<I, J, F, const N: usize> fmt::Debug for ZipWindows<I, J, F, N>
+    where I: Iterator + fmt::Debug,
+          J: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+          J::Item: fmt::Debug,
+{
+    debug_fmt_fields!(ZipWindows, a, b, a_buf, b_buf);
+}
+
+impl<I, J, F, const N: usize> Clone for ZipWindows<I, J, F, N>
+    where I: Iterator + Clone,
+          J: Iterator + Clone,
+          I::Item: Clone,
+          J::Item: Clone,
+          F: Clone,
+{
+    clone_fields!(a, b, f, a_buf, b_buf);
+}
+
+/// Create a new `ZipWindows` iterator.
+pub fn zip_windows<I, J, F, U, const N: usize>(a: I, b: J, f: F) -> ZipWindows<I, J, F, N>
+    where I: Iterator,
+          J: Iterator,
+          F: FnMut(&[I::Item; N], &[J::Item; N]) -> U,
+{
+    assert!(N != 0, "zip_windows: window size must be non-zero");
+    ZipWindows {
+        a: a.fuse(),
+        b: b.fuse(),
+        f,
+        a_buf: WindowBuf::new(N),
+        b_buf: WindowBuf::new(N),
+    }
+}
+
+impl<I, J, F, U, const N: usize> Iterator for ZipWindows<I, J, F, N>
+    where I: Iterator,
+          J: Iterator,
+          F: FnMut(&[I::Item; N], &[J::Item; N]) -> U,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        self.a_buf.advance(&mut self.a)?;
+        self.b_buf.advance(&mut self.b)?;
+        // both windows hold exactly `N` elements
+        let a = <&[I::Item; N]>::try_from(self.a_buf.window()).ok()?;
+        let b = <&[J::Item; N]>::try_from(self.b_buf.window()).ok()?;
+        Some((self.f)(a, b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::min(self.a_buf.size_hint(self.a.size_hint()),
+                       self.b_buf.size_hint(self.b.size_hint()))
+    }
+}
+
+impl<I, J, F, U, const N: usize> ExactSizeIterator for ZipWindows<I, J, F, N>
+    where I: ExactSizeIterator,
+          J: ExactSizeIterator,
+          F: FnMut(&[I::Item; N], &[J::Item; N]) -> U,
+{}
+
+impl<I, J, F, U, const N: usize> FusedIterator for ZipWindows<I, J, F, N>
+    where I: Iterator,
+          J: Iterator,
+          F: FnMut(&[I::Item; N], &[J::Item; N]) -> U,
+{}
@@ -0,0 +1,97 @@
+use std::fmt;
+use std::iter::{Fuse, FusedIterator};
+use std::mem;
+
+use crate::size_hint;
+
+/// An iterator adaptor that flags the first and last element of every run
+/// of consecutive elements with the same key.
+///
+/// Iterator element type is `(bool, bool, I::Item)`.
+///
+/// See [`.mark_boundaries()`](crate::Itertools::mark_boundaries) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MarkBoundaries<I, K, F>
+    where I: Iterator,
+{
+    iter: Fuse<I>,
+    key: F,
+    // the lookahead element, and its key
+    peeked: Option<(K, I::Item)>,
+    // whether the next element starts a group
+    group_start: bool,
+}
+
+impl<I, K, F> fmt::Debug for MarkBoundaries<I, K, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+          K: fmt::Debug,
+{
+    debug_fmt_fields!(MarkBoundaries, iter, peeked, group_start);
+}
+
+impl<I, K, F> Clone for MarkBoundaries<I, K, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          K: Clone,
+          F: Clone,
+{
+    clone_fields!(iter, key, peeked, group_start);
+}
+
+/// Create a new `MarkBoundaries` iterator.
+pub fn mark_boundaries<I, K, F>(iter: I, key: F) -> MarkBoundaries<I, K, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{
+    MarkBoundaries {
+        iter: iter.fuse(),
+        key,
+        peeked: None,
+        group_start: true,
+    }
+}
+
+impl<I, K, F> Iterator for MarkBoundaries<I, K, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{
+    type Item = (bool, bool, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = &mut self.key;
+        let (current_key, elt) = match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => {
+                let elt = self.iter.next()?;
+                (key(&elt), elt)
+            }
+        };
+        self.peeked = self.iter.next().map(|elt| (key(&elt), elt));
+        let is_last = match self.peeked {
+            Some((ref next_key, _)) => *next_key != current_key,
+            None => true,
+        };
+        let is_first = mem::replace(&mut self.group_start, is_last);
+        Some((is_first, is_last, elt))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::add_scalar(self.iter.size_hint(), self.peeked.is_some() as usize)
+    }
+}
+
+impl<I, K, F> ExactSizeIterator for MarkBoundaries<I, K, F>
+    where I: ExactSizeIterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{}
+
+impl<I, K, F> FusedIterator for MarkBoundaries<I, K, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{}
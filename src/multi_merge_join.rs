@@ -0,0 +1,99 @@
+use alloc::vec::Vec;
+use std::fmt;
+use std::iter::{Fuse, FusedIterator};
+
+use crate::size_hint;
+
+/// An iterator adaptor that merge-joins any number of iterators sorted by
+/// key, yielding the items of every iterator that share the smallest key.
+///
+/// Iterator element type is `Vec<Option<I::Item>>`.
+///
+/// See [`.multi_merge_join_by_key()`](crate::Itertools::multi_merge_join_by_key)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MultiMergeJoinByKey<I, K, F>
+    where I: Iterator,
+{
+    iters: Vec<Fuse<I>>,
+    // the next item of each iterator, and its key
+    heads: Vec<Option<(K, I::Item)>>,
+    key: F,
+}
+
+impl<I, K, F> fmt::Debug for MultiMergeJoinByKey<I, K, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+          K: fmt::Debug,
+{
+    debug_fmt_fields!(MultiMergeJoinByKey, iters, heads);
+}
+
+impl<I, K, F> Clone for MultiMergeJoinByKey<I, K, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          K: Clone,
+          F: Clone,
+{
+    clone_fields!(iters, heads, key);
+}
+
+/// Create a new `MultiMergeJoinByKey` iterator.
+pub fn multi_merge_join_by_key<I, K, F>(iterable: I, key: F)
+    -> MultiMergeJoinByKey<<I::Item as IntoIterator>::IntoIter, K, F>
+    where I: IntoIterator,
+          I::Item: IntoIterator,
+          F: FnMut(&<I::Item as IntoIterator>::Item) -> K,
+          K: Ord,
+{
+    let iters: Vec<_> = iterable.into_iter().map(|it| it.into_iter().fuse()).collect();
+    let heads = iters.iter().map(|_| None).collect();
+    MultiMergeJoinByKey { iters, heads, key }
+}
+
+impl<I, K, F> Iterator for MultiMergeJoinByKey<I, K, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: Ord,
+{
+    type Item = Vec<Option<I::Item>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = &mut self.key;
+        for (head, iter) in self.heads.iter_mut().zip(&mut self.iters) {
+            if head.is_none() {
+                *head = iter.next().map(|elt| (key(&elt), elt));
+            }
+        }
+        let min_key = self.heads.iter().flatten().map(|(key, _)| key).min()?;
+        let is_min: Vec<bool> = self.heads.iter()
+            .map(|head| matches!(head, Some((key, _)) if key == min_key))
+            .collect();
+        let row = self.heads.iter_mut()
+            .zip(is_min)
+            .map(|(head, is_min)| if is_min { head.take().map(|(_, elt)| elt) } else { None })
+            .collect();
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // every row takes at most one item, and at least one item in total,
+        // from the iterators
+        self.iters.iter()
+            .zip(&self.heads)
+            .map(|(iter, head)| size_hint::add_scalar(iter.size_hint(), head.is_some() as usize))
+            .fold((0, Some(0)), |(low, hi), (iter_low, iter_hi)| {
+                let hi = match (hi, iter_hi) {
+                    (Some(hi), Some(iter_hi)) => hi.checked_add(iter_hi),
+                    _ => None,
+                };
+                (low.max(iter_low), hi)
+            })
+    }
+}
+
+impl<I, K, F> FusedIterator for MultiMergeJoinByKey<I, K, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: Ord,
+{}
@@ -1,5 +1,5 @@
 use std::iter::Fuse;
-use alloc::collections::VecDeque;
+use alloc::collections::vec_deque::{self, VecDeque};
 use crate::size_hint;
 use crate::PeekingNext;
 
@@ -55,6 +55,23 @@ impl<I: Iterator> MultiPeek<I> {
         self.index += 1;
         ret
     }
+
+    /// Returns an iterator over the items that have been peeked at but not
+    /// yet consumed, in order, regardless of the peeking “cursor”.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut iter = (1..10).multipeek();
+    /// iter.peek();
+    /// iter.peek();
+    /// itertools::assert_equal(iter.peeked(), &[1, 2]);
+    /// iter.next();
+    /// itertools::assert_equal(iter.peeked(), &[2]);
+    /// ```
+    pub fn peeked(&self) -> vec_deque::Iter<'_, I::Item> {
+        self.buf.iter()
+    }
 }
 
 impl<I> PeekingNext for MultiPeek<I>
@@ -89,6 +106,13 @@ impl<I> Iterator for MultiPeek<I>
     fn size_hint(&self) -> (usize, Option<usize>) {
         size_hint::add_scalar(self.iter.size_hint(), self.buf.len())
     }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+        where F: FnMut(B, Self::Item) -> B,
+    {
+        let acc = self.buf.into_iter().fold(init, &mut f);
+        self.iter.fold(acc, f)
+    }
 }
 
 // Same size
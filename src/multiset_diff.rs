@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::hash::Hash;
+
+/// The elements that two iterators do not have in common, regardless of
+/// their order.
+///
+/// See [`.diff_multiset()`](crate::Itertools::diff_multiset) for more
+/// information.
+#[derive(Clone, Debug)]
+pub struct MultisetDiff<T> {
+    /// The elements that the first iterator has more of, and how many more.
+    pub only_left: HashMap<T, usize>,
+    /// The elements that the second iterator has more of, and how many more.
+    pub only_right: HashMap<T, usize>,
+}
+
+impl<T> MultisetDiff<T> {
+    /// Return `true` if both iterators yielded the same elements, the same
+    /// number of times.
+    pub fn is_empty(&self) -> bool {
+        self.only_left.is_empty() && self.only_right.is_empty()
+    }
+}
+
+impl<T> Display for MultisetDiff<T>
+    where T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "only in left: {:?}, only in right: {:?}", self.only_left, self.only_right)
+    }
+}
+
+/// Compare the elements of `left` and `right` as multisets.
+///
+/// See [`.diff_multiset()`](crate::Itertools::diff_multiset) for more
+/// information.
+pub fn diff_multiset<I, J>(left: I, right: J) -> MultisetDiff<I::Item>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Eq + Hash,
+{
+    let mut only_left = HashMap::new();
+    left.for_each(|elt| *only_left.entry(elt).or_insert(0) += 1);
+    let mut only_right = HashMap::new();
+    right.for_each(|elt| match only_left.get_mut(&elt) {
+        Some(count) if *count > 0 => *count -= 1,
+        _ => *only_right.entry(elt).or_insert(0) += 1,
+    });
+    only_left.retain(|_, count| *count > 0);
+    MultisetDiff { only_left, only_right }
+}
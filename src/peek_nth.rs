@@ -1,6 +1,6 @@
 use crate::size_hint;
 use crate::PeekingNext;
-use alloc::collections::VecDeque;
+use alloc::collections::vec_deque::{self, VecDeque};
 use std::iter::Fuse;
 
 /// See [`peek_nth()`] for more information.
@@ -69,6 +69,24 @@ where
 
         self.buf.get(n)
     }
+
+    /// Returns an iterator over the items that have been peeked at but not
+    /// yet consumed, in order.
+    ///
+    /// ```rust
+    /// use itertools::{peek_nth, Itertools};
+    ///
+    /// let mut iter = peek_nth(1..10);
+    /// iter.peek_nth(2);
+    /// itertools::assert_equal(iter.peeked(), &[1, 2, 3]);
+    ///
+    /// // lookahead can be mixed with `peeking_take_while`
+    /// itertools::assert_equal(iter.peeking_take_while(|&x| x < 3), 1..3);
+    /// itertools::assert_equal(iter.peeked(), &[3]);
+    /// ```
+    pub fn peeked(&self) -> vec_deque::Iter<'_, I::Item> {
+        self.buf.iter()
+    }
 }
 
 impl<I> Iterator for PeekNth<I>
@@ -84,6 +102,14 @@ where
     fn size_hint(&self) -> (usize, Option<usize>) {
         size_hint::add_scalar(self.iter.size_hint(), self.buf.len())
     }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let acc = self.buf.into_iter().fold(init, &mut f);
+        self.iter.fold(acc, f)
+    }
 }
 
 impl<I> ExactSizeIterator for PeekNth<I> where I: ExactSizeIterator {}
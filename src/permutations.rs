@@ -1,25 +1,35 @@
 use alloc::vec::Vec;
 use std::fmt;
+use std::convert::TryFrom;
 use std::iter::once;
 
 use super::lazy_buffer::LazyBuffer;
+use super::size_hint;
 
 /// An iterator adaptor that iterates through all the `k`-permutations of the
 /// elements from an iterator.
 ///
 /// See [`.permutations()`](crate::Itertools::permutations) for
 /// more information.
+///
+/// Until the source iterator is exhausted, the size hint is only
+/// `(0, None)`. After that it is exact, or `(usize::MAX, None)` if the count
+/// overflows a `usize`, the saturation policy of
+/// [`Combinations`](crate::structs::Combinations).
+/// [`exact_count`](Permutations::exact_count) returns the exact count.
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct Permutations<I: Iterator> {
     vals: LazyBuffer<I>,
     state: PermutationState,
+    // the number of permutations yielded so far
+    rank: usize,
 }
 
 impl<I> Clone for Permutations<I>
     where I: Clone + Iterator,
           I::Item: Clone,
 {
-    clone_fields!(vals, state);
+    clone_fields!(vals, state, rank);
 }
 
 #[derive(Clone, Debug)]
@@ -47,16 +57,11 @@ enum CompleteState {
     }
 }
 
-enum CompleteStateRemaining {
-    Known(usize),
-    Overflow,
-}
-
 impl<I> fmt::Debug for Permutations<I>
     where I: Iterator + fmt::Debug,
           I::Item: fmt::Debug,
 {
-    debug_fmt_fields!(Permutations, vals, state);
+    debug_fmt_fields!(Permutations, vals, state, rank);
 }
 
 pub fn permutations<I: Iterator>(iter: I, k: usize) -> Permutations<I> {
@@ -68,7 +73,8 @@ pub fn permutations<I: Iterator>(iter: I, k: usize) -> Permutations<I> {
 
         return Permutations {
             vals,
-            state
+            state,
+            rank: 0,
         };
     }
 
@@ -89,7 +95,8 @@ pub fn permutations<I: Iterator>(iter: I, k: usize) -> Permutations<I> {
 
     Permutations {
         vals,
-        state
+        state,
+        rank: 0,
     }
 }
 
@@ -103,9 +110,9 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.advance();
 
-        let &mut Permutations { ref vals, ref state } = self;
+        let &mut Permutations { ref vals, ref state, ref mut rank } = self;
 
-        match *state {
+        let item = match *state {
             PermutationState::StartUnknownLen { .. } => panic!("unexpected iterator state"),
             PermutationState::OngoingUnknownLen { k, min_n } => {
                 let latest_idx = min_n - 1;
@@ -120,49 +127,77 @@ where
                 Some(indices[0..k].iter().map(|&i| vals[i].clone()).collect())
             },
             PermutationState::Empty => None
+        };
+        if item.is_some() {
+            *rank += 1;
         }
+        item
     }
 
     fn count(self) -> usize {
-        let Permutations { vals, state } = self;
+        match self.exact_count().and_then(|count| usize::try_from(count).ok()) {
+            Some(count) => count,
+            None => panic!("Iterator count greater than usize::MAX"),
+        }
+    }
 
-        fn from_complete(complete_state: CompleteState) -> usize {
-            match complete_state.remaining() {
-                CompleteStateRemaining::Known(count) => count,
-                CompleteStateRemaining::Overflow => {
-                    panic!("Iterator count greater than usize::MAX");
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.state {
+            PermutationState::StartUnknownLen { .. } |
+            PermutationState::OngoingUnknownLen { .. } => (0, None), // TODO can we improve this lower bound?
+            PermutationState::Complete(ref state) => {
+                match state.remaining().and_then(|count| usize::try_from(count).ok()) {
+                    Some(count) => (count, Some(count)),
+                    None => (::std::usize::MAX, None)
                 }
             }
+            PermutationState::Empty => (0, Some(0))
         }
+    }
+}
+
+impl<I: Iterator> Permutations<I> {
+    /// Consume the iterator and return the number of permutations it would
+    /// still yield, without building them.
+    ///
+    /// Unlike `.count()`, which panics if the count overflows `usize`, this
+    /// saturates at `u128::MAX`. The source iterator is run to completion to
+    /// find its length, unless its size hint reports it as unbounded, like
+    /// `0..` does; then the count is infinite if `k` is not 0, and `None` is
+    /// returned.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut perms = (0..4).permutations(3);
+    /// perms.next();
+    /// assert_eq!(perms.exact_count(), Some(23));
+    ///
+    /// // 30! / 10!, which overflows a `u64`
+    /// assert_eq!((0..30).permutations(20).exact_count(), Some(73096577329197271449600000));
+    ///
+    /// assert_eq!((0..100).permutations(50).exact_count(), Some(u128::MAX));
+    /// assert_eq!((0..).permutations(2).exact_count(), None);
+    /// ```
+    pub fn exact_count(self) -> Option<u128> {
+        let Permutations { vals, state, .. } = self;
 
         match state {
+            PermutationState::StartUnknownLen { .. } |
+            PermutationState::OngoingUnknownLen { .. }
+                if size_hint::is_unbounded(vals.size_hint()) => None,
             PermutationState::StartUnknownLen { k } => {
-                let n = vals.len() + vals.it.count();
-                let complete_state = CompleteState::Start { n, k };
-
-                from_complete(complete_state)
+                let n = vals.total_len();
+                Some(CompleteState::Start { n, k }.remaining().unwrap_or(u128::MAX))
             }
             PermutationState::OngoingUnknownLen { k, min_n } => {
-                let prev_iteration_count = min_n - k + 1;
-                let n = vals.len() + vals.it.count();
-                let complete_state = CompleteState::Start { n, k };
-
-                from_complete(complete_state) - prev_iteration_count
-            },
-            PermutationState::Complete(state) => from_complete(state),
-            PermutationState::Empty => 0
-        }
-    }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        match self.state {
-            PermutationState::StartUnknownLen { .. } |
-            PermutationState::OngoingUnknownLen { .. } => (0, None), // TODO can we improve this lower bound?
-            PermutationState::Complete(ref state) => match state.remaining() {
-                CompleteStateRemaining::Known(count) => (count, Some(count)),
-                CompleteStateRemaining::Overflow => (::std::usize::MAX, None)
+                let prev_iteration_count = (min_n - k + 1) as u128;
+                let n = vals.total_len();
+                Some(CompleteState::Start { n, k }.remaining()
+                     .map_or(u128::MAX, |count| count - prev_iteration_count))
             }
-            PermutationState::Empty => (0, Some(0))
+            PermutationState::Complete(state) => Some(state.remaining().unwrap_or(u128::MAX)),
+            PermutationState::Empty => Some(0),
         }
     }
 }
@@ -172,8 +207,46 @@ where
     I: Iterator,
     I::Item: Clone
 {
+    /// Returns the number of permutations yielded so far, which is also the
+    /// rank of the next one in the iteration order.
+    #[inline]
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Skip ahead until [`rank`](Permutations::rank) is `rank`, without
+    /// building the skipped permutations. If there are fewer permutations,
+    /// the iterator is exhausted.
+    ///
+    /// **Panics** if `rank` is less than the current rank.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut it = (0..3).permutations(2);
+    /// it.advance_to_rank(3);
+    /// assert_eq!(it.rank(), 3);
+    /// assert_eq!(it.size_hint(), (3, Some(3)));
+    /// assert_eq!(it.next(), Some(vec![1, 2]));
+    /// assert_eq!(it.rank(), 4);
+    /// ```
+    pub fn advance_to_rank(&mut self, rank: usize) {
+        assert!(rank >= self.rank, "advance_to_rank: cannot go back from rank {} to {}", self.rank, rank);
+        while self.rank < rank {
+            self.advance();
+            match self.state {
+                PermutationState::OngoingUnknownLen { .. } |
+                PermutationState::Complete(CompleteState::Ongoing { .. }) => self.rank += 1,
+                _ => {
+                    self.state = PermutationState::Empty;
+                    return;
+                }
+            }
+        }
+    }
+
     fn advance(&mut self) {
-        let &mut Permutations { ref mut vals, ref mut state } = self;
+        let &mut Permutations { ref mut vals, ref mut state, .. } = self;
 
         *state = match *state {
             PermutationState::StartUnknownLen { k } => {
@@ -241,39 +314,26 @@ impl CompleteState {
         }
     }
 
-    fn remaining(&self) -> CompleteStateRemaining {
-        use self::CompleteStateRemaining::{Known, Overflow};
-
+    /// Returns the number of permutations left, or `None` if it does not fit
+    /// in a `u128`.
+    fn remaining(&self) -> Option<u128> {
         match *self {
             CompleteState::Start { n, k } => {
                 if n < k {
-                    return Known(0);
+                    return Some(0);
                 }
 
-                let count: Option<usize> = (n - k + 1..n + 1).fold(Some(1), |acc, i| {
-                    acc.and_then(|acc| acc.checked_mul(i))
-                });
-
-                match count {
-                    Some(count) => Known(count),
-                    None => Overflow
-                }
+                (n - k + 1..n + 1).try_fold(1u128, |acc, i| acc.checked_mul(i as u128))
             }
             CompleteState::Ongoing { ref indices, ref cycles } => {
-                let mut count: usize = 0;
+                let mut count: u128 = 0;
 
                 for (i, &c) in cycles.iter().enumerate() {
-                    let radix = indices.len() - i;
-                    let next_count = count.checked_mul(radix)
-                        .and_then(|count| count.checked_add(c));
-
-                    count = match next_count {
-                        Some(count) => count,
-                        None => { return Overflow; }
-                    };
+                    let radix = (indices.len() - i) as u128;
+                    count = count.checked_mul(radix)?.checked_add(c as u128)?;
                 }
 
-                Known(count)
+                Some(count)
             }
         }
     }
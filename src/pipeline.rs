@@ -0,0 +1,254 @@
+//! Iterator pipelines assembled at runtime.
+//!
+//! Each free function in this module returns a boxed, object-safe [`Stage`]:
+//! an adaptor that has not been applied to an iterator yet. Stages that keep
+//! the element type can be stored in a list — built from configuration, a
+//! plugin registry, etc. — and run in order with [`apply`]:
+//!
+//! ```
+//! use itertools::pipeline::{self, Stage};
+//!
+//! let mut stages = vec![pipeline::filter(|x: &i32| x % 2 == 0)];
+//! let scale = true;
+//! if scale {
+//!     stages.push(pipeline::map(|x| x * 10));
+//! }
+//! stages.push(pipeline::take(3));
+//!
+//! let it = pipeline::apply(0..100, stages);
+//! itertools::assert_equal(it, vec![0, 20, 40]);
+//! ```
+//!
+//! Stages that change the element type, like [`chunks`], are run on their
+//! own with [`Stage::run`]:
+//!
+//! ```
+//! use itertools::pipeline::{self, Stage};
+//!
+//! let it = pipeline::chunks(2).run(pipeline::apply(1..6, vec![]));
+//! itertools::assert_equal(it, vec![vec![1, 2], vec![3, 4], vec![5]]);
+//! ```
+//!
+//! The same adaptors are available as methods through [`DynItertools`].
+//!
+//! The iterators are boxed at every stage, trading a virtual call per element
+//! for a single, nameable iterator type.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::Itertools;
+
+/// A boxed iterator, the input and output of every [`Stage`].
+pub type BoxIter<'a, T> = Box<dyn Iterator<Item = T> + 'a>;
+
+/// An iterator adaptor that is applied to a boxed iterator at runtime.
+///
+/// This trait is object safe; the functions in this module return
+/// `Box<dyn Stage>` so that stages of different kinds can be stored together.
+pub trait Stage<'a, In, Out = In> {
+    /// Apply the stage to `input`.
+    fn run(self: Box<Self>, input: BoxIter<'a, In>) -> BoxIter<'a, Out>;
+}
+
+/// An extension trait with adaptors that return a [`BoxIter`].
+///
+/// Chaining these methods keeps the iterator type flat — `BoxIter<'a, T>`
+/// instead of nested adaptor types — which keeps compile times and type
+/// names manageable for long pipelines, and lets branches that build
+/// different pipelines produce the same type.
+///
+/// ```
+/// use itertools::pipeline::{self, BoxIter, DynItertools};
+///
+/// fn words<'a>(text: &'a str, skip_short: bool) -> BoxIter<'a, String> {
+///     let mut it = text.split_whitespace().boxed();
+///     if skip_short {
+///         it = it.boxed_filter(|w| w.len() > 2);
+///     }
+///     it.boxed_map(str::to_uppercase)
+/// }
+///
+/// itertools::assert_equal(words("a fox is quick", true), vec!["FOX", "QUICK"]);
+/// itertools::assert_equal(words("a fox", false), vec!["A", "FOX"]);
+///
+/// let chunks = (1..6).boxed_apply(pipeline::chunks(2));
+/// itertools::assert_equal(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+/// ```
+pub trait DynItertools<'a>: Iterator + 'a {
+    /// Box the iterator.
+    fn boxed(self) -> BoxIter<'a, Self::Item>
+        where Self: Sized,
+    {
+        Box::new(self)
+    }
+
+    /// Apply `stage` to the boxed iterator.
+    fn boxed_apply<U>(self, stage: Box<dyn Stage<'a, Self::Item, U> + 'a>) -> BoxIter<'a, U>
+        where Self: Sized,
+    {
+        stage.run(self.boxed())
+    }
+
+    /// Like [`Iterator::map`], boxed.
+    fn boxed_map<U, F>(self, f: F) -> BoxIter<'a, U>
+        where Self: Sized,
+              F: FnMut(Self::Item) -> U + 'a,
+    {
+        Box::new(self.map(f))
+    }
+
+    /// Like [`Iterator::filter`], boxed.
+    fn boxed_filter<F>(self, f: F) -> BoxIter<'a, Self::Item>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> bool + 'a,
+    {
+        Box::new(self.filter(f))
+    }
+
+    /// Like [`Iterator::filter_map`], boxed.
+    fn boxed_filter_map<U, F>(self, f: F) -> BoxIter<'a, U>
+        where Self: Sized,
+              F: FnMut(Self::Item) -> Option<U> + 'a,
+    {
+        Box::new(self.filter_map(f))
+    }
+
+    /// Like [`Iterator::flat_map`], boxed.
+    fn boxed_flat_map<U, F>(self, f: F) -> BoxIter<'a, U::Item>
+        where Self: Sized,
+              U: IntoIterator + 'a,
+              U::IntoIter: 'a,
+              F: FnMut(Self::Item) -> U + 'a,
+    {
+        Box::new(self.flat_map(f))
+    }
+
+    /// Like [`Iterator::chain`], boxed.
+    fn boxed_chain<J>(self, other: J) -> BoxIter<'a, Self::Item>
+        where Self: Sized,
+              J: IntoIterator<Item = Self::Item>,
+              J::IntoIter: 'a,
+    {
+        Box::new(self.chain(other))
+    }
+
+    /// Like [`Iterator::take`], boxed.
+    fn boxed_take(self, n: usize) -> BoxIter<'a, Self::Item>
+        where Self: Sized,
+    {
+        Box::new(self.take(n))
+    }
+
+    /// Like [`Iterator::skip`], boxed.
+    fn boxed_skip(self, n: usize) -> BoxIter<'a, Self::Item>
+        where Self: Sized,
+    {
+        Box::new(self.skip(n))
+    }
+
+    /// Like [`.dedup()`](Itertools::dedup), boxed.
+    fn boxed_dedup(self) -> BoxIter<'a, Self::Item>
+        where Self: Sized,
+              Self::Item: PartialEq,
+    {
+        Box::new(self.dedup())
+    }
+}
+
+impl<'a, I> DynItertools<'a> for I where I: Iterator + 'a {}
+
+struct FnStage<F>(F);
+
+impl<'a, In, Out, F> Stage<'a, In, Out> for FnStage<F>
+    where F: FnOnce(BoxIter<'a, In>) -> BoxIter<'a, Out>
+{
+    fn run(self: Box<Self>, input: BoxIter<'a, In>) -> BoxIter<'a, Out> {
+        (self.0)(input)
+    }
+}
+
+/// Run `iterable` through `stages` in order.
+///
+/// See the [module documentation](self) for an example.
+pub fn apply<'a, I, S>(iterable: I, stages: S) -> BoxIter<'a, I::Item>
+    where I: IntoIterator,
+          I::IntoIter: 'a,
+          S: IntoIterator<Item = Box<dyn Stage<'a, I::Item> + 'a>>,
+{
+    let input: BoxIter<'a, I::Item> = Box::new(iterable.into_iter());
+    stages.into_iter().fold(input, |iter, stage| stage.run(iter))
+}
+
+/// A stage from an arbitrary function on boxed iterators, to plug in
+/// adaptors that have no builder in this module.
+///
+/// ```
+/// use itertools::Itertools;
+/// use itertools::pipeline::{self, BoxIter};
+///
+/// let dedup = pipeline::stage(|it: BoxIter<'_, i32>| Box::new(it.dedup()));
+/// itertools::assert_equal(pipeline::apply(vec![1, 1, 2, 1], vec![dedup]), vec![1, 2, 1]);
+/// ```
+pub fn stage<'a, In, Out, F>(f: F) -> Box<dyn Stage<'a, In, Out> + 'a>
+    where F: FnOnce(BoxIter<'a, In>) -> BoxIter<'a, Out> + 'a,
+{
+    Box::new(FnStage(f))
+}
+
+/// A stage that maps every element with `f`, like [`Iterator::map`].
+pub fn map<'a, T, U, F>(f: F) -> Box<dyn Stage<'a, T, U> + 'a>
+    where T: 'a,
+          U: 'a,
+          F: FnMut(T) -> U + 'a,
+{
+    stage(move |it: BoxIter<'a, T>| Box::new(it.map(f)))
+}
+
+/// A stage that keeps the elements accepted by `f`, like [`Iterator::filter`].
+pub fn filter<'a, T, F>(f: F) -> Box<dyn Stage<'a, T> + 'a>
+    where T: 'a,
+          F: FnMut(&T) -> bool + 'a,
+{
+    stage(move |it: BoxIter<'a, T>| Box::new(it.filter(f)))
+}
+
+/// A stage that maps and filters the elements with `f`, like
+/// [`Iterator::filter_map`].
+pub fn filter_map<'a, T, U, F>(f: F) -> Box<dyn Stage<'a, T, U> + 'a>
+    where T: 'a,
+          U: 'a,
+          F: FnMut(T) -> Option<U> + 'a,
+{
+    stage(move |it: BoxIter<'a, T>| Box::new(it.filter_map(f)))
+}
+
+/// A stage that yields at most the first `n` elements, like [`Iterator::take`].
+pub fn take<'a, T: 'a>(n: usize) -> Box<dyn Stage<'a, T> + 'a> {
+    stage(move |it: BoxIter<'a, T>| Box::new(it.take(n)))
+}
+
+/// A stage that skips the first `n` elements, like [`Iterator::skip`].
+pub fn skip<'a, T: 'a>(n: usize) -> Box<dyn Stage<'a, T> + 'a> {
+    stage(move |it: BoxIter<'a, T>| Box::new(it.skip(n)))
+}
+
+/// A stage that removes consecutive duplicates, like
+/// [`.dedup()`](Itertools::dedup).
+pub fn dedup<'a, T>() -> Box<dyn Stage<'a, T> + 'a>
+    where T: PartialEq + 'a,
+{
+    stage(|it: BoxIter<'a, T>| Box::new(it.dedup()))
+}
+
+/// A stage that groups the elements in vectors of `size` elements; the last
+/// vector is shorter if there aren't enough elements.
+///
+/// **Panics** if `size` is 0.
+pub fn chunks<'a, T: 'a>(size: usize) -> Box<dyn Stage<'a, T, Vec<T>> + 'a> {
+    assert!(size != 0);
+    stage(move |it: BoxIter<'a, T>| Box::new(it.batching(move |it| {
+        let chunk: Vec<T> = it.take(size).collect();
+        if chunk.is_empty() { None } else { Some(chunk) }
+    })))
+}
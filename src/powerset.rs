@@ -3,13 +3,19 @@ use std::iter::FusedIterator;
 use std::usize;
 use alloc::vec::Vec;
 
-use super::combinations::{Combinations, combinations};
+use super::combinations::{Combinations, binomial, combinations};
+use super::lazy_buffer::LazyBuffer;
 use super::size_hint;
 
 /// An iterator to iterate through the powerset of the elements from an iterator.
 ///
 /// See [`.powerset()`](crate::Itertools::powerset) for more
 /// information.
+///
+/// A powerset quickly outgrows a `usize`: past that, the lower bound of its
+/// size hint saturates at `usize::MAX` and the upper bound is `None`, as for
+/// [`Combinations`]. [`exact_count`](Powerset::exact_count) counts up to
+/// `u128::MAX`.
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct Powerset<I: Iterator> {
     combs: Combinations<I>,
@@ -42,6 +48,42 @@ pub fn powerset<I>(src: I) -> Powerset<I>
     }
 }
 
+impl<I: Iterator> Powerset<I> {
+    /// Consume the iterator and return the number of subsets it would still
+    /// yield, without building them.
+    ///
+    /// The count saturates at `u128::MAX`. The source iterator is run to
+    /// completion to find its length, unless its size hint reports it as
+    /// unbounded, like `0..` does; then the count is infinite, and `None` is
+    /// returned.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut sets = (0..10).powerset();
+    /// sets.next();
+    /// assert_eq!(sets.exact_count(), Some(1023));
+    ///
+    /// assert_eq!((0..100).powerset().exact_count(), Some(1 << 100));
+    /// assert_eq!((0..128).powerset().exact_count(), Some(u128::MAX));
+    /// assert_eq!((0..).powerset().exact_count(), None);
+    /// ```
+    pub fn exact_count(self) -> Option<u128> {
+        let k = self.combs.k();
+        let rank = self.combs.rank() as u128;
+        let pool = self.combs.into_pool();
+        if size_hint::is_unbounded(pool.size_hint()) {
+            return None;
+        }
+        let n = pool.total_len();
+        // the rest of the current size, then every larger size
+        let rest = binomial(n, k).map_or(u128::MAX, |count| count - rank);
+        Some((k + 1..n + 1).fold(rest, |count, size| {
+            count.saturating_add(binomial(n, size).unwrap_or(u128::MAX))
+        }))
+    }
+}
+
 impl<I> Iterator for Powerset<I>
     where
         I: Iterator,
@@ -88,3 +130,88 @@ impl<I> FusedIterator for Powerset<I>
         I: Iterator,
         I::Item: Clone,
 {}
+
+/// An iterator to iterate through the powerset of the elements from an
+/// iterator, reading the elements only as they are needed.
+///
+/// See [`.powerset_bfs()`](crate::Itertools::powerset_bfs) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct PowersetBfs<I: Iterator> {
+    buffer: LazyBuffer<I>,
+    // The next subset holds the elements whose bits are set; this is also
+    // the count of yielded subsets.
+    mask: usize,
+    done: bool,
+}
+
+impl<I> Clone for PowersetBfs<I>
+    where I: Clone + Iterator,
+          I::Item: Clone,
+{
+    clone_fields!(buffer, mask, done);
+}
+
+impl<I> fmt::Debug for PowersetBfs<I>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(PowersetBfs, buffer, mask, done);
+}
+
+/// Create a new `PowersetBfs` from a clonable iterator.
+pub fn powerset_bfs<I>(src: I) -> PowersetBfs<I>
+    where I: Iterator,
+          I::Item: Clone,
+{
+    PowersetBfs {
+        buffer: LazyBuffer::new(src),
+        mask: 0,
+        done: false,
+    }
+}
+
+impl<I> Iterator for PowersetBfs<I>
+    where
+        I: Iterator,
+        I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let needed = (usize::BITS - self.mask.leading_zeros()) as usize;
+        self.buffer.prefill(needed);
+        if self.buffer.len() < needed {
+            // Every later subset needs this element too.
+            self.done = true;
+            return None;
+        }
+        let mask = self.mask;
+        let subset = (0..needed)
+            .filter(|i| mask & (1 << i) != 0)
+            .map(|i| self.buffer[i].clone())
+            .collect();
+        match self.mask.checked_add(1) {
+            Some(mask) => self.mask = mask,
+            None => self.done = true,
+        }
+        Some(subset)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let self_total = size_hint::pow_scalar_base(2, self.buffer.size_hint());
+        size_hint::sub_scalar(self_total, self.mask)
+    }
+}
+
+impl<I> FusedIterator for PowersetBfs<I>
+    where
+        I: Iterator,
+        I::Item: Clone,
+{}
@@ -95,3 +95,122 @@ pub fn process_results<I, F, T, E, R>(iterable: I, processor: F) -> Result<R, E>
 
     error.map(|_| result)
 }
+
+/// Like [`process_results`], but hands back the closure's output even if the
+/// original iterable produced an error.
+///
+/// The adapted iterator ends at the first error, as with `process_results`;
+/// the closure still runs to completion over the values seen before it, and
+/// its return value is returned alongside the error as `Err((error, partial))`.
+///
+/// # Example
+///
+/// ```
+/// use itertools::process_results_partial;
+///
+/// type R = Result<i32, &'static str>;
+///
+/// let values: Vec<R> = vec![Ok(1), Ok(2), Err("bad record"), Ok(4)];
+///
+/// let done = process_results_partial(values, |iter| iter.collect::<Vec<_>>());
+///
+/// assert_eq!(done, Err(("bad record", vec![1, 2])));
+/// ```
+pub fn process_results_partial<I, F, T, E, R>(iterable: I, processor: F) -> Result<R, (E, R)>
+    where I: IntoIterator<Item = Result<T, E>>,
+          F: FnOnce(ProcessResults<I::IntoIter, E>) -> R
+{
+    let iter = iterable.into_iter();
+    let mut error = Ok(());
+
+    let result = processor(ProcessResults { error: &mut error, iter });
+
+    match error {
+        Ok(()) => Ok(result),
+        Err(e) => Err((e, result)),
+    }
+}
+
+/// An iterator that produces only the `T` values as long as the
+/// inner iterator produces `Some(T)`.
+///
+/// Used by [`process_options`](crate::process_options), see its docs
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug)]
+pub struct ProcessOptions<'a, I> {
+    found_none: &'a mut bool,
+    iter: I,
+}
+
+impl<'a, I, T> Iterator for ProcessOptions<'a, I>
+    where I: Iterator<Item = Option<T>>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(Some(x)) => Some(x),
+            Some(None) => {
+                *self.found_none = true;
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let found_none = self.found_none;
+        self.iter
+            .try_fold(init, |acc, opt| match opt {
+                Some(x) => Ok(f(acc, x)),
+                None => {
+                    *found_none = true;
+                    Err(acc)
+                }
+            })
+            .unwrap_or_else(|e| e)
+    }
+}
+
+/// “Lift” a function of the values of an iterator so that it can process
+/// an iterator of `Option` values instead.
+///
+/// This is the `Option` equivalent of [`process_results`]: the closure
+/// receives an iterator of the `T` values, which ends at the first `None`.
+/// If there was a `None`, `process_options` returns `None`, otherwise the
+/// return value from the closure wrapped in `Some`.
+///
+/// # Example
+///
+/// ```
+/// use itertools::process_options;
+///
+/// let first_values = vec![Some(1), Some(0), Some(3)];
+/// let second_values = vec![Some(2), None, Some(5)];
+///
+/// let first_max = process_options(first_values, |iter| iter.max().unwrap_or(0));
+/// let second_max = process_options(second_values, |iter| iter.max().unwrap_or(0));
+///
+/// assert_eq!(first_max, Some(3));
+/// assert_eq!(second_max, None);
+/// ```
+pub fn process_options<I, F, T, R>(iterable: I, processor: F) -> Option<R>
+    where I: IntoIterator<Item = Option<T>>,
+          F: FnOnce(ProcessOptions<I::IntoIter>) -> R
+{
+    let iter = iterable.into_iter();
+    let mut found_none = false;
+
+    let result = processor(ProcessOptions { found_none: &mut found_none, iter });
+
+    if found_none { None } else { Some(result) }
+}
@@ -57,5 +57,12 @@ impl<I: Iterator> Iterator for PutBackN<I> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         size_hint::add_scalar(self.iter.size_hint(), self.top.len())
     }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+        where F: FnMut(B, Self::Item) -> B,
+    {
+        let acc = self.top.into_iter().rev().fold(init, &mut f);
+        self.iter.fold(acc, f)
+    }
 }
 
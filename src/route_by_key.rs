@@ -0,0 +1,78 @@
+use std::collections::hash_map::{Entry, HashMap};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::hash::Hash;
+
+/// The error returned by
+/// [`.route_by_key()`](crate::Itertools::route_by_key) when the iterator
+/// yields more distinct keys than allowed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TooManyKeysError<K> {
+    max_keys: usize,
+    key: K,
+}
+
+impl<K> TooManyKeysError<K> {
+    /// The number of distinct keys that was allowed.
+    pub fn max_keys(&self) -> usize {
+        self.max_keys
+    }
+
+    /// The first key past the limit.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Take the first key past the limit.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+}
+
+impl<K> Display for TooManyKeysError<K>
+    where K: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "key {:?} exceeds the limit of {} distinct keys", self.key, self.max_keys)
+    }
+}
+
+impl<K> Error for TooManyKeysError<K> where K: Debug {}
+
+/// Return a `HashMap` of keys mapped to the `Vec` of elements routed to
+/// them, allowing at most `max_keys` distinct keys if given.
+///
+/// See [`.route_by_key()`](crate::Itertools::route_by_key)
+/// for more information.
+pub fn route_by_key<I, K, F>(iter: I, max_keys: Option<usize>, mut key: F)
+    -> Result<HashMap<K, Vec<I::Item>>, TooManyKeysError<K>>
+    where I: Iterator,
+          K: Hash + Eq,
+          F: FnMut(&I::Item) -> K,
+{
+    let (mut remaining, _) = iter.size_hint();
+    let mut map: HashMap<K, Vec<I::Item>> = HashMap::with_capacity(max_keys.map_or(0, |max| max.min(remaining)));
+
+    for item in iter {
+        remaining = remaining.saturating_sub(1);
+        let len = map.len();
+        match map.entry(key(&item)) {
+            Entry::Occupied(mut entry) => entry.get_mut().push(item),
+            Entry::Vacant(entry) => {
+                let mut queue = match max_keys {
+                    Some(max) if len >= max => {
+                        return Err(TooManyKeysError { max_keys: max, key: entry.into_key() });
+                    }
+                    // expect the rest of the elements to be spread evenly
+                    // over the allowed keys
+                    Some(max) => Vec::with_capacity(remaining / max + 1),
+                    None => Vec::new(),
+                };
+                queue.push(item);
+                entry.insert(queue);
+            }
+        }
+    }
+
+    Ok(map)
+}
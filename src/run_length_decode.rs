@@ -0,0 +1,66 @@
+use std::iter::{Fuse, FusedIterator};
+
+use crate::repeatn::{repeat_n, RepeatN};
+
+/// An iterator adaptor that repeats every element of an iterator of
+/// `(count, element)` pairs `count` times.
+///
+/// See [`.run_length_decode()`](crate::Itertools::run_length_decode) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct RunLengthDecode<I, T> {
+    iter: Fuse<I>,
+    // the repetitions left of the current run
+    run: Option<RepeatN<T>>,
+}
+
+/// Create a new `RunLengthDecode` iterator.
+pub fn run_length_decode<I, T>(iter: I) -> RunLengthDecode<I, T>
+    where I: Iterator<Item = (usize, T)>,
+          T: Clone,
+{
+    RunLengthDecode {
+        iter: iter.fuse(),
+        run: None,
+    }
+}
+
+impl<I, T> Iterator for RunLengthDecode<I, T>
+    where I: Iterator<Item = (usize, T)>,
+          T: Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            if let Some(elt) = self.run.as_mut().and_then(Iterator::next) {
+                return Some(elt);
+            }
+            let (n, elt) = self.iter.next()?;
+            self.run = Some(repeat_n(elt, n));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the length of the runs not started yet is only known once they
+        // are, unless there are none
+        let run = self.run.as_ref().map_or(0, ExactSizeIterator::len);
+        match self.iter.size_hint() {
+            (_, Some(0)) => (run, Some(run)),
+            _ => (run, None),
+        }
+    }
+
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+        where G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let acc = self.run.into_iter().flatten().fold(init, &mut g);
+        self.iter.fold(acc, |acc, (n, elt)| repeat_n(elt, n).fold(acc, &mut g))
+    }
+}
+
+impl<I, T> FusedIterator for RunLengthDecode<I, T>
+    where I: Iterator<Item = (usize, T)>,
+          T: Clone,
+{}
@@ -0,0 +1,122 @@
+use std::iter::{Fuse, FusedIterator};
+
+use super::size_hint;
+
+/// What [`.select_by_mask()`](crate::Itertools::select_by_mask) and
+/// [`.partition_by_mask()`](crate::Itertools::partition_by_mask) do when the
+/// mask and the adapted iterator are not of the same length.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum MaskPolicy {
+    /// Stop at the end of the shorter one, like `zip`.
+    Truncate,
+    /// Keep the elements past the end of the mask, as if it were padded
+    /// with `true`. Extra mask values are ignored.
+    KeepRest,
+    /// Panic if the lengths differ, like `zip_eq`.
+    Strict,
+}
+
+/// An iterator adaptor that yields the elements whose corresponding mask
+/// value is `true`.
+///
+/// Iterator element type is `I::Item`.
+///
+/// This iterator is *fused*.
+///
+/// See [`.select_by_mask()`](crate::Itertools::select_by_mask) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct SelectByMask<I, M> {
+    iter: Fuse<I>,
+    mask: Fuse<M>,
+    policy: MaskPolicy,
+}
+
+/// Create a new `SelectByMask` iterator.
+pub fn select_by_mask<I, M>(iter: I, mask: M, policy: MaskPolicy) -> SelectByMask<I, M::IntoIter>
+    where I: Iterator,
+          M: IntoIterator<Item = bool>,
+{
+    SelectByMask {
+        iter: iter.fuse(),
+        mask: mask.into_iter().fuse(),
+        policy,
+    }
+}
+
+impl<I, M> SelectByMask<I, M>
+    where I: Iterator,
+          M: Iterator<Item = bool>,
+{
+    /// Return the next element with its mask value, applying the policy.
+    fn next_masked(&mut self) -> Option<(bool, I::Item)> {
+        match (self.mask.next(), self.policy) {
+            (Some(keep), policy) => match self.iter.next() {
+                Some(elt) => Some((keep, elt)),
+                None if policy == MaskPolicy::Strict =>
+                    panic!("itertools: mask is longer than the iterator"),
+                None => None,
+            },
+            (None, MaskPolicy::Truncate) => None,
+            (None, MaskPolicy::KeepRest) => self.iter.next().map(|elt| (true, elt)),
+            (None, MaskPolicy::Strict) => match self.iter.next() {
+                Some(_) => panic!("itertools: mask is shorter than the iterator"),
+                None => None,
+            },
+        }
+    }
+}
+
+impl<I, M> Iterator for SelectByMask<I, M>
+    where I: Iterator,
+          M: Iterator<Item = bool>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let (true, elt) = self.next_masked()? {
+                return Some(elt);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.policy {
+            MaskPolicy::KeepRest => {
+                let (low, hi) = self.iter.size_hint();
+                let low = self.mask.size_hint().1.map_or(0, |masked| low.saturating_sub(masked));
+                (low, hi)
+            }
+            MaskPolicy::Truncate | MaskPolicy::Strict => {
+                (0, size_hint::min(self.iter.size_hint(), self.mask.size_hint()).1)
+            }
+        }
+    }
+}
+
+impl<I, M> FusedIterator for SelectByMask<I, M>
+    where I: Iterator,
+          M: Iterator<Item = bool>,
+{}
+
+/// Split the elements of `iter` by their corresponding mask values.
+pub fn partition_by_mask<A, B, I, M>(iter: I, mask: M, policy: MaskPolicy) -> (A, B)
+    where I: Iterator,
+          M: IntoIterator<Item = bool>,
+          A: Default + Extend<I::Item>,
+          B: Default + Extend<I::Item>,
+{
+    let mut selected = A::default();
+    let mut rejected = B::default();
+    let mut iter = select_by_mask(iter, mask, policy);
+    while let Some((keep, elt)) = iter.next_masked() {
+        if keep {
+            selected.extend(Some(elt));
+        } else {
+            rejected.extend(Some(elt));
+        }
+    }
+    (selected, rejected)
+}
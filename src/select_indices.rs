@@ -0,0 +1,133 @@
+use std::iter::{Fuse, FusedIterator};
+
+use super::size_hint;
+
+/// An iterator adaptor that yields only the elements at the given positions
+/// of the adapted iterator.
+///
+/// Iterator element type is `I::Item`.
+///
+/// This iterator is *fused*.
+///
+/// See [`.select_indices()`](crate::Itertools::select_indices) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct SelectIndices<I, J> {
+    iter: Fuse<I>,
+    indices: J,
+    // the number of elements taken from `iter` so far
+    pos: usize,
+}
+
+/// Create a new `SelectIndices` iterator.
+pub fn select_indices<I, J>(iter: I, indices: J) -> SelectIndices<I, J::IntoIter>
+    where I: Iterator,
+          J: IntoIterator<Item = usize>,
+{
+    SelectIndices {
+        iter: iter.fuse(),
+        indices: indices.into_iter(),
+        pos: 0,
+    }
+}
+
+impl<I, J> Iterator for SelectIndices<I, J>
+    where I: Iterator,
+          J: Iterator<Item = usize>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let index = self.indices.next()?;
+            // skip repeated indices
+            if index >= self.pos {
+                let elt = self.iter.nth(index - self.pos)?;
+                self.pos = index + 1;
+                return Some(elt);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, hi) = size_hint::min(self.iter.size_hint(), self.indices.size_hint());
+        (0, hi)
+    }
+}
+
+impl<I, J> FusedIterator for SelectIndices<I, J>
+    where I: Iterator,
+          J: Iterator<Item = usize>,
+{}
+
+/// An iterator adaptor that skips the elements at the given positions of the
+/// adapted iterator.
+///
+/// Iterator element type is `I::Item`.
+///
+/// This iterator is *fused*.
+///
+/// See [`.remove_at()`](crate::Itertools::remove_at) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct RemoveAt<I, J> {
+    iter: Fuse<I>,
+    indices: J,
+    // the next index to skip, if any
+    next_index: Option<usize>,
+    // the number of elements taken from `iter` so far
+    pos: usize,
+}
+
+/// Create a new `RemoveAt` iterator.
+pub fn remove_at<I, J>(iter: I, indices: J) -> RemoveAt<I, J::IntoIter>
+    where I: Iterator,
+          J: IntoIterator<Item = usize>,
+{
+    let mut indices = indices.into_iter();
+    RemoveAt {
+        iter: iter.fuse(),
+        next_index: indices.next(),
+        indices,
+        pos: 0,
+    }
+}
+
+impl<I, J> Iterator for RemoveAt<I, J>
+    where I: Iterator,
+          J: Iterator<Item = usize>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let elt = self.iter.next()?;
+            let pos = self.pos;
+            self.pos += 1;
+            // skip repeated indices
+            while matches!(self.next_index, Some(index) if index < pos) {
+                self.next_index = self.indices.next();
+            }
+            if self.next_index != Some(pos) {
+                return Some(elt);
+            }
+            self.next_index = self.indices.next();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = self.iter.size_hint();
+        // every index removes at most one element
+        let removed = match self.next_index {
+            Some(_) => size_hint::add_scalar(self.indices.size_hint(), 1).1,
+            None => Some(0),
+        };
+        (removed.map_or(0, |removed| low.saturating_sub(removed)), hi)
+    }
+}
+
+impl<I, J> FusedIterator for RemoveAt<I, J>
+    where I: Iterator,
+          J: Iterator<Item = usize>,
+{}
@@ -29,6 +29,14 @@ pub fn add_scalar(sh: SizeHint, x: usize) -> SizeHint {
     (low, hi)
 }
 
+/// Whether a **SizeHint** reports an iterator that never ends, like
+/// `iter::repeat()` or `0..`.
+#[inline]
+#[allow(dead_code)]
+pub fn is_unbounded(sh: SizeHint) -> bool {
+    sh == (usize::MAX, None)
+}
+
 /// Sbb **x** correctly to a **SizeHint**.
 #[inline]
 #[allow(dead_code)]
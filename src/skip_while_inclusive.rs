@@ -0,0 +1,81 @@
+use std::iter::FusedIterator;
+use std::fmt;
+
+/// An iterator adaptor that skips elements while the given predicate is
+/// `true`, also skipping the element for which the predicate first returned
+/// `false`, and then yields the rest.
+///
+/// See [`.skip_while_inclusive()`](crate::Itertools::skip_while_inclusive)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct SkipWhileInclusive<I, F> {
+    iter: I,
+    predicate: F,
+    // whether the skipped prefix has been consumed
+    skipped: bool,
+}
+
+impl<I, F> fmt::Debug for SkipWhileInclusive<I, F>
+    where I: fmt::Debug,
+{
+    debug_fmt_fields!(SkipWhileInclusive, iter, skipped);
+}
+
+/// Create a new `SkipWhileInclusive` iterator.
+pub fn skip_while_inclusive<I, F>(iter: I, predicate: F) -> SkipWhileInclusive<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool,
+{
+    SkipWhileInclusive {
+        iter,
+        predicate,
+        skipped: false,
+    }
+}
+
+impl<I, F> SkipWhileInclusive<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool,
+{
+    fn skip_prefix(&mut self) {
+        if !self.skipped {
+            self.skipped = true;
+            let predicate = &mut self.predicate;
+            // `any` stops right after the first rejected element
+            self.iter.any(|item| !predicate(&item));
+        }
+    }
+}
+
+impl<I, F> Iterator for SkipWhileInclusive<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.skip_prefix();
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.skipped {
+            self.iter.size_hint()
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+
+    fn fold<B, G>(mut self, init: B, f: G) -> B
+        where G: FnMut(B, Self::Item) -> B,
+    {
+        self.skip_prefix();
+        self.iter.fold(init, f)
+    }
+}
+
+impl<I, F> FusedIterator for SkipWhileInclusive<I, F>
+    where I: FusedIterator,
+          F: FnMut(&I::Item) -> bool,
+{}
@@ -181,3 +181,207 @@ pub fn iterate<St, F>(initial_value: St, f: F) -> Iterate<St, F>
         f,
     }
 }
+
+/// An iterator that repeatedly computes an element and the next state from
+/// the current state.
+///
+/// This `struct` is created by the [`generate()`](crate::generate) function.
+/// See its documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Generate<St, F> {
+    f: F,
+    /// The state that the next element will be computed from
+    pub state: St,
+}
+
+impl<St, F> fmt::Debug for Generate<St, F>
+    where St: fmt::Debug,
+{
+    debug_fmt_fields!(Generate, state);
+}
+
+impl<A, St, F> Iterator for Generate<St, F>
+    where F: FnMut(&St) -> Option<(A, St)>
+{
+    type Item = A;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (elt, next_state) = (self.f)(&self.state)?;
+        self.state = next_state;
+        Some(elt)
+    }
+}
+
+/// Creates a new iterator that computes every element together with the next
+/// state from the current one, until `f` returns `None`.
+///
+/// Unlike with [`unfold`](crate::unfold), `f` only reads the state, and the
+/// state is a field of the iterator rather than something captured by the
+/// closure: it can be inspected at any point, and cloning the iterator
+/// checkpoints it.
+///
+/// ```
+/// use itertools::generate;
+///
+/// // a linear congruential generator, yielding the high bits of its state
+/// let mut rng = generate(7u32, |&s| {
+///     let next = s.wrapping_mul(1_103_515_245).wrapping_add(12345);
+///     Some((next >> 16, next))
+/// });
+/// rng.next();
+/// let checkpoint = rng.clone();
+/// itertools::assert_equal(rng.take(3), checkpoint.take(3));
+///
+/// let countdown = generate(3, |&n| if n > 0 { Some((n, n - 1)) } else { None });
+/// itertools::assert_equal(countdown, vec![3, 2, 1]);
+/// ```
+pub fn generate<A, St, F>(initial_state: St, f: F) -> Generate<St, F>
+    where F: FnMut(&St) -> Option<(A, St)>
+{
+    Generate {
+        f,
+        state: initial_state,
+    }
+}
+
+/// An iterator that repeatedly computes an element and the next state from
+/// the current state, where each step may fail.
+///
+/// This `struct` is created by the [`try_generate()`](crate::try_generate)
+/// function. See its documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryGenerate<St, F> {
+    f: F,
+    /// The state that the next element will be computed from
+    pub state: St,
+}
+
+impl<St, F> fmt::Debug for TryGenerate<St, F>
+    where St: fmt::Debug,
+{
+    debug_fmt_fields!(TryGenerate, state);
+}
+
+impl<A, E, St, F> Iterator for TryGenerate<St, F>
+    where F: FnMut(&St) -> Result<Option<(A, St)>, E>
+{
+    type Item = Result<A, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.f)(&self.state) {
+            Ok(Some((elt, next_state))) => {
+                self.state = next_state;
+                Some(Ok(elt))
+            }
+            Ok(None) => None,
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+/// Creates a new iterator like [`generate`](crate::generate), where each
+/// step may fail.
+///
+/// An error is yielded as `Err`, and leaves the state unchanged, so the
+/// next call retries the same step.
+///
+/// ```
+/// use itertools::try_generate;
+///
+/// let halvings = try_generate(20, |&n| match n {
+///     1 => Ok(None),
+///     n if n % 2 == 0 => Ok(Some((n, n / 2))),
+///     n => Err(n),
+/// });
+/// assert_eq!(halvings.collect::<Result<Vec<_>, _>>(), Err(5));
+/// ```
+pub fn try_generate<A, E, St, F>(initial_state: St, f: F) -> TryGenerate<St, F>
+    where F: FnMut(&St) -> Result<Option<(A, St)>, E>
+{
+    TryGenerate {
+        f,
+        state: initial_state,
+    }
+}
+
+/// An iterator over a recurrence of order `K`, where every value is computed
+/// from the `K` values before it.
+///
+/// This `struct` is created by the [`memoized_recurrence()`](crate::memoized_recurrence)
+/// function. See its documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MemoizedRecurrence<T, F, const K: usize> {
+    // the last `K` values, oldest first
+    window: [T; K],
+    // the number of initial values yielded so far
+    yielded: usize,
+    f: F,
+}
+
+impl<T, F, const K: usize> fmt::Debug for MemoizedRecurrence<T, F, K>
+    where T: fmt::Debug,
+{
+    debug_fmt_fields!(MemoizedRecurrence, window, yielded);
+}
+
+impl<T, F, const K: usize> Iterator for MemoizedRecurrence<T, F, K>
+    where T: Clone,
+          F: FnMut(&[T; K]) -> T
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.yielded < K {
+            self.yielded += 1;
+            return Some(self.window[self.yielded - 1].clone());
+        }
+        let value = (self.f)(&self.window);
+        if K != 0 {
+            self.window.rotate_left(1);
+            self.window[K - 1] = value.clone();
+        }
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// Creates a new iterator over a recurrence of order `K`: it yields the
+/// `initial` values, and then every next value computed by `f` from the `K`
+/// values before it, oldest first.
+///
+/// Only the last `K` values are kept, and every value is computed only when
+/// it is needed.
+///
+/// ```
+/// use itertools::memoized_recurrence;
+///
+/// let fibonacci = memoized_recurrence([0, 1], |&[a, b]| a + b);
+/// itertools::assert_equal(fibonacci.take(8), vec![0, 1, 1, 2, 3, 5, 8, 13]);
+///
+/// let tribonacci = memoized_recurrence([0, 0, 1], |w| w.iter().sum());
+/// itertools::assert_equal(tribonacci.take(8), vec![0, 0, 1, 1, 2, 4, 7, 13]);
+///
+/// // values beyond the last one taken are never computed, so this doesn't overflow
+/// let last = memoized_recurrence([0u8, 1], |&[a, b]| a + b).take(14).last();
+/// assert_eq!(last, Some(233));
+/// ```
+pub fn memoized_recurrence<T, F, const K: usize>(initial: [T; K], f: F) -> MemoizedRecurrence<T, F, K>
+    where T: Clone,
+          F: FnMut(&[T; K]) -> T
+{
+    MemoizedRecurrence {
+        window: initial,
+        yielded: 0,
+        f,
+    }
+}
@@ -0,0 +1,88 @@
+use alloc::vec::Vec;
+use std::fmt;
+use std::iter::FusedIterator;
+
+/// An iterator adaptor that splits the adapted iterator into segments at
+/// the elements that match a predicate.
+///
+/// Iterator element type is `Vec<I::Item>`.
+///
+/// See [`.split_when()`](crate::Itertools::split_when) and
+/// [`.split_when_inclusive()`](crate::Itertools::split_when_inclusive) for
+/// more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct SplitWhen<I, F> {
+    iter: I,
+    pred: F,
+    // whether separators end the segment before them, or are dropped
+    inclusive: bool,
+    finished: bool,
+}
+
+impl<I, F> fmt::Debug for SplitWhen<I, F>
+    where I: fmt::Debug,
+{
+    debug_fmt_fields!(SplitWhen, iter, inclusive, finished);
+}
+
+/// Create a new `SplitWhen` iterator.
+pub fn split_when<I, F>(iter: I, pred: F, inclusive: bool) -> SplitWhen<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool,
+{
+    SplitWhen {
+        iter,
+        pred,
+        inclusive,
+        finished: false,
+    }
+}
+
+impl<I, F> Iterator for SplitWhen<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let mut segment = Vec::new();
+        for elt in &mut self.iter {
+            if (self.pred)(&elt) {
+                if self.inclusive {
+                    segment.push(elt);
+                }
+                return Some(segment);
+            }
+            segment.push(elt);
+        }
+        self.finished = true;
+        // like `slice::split_inclusive`, a trailing separator doesn't start
+        // an empty segment
+        if self.inclusive && segment.is_empty() {
+            None
+        } else {
+            Some(segment)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
+        let (low, hi) = self.iter.size_hint();
+        if self.inclusive {
+            (low.min(1), hi)
+        } else {
+            (1, hi.and_then(|hi| hi.checked_add(1)))
+        }
+    }
+}
+
+impl<I, F> FusedIterator for SplitWhen<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool,
+{}
@@ -0,0 +1,87 @@
+use std::iter::FusedIterator;
+use std::fmt;
+
+/// An iterator adaptor that consumes elements while the given predicate is
+/// `true`, including the element for which the predicate first returned
+/// `false`.
+///
+/// See [`.take_while_inclusive()`](crate::Itertools::take_while_inclusive)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct TakeWhileInclusive<I, F> {
+    iter: I,
+    predicate: F,
+    done: bool,
+}
+
+impl<I, F> fmt::Debug for TakeWhileInclusive<I, F>
+    where I: fmt::Debug,
+{
+    debug_fmt_fields!(TakeWhileInclusive, iter, done);
+}
+
+/// Create a new `TakeWhileInclusive` iterator.
+pub fn take_while_inclusive<I, F>(iter: I, predicate: F) -> TakeWhileInclusive<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool,
+{
+    TakeWhileInclusive {
+        iter,
+        predicate,
+        done: false,
+    }
+}
+
+impl<I, F> Iterator for TakeWhileInclusive<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            None
+        } else {
+            let item = self.iter.next();
+            match item {
+                Some(ref item) if (self.predicate)(item) => {}
+                _ => self.done = true,
+            }
+            item
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+
+    fn fold<B, G>(mut self, init: B, mut f: G) -> B
+        where G: FnMut(B, Self::Item) -> B,
+    {
+        if self.done {
+            return init;
+        }
+        let predicate = &mut self.predicate;
+        // stop right after the first rejected element, which `try_fold` reports as `Err`
+        let res = self.iter.try_fold(init, |acc, item| {
+            if predicate(&item) {
+                Ok(f(acc, item))
+            } else {
+                Err(f(acc, item))
+            }
+        });
+        match res {
+            Ok(acc) | Err(acc) => acc,
+        }
+    }
+}
+
+impl<I, F> FusedIterator for TakeWhileInclusive<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool,
+{}
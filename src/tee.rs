@@ -1,8 +1,12 @@
 use super::size_hint;
 
 use std::cell::RefCell;
+#[cfg(feature = "use_std")]
+use std::error::Error;
+use std::fmt;
 use alloc::collections::VecDeque;
 use alloc::rc::Rc;
+use alloc::vec::Vec;
 
 /// Common buffer object for the two tee halves
 #[derive(Debug)]
@@ -76,3 +80,168 @@ impl<I> ExactSizeIterator for Tee<I>
     where I: ExactSizeIterator,
           I::Item: Clone
 {}
+
+/// One half of an iterator pair where both return the same elements, and
+/// neither may get more than a fixed number of elements ahead of the other.
+///
+/// See [`.tee_bounded()`](crate::Itertools::tee_bounded) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct TeeBounded<I>
+    where I: Iterator
+{
+    tee: Tee<I>,
+    max_buffer: usize,
+}
+
+impl<I> fmt::Debug for TeeBounded<I>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(TeeBounded, tee, max_buffer);
+}
+
+/// The error yielded by a [`TeeBounded`] half that is too far ahead of the
+/// other half.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TeeBufferFull;
+
+impl fmt::Display for TeeBufferFull {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "tee buffer is full, the other half needs to catch up")
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl Error for TeeBufferFull {}
+
+pub fn new_bounded<I>(iter: I, max_buffer: usize) -> (TeeBounded<I>, TeeBounded<I>)
+    where I: Iterator
+{
+    assert!(max_buffer != 0, "tee_bounded: max_buffer must be non-zero");
+    let (t1, t2) = new(iter);
+    (TeeBounded { tee: t1, max_buffer }, TeeBounded { tee: t2, max_buffer })
+}
+
+impl<I> Iterator for TeeBounded<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    type Item = Result<I::Item, TeeBufferFull>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if Rc::strong_count(&self.tee.rcbuffer) == 1 {
+            // the other half is gone, so nothing needs to be buffered for it
+            let mut buffer = self.tee.rcbuffer.borrow_mut();
+            if buffer.owner == self.tee.id {
+                if let Some(elt) = buffer.backlog.pop_front() {
+                    return Some(Ok(elt));
+                }
+            } else {
+                buffer.backlog.clear();
+            }
+            return buffer.iter.next().map(Ok);
+        }
+        {
+            let buffer = self.tee.rcbuffer.borrow();
+            // this half is ahead if the other one reads from the backlog
+            if buffer.owner != self.tee.id && buffer.backlog.len() >= self.max_buffer {
+                return Some(Err(TeeBufferFull));
+            }
+        }
+        self.tee.next().map(Ok)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // any number of errors may be yielded in between the elements
+        (self.tee.size_hint().0, None)
+    }
+}
+
+/// Common buffer object for the handles of an n-way tee
+#[derive(Debug)]
+struct TeeNBuffer<A, I> {
+    /// The elements that some handle has not read yet
+    backlog: VecDeque<A>,
+    /// The index of the front of the backlog in the original iterator
+    offset: usize,
+    /// The index of the next element of each handle, `usize::MAX` once dropped
+    positions: Vec<usize>,
+    iter: I,
+}
+
+impl<A, I> TeeNBuffer<A, I> {
+    /// Drop the elements that every handle has read.
+    fn trim(&mut self) {
+        let min = self.positions.iter().copied().min().unwrap_or(usize::MAX);
+        while self.offset < min && self.backlog.pop_front().is_some() {
+            self.offset += 1;
+        }
+    }
+}
+
+/// One of several iterators that all return the same elements.
+///
+/// See [`.tee_n()`](crate::Itertools::tee_n) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug)]
+pub struct TeeN<I>
+    where I: Iterator
+{
+    rcbuffer: Rc<RefCell<TeeNBuffer<I::Item, I>>>,
+    id: usize,
+}
+
+pub fn new_n<I>(iter: I, n: usize) -> Vec<TeeN<I>>
+    where I: Iterator
+{
+    let buffer = TeeNBuffer { backlog: VecDeque::new(), offset: 0, positions: (0..n).map(|_| 0).collect(), iter };
+    let rcbuffer = Rc::new(RefCell::new(buffer));
+    (0..n).map(|id| TeeN { rcbuffer: rcbuffer.clone(), id }).collect()
+}
+
+impl<I> Iterator for TeeN<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buffer = self.rcbuffer.borrow_mut();
+        let pos = buffer.positions[self.id];
+        let elt = match buffer.backlog.get(pos - buffer.offset) {
+            Some(elt) => elt.clone(),
+            None => {
+                let elt = buffer.iter.next()?;
+                buffer.backlog.push_back(elt.clone());
+                elt
+            }
+        };
+        buffer.positions[self.id] += 1;
+        // only the slowest handles can free up the front of the backlog
+        if pos == buffer.offset {
+            buffer.trim();
+        }
+        Some(elt)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffer = self.rcbuffer.borrow();
+        let unread = buffer.offset + buffer.backlog.len() - buffer.positions[self.id];
+        size_hint::add_scalar(buffer.iter.size_hint(), unread)
+    }
+}
+
+impl<I> ExactSizeIterator for TeeN<I>
+    where I: ExactSizeIterator,
+          I::Item: Clone
+{}
+
+impl<I> Drop for TeeN<I>
+    where I: Iterator
+{
+    fn drop(&mut self) {
+        // `.borrow_mut` can only fail if the iterator refers back to its tee
+        if let Ok(mut buffer) = self.rcbuffer.try_borrow_mut() {
+            buffer.positions[self.id] = usize::MAX;
+            buffer.trim();
+        }
+    }
+}
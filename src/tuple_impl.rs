@@ -1,11 +1,14 @@
 //! Some iterator that produces tuples
 
+use std::fmt;
 use std::iter::Fuse;
 use std::iter::FusedIterator;
 use std::iter::Take;
 use std::iter::Cycle;
 use std::marker::PhantomData;
 
+use crate::repeat_n;
+
 // `HomogeneousTuple` is a public facade for `TupleCollect`, allowing
 // tuple-related methods to be used by clients in generic contexts, while
 // hiding the implementation details of `TupleCollect`.
@@ -186,14 +189,113 @@ impl<I, T> Iterator for TupleWindows<I, T>
         }
         None
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the first window was filled up front: each remaining element
+        // produces exactly one window, unless there was none to fill it
+        if T::num_items() == 1 || self.last.is_some() {
+            self.iter.size_hint()
+        } else {
+            (0, Some(0))
+        }
+    }
 }
 
+impl<I, T> ExactSizeIterator for TupleWindows<I, T>
+    where I: ExactSizeIterator<Item = T::Item>,
+          T: HomogeneousTuple + Clone,
+          T::Item: Clone
+{}
+
 impl<I, T> FusedIterator for TupleWindows<I, T>
     where I: FusedIterator<Item = T::Item>,
           T: HomogeneousTuple + Clone,
           T::Item: Clone
 {}
 
+/// An iterator over all contiguous windows that produces tuples of a specific
+/// size, restarting after each boundary element.
+///
+/// See [`.windows_split_by()`](crate::Itertools::windows_split_by) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct WindowsSplitBy<I, T, F>
+    where I: Iterator<Item = T::Item>,
+          T: HomogeneousTuple
+{
+    iter: I,
+    last: Option<T>,
+    // number of elements in `last` since the latest boundary, at most the tuple size
+    filled: usize,
+    pred: F,
+}
+
+impl<I, T, F> fmt::Debug for WindowsSplitBy<I, T, F>
+    where I: Iterator<Item = T::Item> + fmt::Debug,
+          T: HomogeneousTuple + fmt::Debug
+{
+    debug_fmt_fields!(WindowsSplitBy, iter, last, filled);
+}
+
+/// Create a new `WindowsSplitBy` iterator.
+pub fn windows_split_by<I, T, F>(iter: I, pred: F) -> WindowsSplitBy<I, T, F>
+    where I: Iterator<Item = T::Item>,
+          T: HomogeneousTuple,
+          T::Item: Clone,
+          F: FnMut(&T::Item) -> bool
+{
+    WindowsSplitBy {
+        iter,
+        last: None,
+        filled: 0,
+        pred,
+    }
+}
+
+impl<I, T, F> Iterator for WindowsSplitBy<I, T, F>
+    where I: Iterator<Item = T::Item>,
+          T: HomogeneousTuple + Clone,
+          T::Item: Clone,
+          F: FnMut(&T::Item) -> bool
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.iter.next()?;
+            if (self.pred)(&item) {
+                self.filled = 0;
+                continue;
+            }
+            match self.last {
+                Some(ref mut last) if self.filled > 0 => last.left_shift_push(item),
+                // fill the whole tuple with the first element of the segment;
+                // it is shifted out before the first window is produced.
+                _ => self.last = T::collect_from_iter_no_buf(repeat_n(item, T::num_items())),
+            }
+            if self.filled < T::num_items() {
+                self.filled += 1;
+            }
+            if self.filled == T::num_items() {
+                return self.last.clone();
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // any remaining element may be a boundary
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, T, F> FusedIterator for WindowsSplitBy<I, T, F>
+    where I: FusedIterator<Item = T::Item>,
+          T: HomogeneousTuple + Clone,
+          T::Item: Clone,
+          F: FnMut(&T::Item) -> bool
+{}
+
 /// An iterator over all windows,wrapping back to the first elements when the
 /// window would otherwise exceed the length of the iterator, producing tuples
 /// of a specific size.
@@ -234,8 +336,25 @@ impl<I, T> Iterator for CircularTupleWindows<I, T>
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
+impl<I, T> ExactSizeIterator for CircularTupleWindows<I, T>
+    where I: Iterator<Item = T::Item> + Clone,
+          T: TupleCollect + Clone,
+          T::Item: Clone
+{}
+
+// `Take` stops for good after `len` windows, and the cycle only ends if it is empty
+impl<I, T> FusedIterator for CircularTupleWindows<I, T>
+    where I: Iterator<Item = T::Item> + Clone,
+          T: TupleCollect + Clone,
+          T::Item: Clone
+{}
+
 pub trait TupleCollect: Sized {
     type Item;
     type Buffer: Default + AsRef<[Option<Self::Item>]> + AsMut<[Option<Self::Item>]>;
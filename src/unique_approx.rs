@@ -0,0 +1,121 @@
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::iter::FusedIterator;
+
+/// A Bloom filter over hashes of the elements seen so far.
+#[derive(Clone)]
+struct BloomFilter<S> {
+    bits: Vec<u64>,
+    num_bits: u64,
+    num_hashes: u32,
+    hash_builder: S,
+}
+
+impl<S: BuildHasher> BloomFilter<S> {
+    fn new(expected_items: usize, false_positive_rate: f64, hash_builder: S) -> Self {
+        assert!(false_positive_rate > 0.0 && false_positive_rate < 1.0,
+                "false positive rate must be in (0, 1)");
+        let n = expected_items.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let words = (-n * false_positive_rate.ln() / (ln2 * ln2) / 64.0).ceil().max(1.0) as usize;
+        let num_bits = words as u64 * 64;
+        let num_hashes = ((num_bits as f64 / n) * ln2).round().max(1.0) as u32;
+        BloomFilter {
+            bits: vec![0; words],
+            num_bits,
+            num_hashes,
+            hash_builder,
+        }
+    }
+
+    /// Insert `value`, returning `true` if it was (probably) not present.
+    fn insert<T: Hash + ?Sized>(&mut self, value: &T) -> bool {
+        let hash = hash_with(value, self.hash_builder.build_hasher());
+        // derive the probe positions by double hashing
+        let (h1, h2) = (hash, hash.rotate_left(32) | 1);
+        let mut inserted = false;
+        for i in 0..u64::from(self.num_hashes) {
+            let bit = h1.wrapping_add(i.wrapping_mul(h2)) % self.num_bits;
+            let (word, mask) = ((bit / 64) as usize, 1 << (bit % 64));
+            inserted |= self.bits[word] & mask == 0;
+            self.bits[word] |= mask;
+        }
+        inserted
+    }
+}
+
+fn hash_with<T: Hash + ?Sized, H: Hasher>(value: &T, mut hasher: H) -> u64 {
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// An iterator adapter to filter out duplicate elements using a fixed amount
+/// of memory, at the cost of sometimes filtering out distinct elements too.
+///
+/// See [`.unique_approx()`](crate::Itertools::unique_approx) for more
+/// information.
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct UniqueApprox<I, S = RandomState> {
+    iter: I,
+    filter: BloomFilter<S>,
+}
+
+impl<I, S> fmt::Debug for UniqueApprox<I, S>
+    where I: fmt::Debug,
+{
+    debug_fmt_fields!(UniqueApprox, iter);
+}
+
+/// Create a new `UniqueApprox` iterator.
+pub fn unique_approx<I>(iter: I, expected_items: usize, false_positive_rate: f64) -> UniqueApprox<I>
+    where I: Iterator,
+          I::Item: Hash,
+{
+    unique_approx_with_hasher(iter, expected_items, false_positive_rate, RandomState::new())
+}
+
+/// Create a new `UniqueApprox` iterator using `hash_builder` to hash the
+/// elements.
+pub fn unique_approx_with_hasher<I, S>(iter: I, expected_items: usize, false_positive_rate: f64, hash_builder: S)
+    -> UniqueApprox<I, S>
+    where I: Iterator,
+          I::Item: Hash,
+          S: BuildHasher,
+{
+    UniqueApprox {
+        iter,
+        filter: BloomFilter::new(expected_items, false_positive_rate, hash_builder),
+    }
+}
+
+impl<I, S> UniqueApprox<I, S> {
+    /// Return the size of the filter, in bytes.
+    pub fn filter_size(&self) -> usize {
+        self.filter.bits.len() * 8
+    }
+}
+
+impl<I, S> Iterator for UniqueApprox<I, S>
+    where I: Iterator,
+          I::Item: Hash,
+          S: BuildHasher,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let filter = &mut self.filter;
+        self.iter.find(|elt| filter.insert(elt))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, S> FusedIterator for UniqueApprox<I, S>
+    where I: FusedIterator,
+          I::Item: Hash,
+          S: BuildHasher,
+{}
@@ -1,7 +1,7 @@
 
-use std::collections::HashMap;
-use std::collections::hash_map::{Entry};
-use std::hash::Hash;
+use std::collections::{HashMap, HashSet};
+use std::collections::hash_map::{Entry, RandomState};
+use std::hash::{BuildHasher, Hash};
 use std::fmt;
 use std::iter::FusedIterator;
 
@@ -10,14 +10,14 @@ use std::iter::FusedIterator;
 /// See [`.unique_by()`](crate::Itertools::unique) for more information.
 #[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
-pub struct UniqueBy<I: Iterator, V, F> {
+pub struct UniqueBy<I: Iterator, V, F, S = RandomState> {
     iter: I,
     // Use a hashmap for the entry API
-    used: HashMap<V, ()>,
+    used: HashMap<V, (), S>,
     f: F,
 }
 
-impl<I, V, F> fmt::Debug for UniqueBy<I, V, F>
+impl<I, V, F, S> fmt::Debug for UniqueBy<I, V, F, S>
     where I: Iterator + fmt::Debug,
           V: fmt::Debug + Hash + Eq,
 {
@@ -29,18 +29,78 @@ pub fn unique_by<I, V, F>(iter: I, f: F) -> UniqueBy<I, V, F>
     where V: Eq + Hash,
           F: FnMut(&I::Item) -> V,
           I: Iterator,
+{
+    unique_by_with_hasher(iter, f, RandomState::new())
+}
+
+/// Create a new `UniqueBy` iterator using `hash_builder` to hash the keys.
+pub fn unique_by_with_hasher<I, V, F, S>(iter: I, f: F, hash_builder: S) -> UniqueBy<I, V, F, S>
+    where V: Eq + Hash,
+          F: FnMut(&I::Item) -> V,
+          I: Iterator,
+          S: BuildHasher,
 {
     UniqueBy {
         iter,
-        used: HashMap::new(),
+        used: HashMap::with_hasher(hash_builder),
         f,
     }
 }
 
+impl<I, V, F, S> UniqueBy<I, V, F, S>
+    where I: Iterator,
+          V: Eq + Hash,
+          S: BuildHasher,
+{
+    /// Return the number of distinct keys seen so far.
+    pub fn seen_len(&self) -> usize {
+        self.used.len()
+    }
+
+    /// Return the number of keys the seen set can hold without
+    /// reallocating.
+    pub fn seen_capacity(&self) -> usize {
+        self.used.capacity()
+    }
+
+    /// Shrink the capacity of the seen set as much as possible.
+    pub fn shrink_seen_to_fit(&mut self) {
+        self.used.shrink_to_fit();
+    }
+
+    /// Treat the keys in `seen` as already seen, so that elements with
+    /// those keys are filtered out too.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut batch = vec!["a", "bb", "c"].into_iter().unique_by(|s| s.len());
+    /// itertools::assert_equal(batch.by_ref(), vec!["a", "bb"]);
+    /// let (_, seen) = batch.into_inner();
+    ///
+    /// let next = vec!["ddd", "ee"].into_iter().unique_by(|s| s.len()).with_seen(seen);
+    /// itertools::assert_equal(next, vec!["ddd"]);
+    /// ```
+    pub fn with_seen(mut self, seen: HashSet<V, S>) -> Self {
+        self.used.extend(seen.into_iter().map(|key| (key, ())));
+        self
+    }
+
+    /// Return the adapted iterator and the set of keys seen so far.
+    pub fn into_inner(self) -> (I, HashSet<V, S>)
+        where S: Clone,
+    {
+        let mut seen = HashSet::with_capacity_and_hasher(self.used.len(), self.used.hasher().clone());
+        seen.extend(self.used.into_keys());
+        (self.iter, seen)
+    }
+}
+
 // count the number of new unique keys in iterable (`used` is the set already seen)
-fn count_new_keys<I, K>(mut used: HashMap<K, ()>, iterable: I) -> usize
+fn count_new_keys<I, K, S>(mut used: HashMap<K, (), S>, iterable: I) -> usize
     where I: IntoIterator<Item=K>,
           K: Hash + Eq,
+          S: BuildHasher,
 {
     let iter = iterable.into_iter();
     let current_used = used.len();
@@ -48,10 +108,11 @@ fn count_new_keys<I, K>(mut used: HashMap<K, ()>, iterable: I) -> usize
     used.len() - current_used
 }
 
-impl<I, V, F> Iterator for UniqueBy<I, V, F>
+impl<I, V, F, S> Iterator for UniqueBy<I, V, F, S>
     where I: Iterator,
           V: Eq + Hash,
-          F: FnMut(&I::Item) -> V
+          F: FnMut(&I::Item) -> V,
+          S: BuildHasher,
 {
     type Item = I::Item;
 
@@ -77,10 +138,11 @@ impl<I, V, F> Iterator for UniqueBy<I, V, F>
     }
 }
 
-impl<I, V, F> DoubleEndedIterator for UniqueBy<I, V, F>
+impl<I, V, F, S> DoubleEndedIterator for UniqueBy<I, V, F, S>
     where I: DoubleEndedIterator,
           V: Eq + Hash,
-          F: FnMut(&I::Item) -> V
+          F: FnMut(&I::Item) -> V,
+          S: BuildHasher,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         while let Some(v) = self.iter.next_back() {
@@ -93,15 +155,17 @@ impl<I, V, F> DoubleEndedIterator for UniqueBy<I, V, F>
     }
 }
 
-impl<I, V, F> FusedIterator for UniqueBy<I, V, F>
+impl<I, V, F, S> FusedIterator for UniqueBy<I, V, F, S>
     where I: FusedIterator,
           V: Eq + Hash,
-          F: FnMut(&I::Item) -> V
+          F: FnMut(&I::Item) -> V,
+          S: BuildHasher,
 {}
 
-impl<I> Iterator for Unique<I>
+impl<I, S> Iterator for Unique<I, S>
     where I: Iterator,
-          I::Item: Eq + Hash + Clone
+          I::Item: Eq + Hash + Clone,
+          S: BuildHasher,
 {
     type Item = I::Item;
 
@@ -127,9 +191,10 @@ impl<I> Iterator for Unique<I>
     }
 }
 
-impl<I> DoubleEndedIterator for Unique<I>
+impl<I, S> DoubleEndedIterator for Unique<I, S>
     where I: DoubleEndedIterator,
-          I::Item: Eq + Hash + Clone
+          I::Item: Eq + Hash + Clone,
+          S: BuildHasher,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         while let Some(v) = self.iter.iter.next_back() {
@@ -143,9 +208,10 @@ impl<I> DoubleEndedIterator for Unique<I>
     }
 }
 
-impl<I> FusedIterator for Unique<I>
+impl<I, S> FusedIterator for Unique<I, S>
     where I: FusedIterator,
-          I::Item: Eq + Hash + Clone
+          I::Item: Eq + Hash + Clone,
+          S: BuildHasher,
 {}
 
 /// An iterator adapter to filter out duplicate elements.
@@ -153,25 +219,81 @@ impl<I> FusedIterator for Unique<I>
 /// See [`.unique()`](crate::Itertools::unique) for more information.
 #[derive(Clone)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
-pub struct Unique<I: Iterator> {
-    iter: UniqueBy<I, I::Item, ()>,
+pub struct Unique<I: Iterator, S = RandomState> {
+    iter: UniqueBy<I, I::Item, (), S>,
 }
 
-impl<I> fmt::Debug for Unique<I>
+impl<I, S> fmt::Debug for Unique<I, S>
     where I: Iterator + fmt::Debug,
           I::Item: Hash + Eq + fmt::Debug,
 {
     debug_fmt_fields!(Unique, iter);
 }
 
+impl<I, S> Unique<I, S>
+    where I: Iterator,
+          I::Item: Eq + Hash,
+          S: BuildHasher,
+{
+    /// Return the number of distinct elements seen so far.
+    pub fn seen_len(&self) -> usize {
+        self.iter.seen_len()
+    }
+
+    /// Return the number of elements the seen set can hold without
+    /// reallocating.
+    pub fn seen_capacity(&self) -> usize {
+        self.iter.seen_capacity()
+    }
+
+    /// Shrink the capacity of the seen set as much as possible.
+    pub fn shrink_seen_to_fit(&mut self) {
+        self.iter.shrink_seen_to_fit();
+    }
+
+    /// Treat the elements of `seen` as already seen, so that they are
+    /// filtered out too.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut batch = vec![1, 2, 1].into_iter().unique();
+    /// itertools::assert_equal(batch.by_ref(), vec![1, 2]);
+    /// assert_eq!(batch.seen_len(), 2);
+    /// let (_, seen) = batch.into_inner();
+    ///
+    /// let next = vec![2, 3, 1, 4].into_iter().unique().with_seen(seen);
+    /// itertools::assert_equal(next, vec![3, 4]);
+    /// ```
+    pub fn with_seen(self, seen: HashSet<I::Item, S>) -> Self {
+        Unique { iter: self.iter.with_seen(seen) }
+    }
+
+    /// Return the adapted iterator and the set of elements seen so far.
+    pub fn into_inner(self) -> (I, HashSet<I::Item, S>)
+        where S: Clone,
+    {
+        self.iter.into_inner()
+    }
+}
+
 pub fn unique<I>(iter: I) -> Unique<I>
     where I: Iterator,
           I::Item: Eq + Hash,
+{
+    unique_with_hasher(iter, RandomState::new())
+}
+
+/// Create a new `Unique` iterator using `hash_builder` to hash the elements.
+pub fn unique_with_hasher<I, S>(iter: I, hash_builder: S) -> Unique<I, S>
+    where I: Iterator,
+          I::Item: Eq + Hash,
+          S: BuildHasher,
 {
     Unique {
         iter: UniqueBy {
             iter,
-            used: HashMap::new(),
+            used: HashMap::with_hasher(hash_builder),
             f: (),
         }
     }
@@ -0,0 +1,82 @@
+use std::collections::hash_map::{Entry, HashMap};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::hash::Hash;
+
+/// What [`.into_unique_map()`](crate::Itertools::into_unique_map) does when
+/// a key occurs more than once.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DuplicatePolicy {
+    /// Keep the value of the first occurrence of the key.
+    KeepFirst,
+    /// Keep the value of the last occurrence of the key, like collecting
+    /// into a `HashMap`.
+    KeepLast,
+    /// Stop at the second occurrence of the key and return a
+    /// [`DuplicateKeyError`].
+    Error,
+}
+
+/// The error returned by
+/// [`.into_unique_map()`](crate::Itertools::into_unique_map) when a key
+/// occurs more than once under [`DuplicatePolicy::Error`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DuplicateKeyError<K> {
+    key: K,
+}
+
+impl<K> DuplicateKeyError<K> {
+    /// The key that occurred more than once, as read at its second
+    /// occurrence.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Take the key that occurred more than once.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+}
+
+impl<K> Display for DuplicateKeyError<K>
+    where K: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "duplicate key {:?}", self.key)
+    }
+}
+
+impl<K> Error for DuplicateKeyError<K> where K: Debug {}
+
+/// Return a `HashMap` of keys mapped to a single value, resolving duplicate
+/// keys according to `policy`.
+///
+/// See [`.into_unique_map()`](crate::Itertools::into_unique_map)
+/// for more information.
+pub fn into_unique_map<I, K, V>(iter: I, policy: DuplicatePolicy)
+    -> Result<HashMap<K, V>, DuplicateKeyError<K>>
+    where I: Iterator<Item=(K, V)>,
+          K: Hash + Eq,
+{
+    let mut map = HashMap::with_capacity(iter.size_hint().0);
+
+    for (key, val) in iter {
+        // the entry API keeps the key already in the map, so look it up
+        // first to report the repeated key that was just read
+        if policy == DuplicatePolicy::Error && map.contains_key(&key) {
+            return Err(DuplicateKeyError { key });
+        }
+        match map.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(val);
+            }
+            Entry::Occupied(mut entry) => {
+                if policy == DuplicatePolicy::KeepLast {
+                    entry.insert(val);
+                }
+            }
+        }
+    }
+
+    Ok(map)
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "use_alloc")]
+use alloc::vec::Vec;
+
 /// Converts an iterator of tuples into a tuple of containers.
 ///
 /// `unzip()` consumes an entire iterator of n-ary tuples, producing `n` collections, one for each
@@ -78,3 +81,24 @@ impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F =
 impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F => FromF, G => FromG, H => FromH, I => FromI, J => FromJ);
 impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F => FromF, G => FromG, H => FromH, I => FromI, J => FromJ, K => FromK);
 impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F => FromF, G => FromG, H => FromH, I => FromI, J => FromJ, K => FromK, L => FromL);
+impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F => FromF, G => FromG, H => FromH, I => FromI, J => FromJ, K => FromK, L => FromL, M => FromM);
+impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F => FromF, G => FromG, H => FromH, I => FromI, J => FromJ, K => FromK, L => FromL, M => FromM, N => FromN);
+impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F => FromF, G => FromG, H => FromH, I => FromI, J => FromJ, K => FromK, L => FromL, M => FromM, N => FromN, O => FromO);
+impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F => FromF, G => FromG, H => FromH, I => FromI, J => FromJ, K => FromK, L => FromL, M => FromM, N => FromN, O => FromO, P => FromP);
+
+/// Unzip an iterator of arrays into an array of `Vec`s, one for each column.
+///
+/// See [`.unzip_array()`](crate::Itertools::unzip_array) for more information.
+#[cfg(feature = "use_alloc")]
+pub fn unzip_array<I, T, const N: usize>(iter: I) -> [Vec<T>; N]
+    where I: Iterator<Item = [T; N]>,
+{
+    let (lower, _) = iter.size_hint();
+    let mut res = [(); N].map(|_| Vec::with_capacity(lower));
+    iter.for_each(|row| {
+        for (column, elt) in res.iter_mut().zip(row) {
+            column.push(elt);
+        }
+    });
+    res
+}
@@ -0,0 +1,98 @@
+use alloc::collections::VecDeque;
+use std::fmt;
+use std::iter::{Fuse, FusedIterator};
+
+use crate::size_hint;
+
+/// An iterator adaptor that yields the position of the maximum of each
+/// sliding window of the adapted iterator.
+///
+/// See [`.window_position_max()`](crate::Itertools::window_position_max) for
+/// more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct WindowPositionMax<I>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    size: usize,
+    // number of elements taken from `iter`
+    seen: usize,
+    // candidates for the maximum of the current window and later ones,
+    // with strictly decreasing elements from front to back
+    candidates: VecDeque<(usize, I::Item)>,
+}
+
+impl<I> fmt::Debug for WindowPositionMax<I>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(WindowPositionMax, iter, size, seen, candidates);
+}
+
+impl<I> Clone for WindowPositionMax<I>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+{
+    clone_fields!(iter, size, seen, candidates);
+}
+
+/// Create a new `WindowPositionMax` iterator.
+pub fn window_position_max<I>(iter: I, size: usize) -> WindowPositionMax<I>
+    where I: Iterator,
+          I::Item: Ord,
+{
+    assert!(size != 0, "window_position_max: window size must be non-zero");
+    WindowPositionMax {
+        iter: iter.fuse(),
+        size,
+        seen: 0,
+        candidates: VecDeque::new(),
+    }
+}
+
+impl<I> Iterator for WindowPositionMax<I>
+    where I: Iterator,
+          I::Item: Ord,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let elt = self.iter.next()?;
+            let index = self.seen;
+            self.seen += 1;
+            // later elements win ties, like `max`
+            while let Some((_, last)) = self.candidates.back() {
+                if *last <= elt {
+                    self.candidates.pop_back();
+                } else {
+                    break;
+                }
+            }
+            self.candidates.push_back((index, elt));
+            if let Some(&(front, _)) = self.candidates.front() {
+                if front + self.size <= index {
+                    self.candidates.pop_front();
+                }
+            }
+            if self.seen >= self.size {
+                return self.candidates.front().map(|&(i, _)| i);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let unfilled = (self.size - 1).saturating_sub(self.seen);
+        size_hint::sub_scalar(self.iter.size_hint(), unfilled)
+    }
+}
+
+impl<I> ExactSizeIterator for WindowPositionMax<I>
+    where I: ExactSizeIterator,
+          I::Item: Ord,
+{}
+
+impl<I> FusedIterator for WindowPositionMax<I>
+    where I: Iterator,
+          I::Item: Ord,
+{}
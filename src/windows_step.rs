@@ -0,0 +1,104 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use std::fmt;
+use std::iter::{Fuse, FusedIterator};
+
+/// An iterator adaptor that yields windows of a fixed size, starting a new
+/// window every `step` elements.
+///
+/// Iterator element type is `Vec<I::Item>`.
+///
+/// See [`.windows_step()`](crate::Itertools::windows_step) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct WindowsStep<I>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    size: usize,
+    step: usize,
+    // whether the first window has been requested yet
+    started: bool,
+    // the elements of the previous window
+    buf: VecDeque<I::Item>,
+}
+
+impl<I> fmt::Debug for WindowsStep<I>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(WindowsStep, iter, size, step, started, buf);
+}
+
+impl<I> Clone for WindowsStep<I>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+{
+    clone_fields!(iter, size, step, started, buf);
+}
+
+/// Create a new `WindowsStep` iterator.
+pub fn windows_step<I>(iter: I, size: usize, step: usize) -> WindowsStep<I>
+    where I: Iterator,
+          I::Item: Clone,
+{
+    assert!(size != 0, "windows_step: window size must be non-zero");
+    assert!(step != 0, "windows_step: step must be non-zero");
+    WindowsStep {
+        iter: iter.fuse(),
+        size,
+        step,
+        started: false,
+        buf: VecDeque::with_capacity(size),
+    }
+}
+
+impl<I> Iterator for WindowsStep<I>
+    where I: Iterator,
+          I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.started {
+            // drop the start of the previous window, and skip the gap
+            // between windows if the step is larger than the window
+            let dropped = self.buf.len().min(self.step);
+            self.buf.drain(..dropped);
+            let gap = self.step - dropped;
+            if gap > 0 {
+                self.iter.nth(gap - 1)?;
+            }
+        } else {
+            self.started = true;
+        }
+        while self.buf.len() < self.size {
+            self.buf.push_back(self.iter.next()?);
+        }
+        Some(self.buf.iter().cloned().collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (size, step, started) = (self.size, self.step, self.started);
+        // every window after the first needs `step` new elements
+        let windows = move |n: usize| if started {
+            n / step
+        } else if n >= size {
+            (n - size) / step + 1
+        } else {
+            0
+        };
+        let (low, hi) = self.iter.size_hint();
+        (windows(low), hi.map(windows))
+    }
+}
+
+impl<I> ExactSizeIterator for WindowsStep<I>
+    where I: ExactSizeIterator,
+          I::Item: Clone,
+{}
+
+impl<I> FusedIterator for WindowsStep<I>
+    where I: Iterator,
+          I::Item: Clone,
+{}
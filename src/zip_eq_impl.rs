@@ -1,3 +1,8 @@
+#[cfg(feature = "use_std")]
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::iter::FusedIterator;
+
 use super::size_hint;
 
 /// An iterator which iterates two other iterators simultaneously
@@ -58,3 +63,106 @@ impl<I, J> ExactSizeIterator for ZipEq<I, J>
     where I: ExactSizeIterator,
           J: ExactSizeIterator
 {}
+
+/// An iterator which iterates two other iterators simultaneously, yielding
+/// an error instead of panicking if their lengths differ.
+///
+/// See [`.try_zip_eq()`](crate::Itertools::try_zip_eq) for more information.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct TryZipEq<I, J> {
+    a: I,
+    b: J,
+    index: usize,
+    done: bool,
+}
+
+/// The error yielded by [`TryZipEq`] when one iterator ends before the other.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ZipLengthMismatch {
+    index: usize,
+    first_longer: bool,
+}
+
+impl ZipLengthMismatch {
+    /// The number of pairs yielded before the mismatch, i.e. the length of
+    /// the shorter iterator.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Whether the first iterator is the longer one.
+    pub fn first_longer(&self) -> bool {
+        self.first_longer
+    }
+}
+
+impl Display for ZipLengthMismatch {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let (shorter, longer) = if self.first_longer { ("second", "first") } else { ("first", "second") };
+        write!(f, "{} iterator ended after {} elements, before the {}", shorter, self.index, longer)
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl Error for ZipLengthMismatch {}
+
+/// Create a new `TryZipEq` iterator.
+pub fn try_zip_eq<I, J>(i: I, j: J) -> TryZipEq<I::IntoIter, J::IntoIter>
+    where I: IntoIterator,
+          J: IntoIterator
+{
+    TryZipEq {
+        a: i.into_iter(),
+        b: j.into_iter(),
+        index: 0,
+        done: false,
+    }
+}
+
+impl<I, J> Iterator for TryZipEq<I, J>
+    where I: Iterator,
+          J: Iterator
+{
+    type Item = Result<(I::Item, J::Item), ZipLengthMismatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match (self.a.next(), self.b.next()) {
+            (Some(a), Some(b)) => {
+                self.index += 1;
+                Some(Ok((a, b)))
+            }
+            (None, None) => {
+                self.done = true;
+                None
+            }
+            (a, _) => {
+                self.done = true;
+                Some(Err(ZipLengthMismatch { index: self.index, first_longer: a.is_some() }))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        // there may be one more element for the error
+        let (a_low, a_hi) = self.a.size_hint();
+        let (b_low, b_hi) = self.b.size_hint();
+        let hi = match (a_hi, b_hi) {
+            (Some(a_hi), Some(b_hi)) => a_hi.max(b_hi).min(a_hi.min(b_hi).saturating_add(1)).into(),
+            (Some(hi), None) | (None, Some(hi)) => hi.checked_add(1),
+            (None, None) => None,
+        };
+        (a_low.min(b_low), hi)
+    }
+}
+
+impl<I, J> FusedIterator for TryZipEq<I, J>
+    where I: Iterator,
+          J: Iterator
+{}
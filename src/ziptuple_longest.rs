@@ -0,0 +1,128 @@
+use std::iter::{Fuse, FusedIterator};
+
+use super::size_hint;
+
+/// See [`multizip_longest`] for more information.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ZipLongestTuple<T> {
+    t: T,
+}
+
+/// An iterator that generalizes *.zip_longest()* and allows running multiple
+/// iterators in lockstep until all of them are exhausted.
+///
+/// The iterator `ZipLongestTuple<(I, J, ..., M)>` is formed from a tuple of
+/// iterators (or values that implement [`IntoIterator`]) and yields elements
+/// until every subiterator yields `None`. Each subiterator is fused.
+///
+/// The iterator element type is a tuple like `(Option<A>, Option<B>, ...,
+/// Option<E>)` where `A` to `E` are the element types of the subiterators,
+/// and `None` marks a subiterator that is already exhausted. This avoids the
+/// nested [`EitherOrBoth`](crate::EitherOrBoth) values of chaining
+/// [`.zip_longest()`](crate::Itertools::zip_longest).
+///
+/// See also [`izip_longest!()`].
+///
+/// ```
+/// use itertools::multizip_longest;
+///
+/// let it = multizip_longest((0..3, "ab".chars(), vec![true]));
+/// itertools::assert_equal(it, vec![
+///     (Some(0), Some('a'), Some(true)),
+///     (Some(1), Some('b'), None),
+///     (Some(2), None, None),
+/// ]);
+/// ```
+pub fn multizip_longest<T, U>(t: U) -> ZipLongestTuple<T>
+    where ZipLongestTuple<T>: From<U>,
+          ZipLongestTuple<T>: Iterator,
+{
+    ZipLongestTuple::from(t)
+}
+
+macro_rules! impl_zip_longest_iter {
+    ($($B:ident),*) => (
+        #[allow(non_snake_case)]
+        impl<$($B: IntoIterator),*> From<($($B,)*)> for ZipLongestTuple<($(Fuse<$B::IntoIter>,)*)> {
+            fn from(t: ($($B,)*)) -> Self {
+                let ($($B,)*) = t;
+                ZipLongestTuple { t: ($($B.into_iter().fuse(),)*) }
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<$($B),*> Iterator for ZipLongestTuple<($(Fuse<$B>,)*)>
+            where
+            $(
+                $B: Iterator,
+            )*
+        {
+            type Item = ($(Option<$B::Item>,)*);
+
+            fn next(&mut self) -> Option<Self::Item>
+            {
+                let ($(ref mut $B,)*) = self.t;
+                match ($($B.next(),)*) {
+                    ($(None::<$B::Item>,)*) => None,
+                    elt => Some(elt),
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>)
+            {
+                let sh = (0, Some(0));
+                let ($(ref $B,)*) = self.t;
+                $(
+                    let sh = size_hint::max($B.size_hint(), sh);
+                )*
+                sh
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<$($B),*> ExactSizeIterator for ZipLongestTuple<($(Fuse<$B>,)*)> where
+            $(
+                $B: ExactSizeIterator,
+            )*
+        { }
+
+        #[allow(non_snake_case)]
+        impl<$($B),*> DoubleEndedIterator for ZipLongestTuple<($(Fuse<$B>,)*)> where
+            $(
+                $B: DoubleEndedIterator + ExactSizeIterator,
+            )*
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let ($(ref mut $B,)*) = self.t;
+                // only the longest subiterators have an element in the last row
+                let size = *[$( $B.len(), )*].iter().max().unwrap();
+                match ($(if $B.len() == size { $B.next_back() } else { None },)*) {
+                    ($(None::<$B::Item>,)*) => None,
+                    elt => Some(elt),
+                }
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<$($B),*> FusedIterator for ZipLongestTuple<($(Fuse<$B>,)*)> where
+            $(
+                $B: Iterator,
+            )*
+        { }
+    );
+}
+
+impl_zip_longest_iter!(A);
+impl_zip_longest_iter!(A, B);
+impl_zip_longest_iter!(A, B, C);
+impl_zip_longest_iter!(A, B, C, D);
+impl_zip_longest_iter!(A, B, C, D, E);
+impl_zip_longest_iter!(A, B, C, D, E, F);
+impl_zip_longest_iter!(A, B, C, D, E, F, G);
+impl_zip_longest_iter!(A, B, C, D, E, F, G, H);
+impl_zip_longest_iter!(A, B, C, D, E, F, G, H, I);
+impl_zip_longest_iter!(A, B, C, D, E, F, G, H, I, J);
+impl_zip_longest_iter!(A, B, C, D, E, F, G, H, I, J, K);
+impl_zip_longest_iter!(A, B, C, D, E, F, G, H, I, J, K, L);
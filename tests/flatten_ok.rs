@@ -74,3 +74,14 @@ fn flatten_ok_collect_ok_reverse() {
         Ok((0..6).rev().collect())
     );
 }
+
+#[test]
+fn flatten_ok_meet_in_the_middle() {
+    let mut it = vec![Ok::<_, bool>(0..4)].into_iter().flatten_ok();
+    assert_eq!(it.next(), Some(Ok(0)));
+    assert_eq!(it.next_back(), Some(Ok(3)));
+    assert_eq!(it.next_back(), Some(Ok(2)));
+    assert_eq!(it.next(), Some(Ok(1)));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+}
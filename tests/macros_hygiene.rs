@@ -11,3 +11,17 @@ fn izip_hygiene() {
     let _ = itertools::izip!(0..6, 0..9);
     let _ = itertools::izip!(0..6, 0..9, 0..12);
 }
+
+#[test]
+fn izip_longest_hygiene() {
+    let _ = itertools::izip_longest!(0..6);
+    let _ = itertools::izip_longest!(0..6, 0..9);
+    let _ = itertools::izip_longest!(0..6, 0..9, 0..12,);
+}
+
+#[test]
+fn fuse_ops_hygiene() {
+    let _ = itertools::fuse_ops!(0..6);
+    let _ = itertools::fuse_ops!(0..6, map(|x| x + 1));
+    let _ = itertools::fuse_ops!(0..6, filter(|x| *x > 1), inspect(|_| ()),);
+}
@@ -12,9 +12,15 @@ use std::collections::{HashMap, HashSet};
 use itertools::Itertools;
 use itertools::{
     multizip,
+    multizip_longest,
     EitherOrBoth,
+    MaskPolicy,
+    StopAtShortest,
+    DrainLongest,
+    PadWith,
     iproduct,
     izip,
+    izip_longest,
 };
 use itertools::free::{
     cloned,
@@ -31,6 +37,14 @@ use itertools::free::{
 use rand::Rng;
 use rand::seq::SliceRandom;
 use quickcheck::TestResult;
+#[cfg(feature = "arbitrary")]
+use itertools::arbitrary::{
+    self as arb,
+    CombinationsInput,
+    PermutationsInput,
+    PowersetInput,
+    Unstructured,
+};
 
 /// Trait for size hint modifier types
 trait HintKind: Copy + Send + qc::Arbitrary {
@@ -396,6 +410,42 @@ quickcheck! {
         correct_size_hint(iproduct!(a, b, c))
     }
 
+    fn size_cartesian_product_diagonal(a: Iter<u16>, b: Iter<u16>) -> bool {
+        correct_size_hint(a.cartesian_product_diagonal(b))
+    }
+
+    fn correct_cartesian_product_diagonal(a: Vec<u8>, b: Vec<u8>) -> bool {
+        let mut diagonal = a.iter().cartesian_product_diagonal(&b).collect_vec();
+        let mut product = a.iter().cartesian_product(&b).collect_vec();
+        // each diagonal is in order of increasing index into `a`
+        let indices = (0..a.len()).cartesian_product_diagonal(0..b.len());
+        let in_order = indices.tuple_windows().all(|((i0, j0), (i1, j1))| {
+            i0 + j0 < i1 + j1 || (i0 + j0 == i1 + j1 && i0 < i1)
+        });
+        diagonal.sort();
+        product.sort();
+        in_order && diagonal == product
+    }
+
+    fn correct_cartesian_product_double_ended(a: Vec<u8>, b: Vec<u8>, c: Vec<u8>,
+                                              from_back: Vec<bool>) -> ()
+    {
+        // pull from both ends in the order given by `from_back`, then fold the rest
+        let answer: Vec<_> = iproduct!(a.clone(), b.clone(), c.clone()).collect();
+        let mut expected = answer.iter().cloned().collect::<std::collections::VecDeque<_>>();
+        let mut product_iter = iproduct!(a, b, c);
+        for &back in from_back.iter().cycle().take(answer.len() + 2) {
+            assert_eq!(product_iter.size_hint(), (expected.len(), Some(expected.len())));
+            if back {
+                assert_eq!(product_iter.next_back(), expected.pop_back());
+            } else {
+                assert_eq!(product_iter.next(), expected.pop_front());
+            }
+        }
+        let rest: Vec<_> = expected.into_iter().collect();
+        assert_eq!(product_iter.fold(Vec::new(), |mut acc, elt| { acc.push(elt); acc }), rest);
+    }
+
     fn correct_cartesian_product3(a: Iter<u16>, b: Iter<u16>, c: Iter<u16>,
                                   take_manual: usize) -> ()
     {
@@ -590,6 +640,34 @@ quickcheck! {
         itertools::equal(merged.into_iter(),
                          inputs.into_iter().kmerge_by(|x, y| x <= y))
     }
+    fn equal_merge_by_priority(mut inputs: Vec<Vec<i16>>) -> bool {
+        for input in &mut inputs {
+            input.sort();
+        }
+        let mut merged = inputs.concat();
+        merged.sort();
+        // on sorted inputs, the priority of the heads is a sorting key
+        itertools::equal(merged.into_iter(),
+                         inputs.into_iter().merge_by_priority(|&x| x))
+    }
+    fn size_merge_by_priority(a: Iter<i16>, b: Iter<i16>, c: Iter<i16>) -> bool {
+        correct_size_hint(vec![a, b, c].into_iter().merge_by_priority(|&x| x / 4))
+    }
+    fn equal_kmerge_tagged(mut inputs: Vec<Vec<i16>>) -> bool {
+        for input in &mut inputs {
+            input.sort();
+        }
+        let tagged = inputs.clone().into_iter().kmerge_tagged().collect_vec();
+        // the values are merged, and each source keeps its own order
+        itertools::equal(tagged.iter().map(|&(_, x)| x), inputs.clone().into_iter().kmerge())
+            && inputs.iter().enumerate().all(|(i, input)| {
+                itertools::equal(tagged.iter().filter(|&&(j, _)| j == i).map(|&(_, x)| x),
+                                 input.iter().cloned())
+            })
+    }
+    fn size_kmerge_tagged(a: Iter<i16>, b: Iter<i16>, c: Iter<i16>) -> bool {
+        correct_size_hint(vec![a, b, c].into_iter().kmerge_tagged_by(|x, y| x > y))
+    }
     fn size_kmerge(a: Iter<i16>, b: Iter<i16>, c: Iter<i16>) -> bool {
         use itertools::free::kmerge;
         correct_size_hint(kmerge(vec![a, b, c]))
@@ -600,6 +678,20 @@ quickcheck! {
         let b = &b[..len];
         itertools::equal(zip_eq(a, b), zip(a, b))
     }
+    fn equal_try_zip_eq(a: Vec<i32>, b: Vec<i32>) -> bool {
+        let len = std::cmp::min(a.len(), b.len());
+        let mut it = a.iter().try_zip_eq(&b);
+        let pairs_ok = it.by_ref().take(len).map(Result::unwrap).eq(a.iter().zip(&b));
+        let tail: Vec<_> = it.collect();
+        pairs_ok && match tail.as_slice() {
+            [] => a.len() == b.len(),
+            [Err(e)] => e.index() == len && e.first_longer() == (a.len() > b.len()),
+            _ => false,
+        }
+    }
+    fn size_try_zip_eq(a: Iter<i16>, b: Iter<i16>) -> bool {
+        correct_size_hint(a.try_zip_eq(b))
+    }
     fn size_zip_longest(a: Iter<i16, Exact>, b: Iter<i16, Exact>) -> bool {
         let filt = a.clone().dedup();
         let filt2 = b.clone().dedup();
@@ -626,6 +718,17 @@ quickcheck! {
                          }
                          ))
     }
+    fn size_zip_longest_tuple(a: Iter<i16, Exact>, b: Iter<i16, Exact>, c: Iter<i16, Exact>) -> bool {
+        let filt = a.clone().dedup();
+        correct_size_hint(multizip_longest((filt, b.clone(), c.clone()))) &&
+            exact_size(izip_longest!(a, b, c))
+    }
+    fn equal_zip_longest_tuple(a: Vec<i16>, b: Vec<i16>, c: Vec<i16>) -> bool {
+        let len = max(a.len(), max(b.len(), c.len()));
+        let expected = (0..len).map(|i| (a.get(i), b.get(i), c.get(i)));
+        itertools::equal(izip_longest!(&a, &b, &c), expected.clone())
+            && itertools::equal(izip_longest!(&a, &b, &c).rev(), expected.rev())
+    }
     fn size_interleave(a: Iter<i16>, b: Iter<i16>) -> bool {
         correct_size_hint(a.interleave(b))
     }
@@ -638,9 +741,125 @@ quickcheck! {
     fn exact_interleave_shortest(a: Vec<()>, b: Vec<()>) -> bool {
         exact_size_for_this(a.iter().interleave_shortest(&b))
     }
+    fn size_interleave_tail(a: Iter<i16>, b: Iter<i16>) -> bool {
+        correct_size_hint(a.clone().interleave_tail(b.clone(), StopAtShortest)) &&
+        correct_size_hint(a.clone().interleave_tail(b.clone(), DrainLongest)) &&
+        correct_size_hint(a.interleave_tail(b, PadWith(|| 0)))
+    }
+    fn exact_interleave_tail(a: Vec<()>, b: Vec<()>) -> bool {
+        exact_size_for_this(a.iter().interleave_tail(&b, StopAtShortest)) &&
+        exact_size_for_this(a.iter().interleave_tail(&b, DrainLongest)) &&
+        exact_size_for_this(a.iter().interleave_tail(&b, PadWith(|| &())))
+    }
+    fn size_window_position_max(a: Iter<u8>, size: u8) -> bool {
+        correct_size_hint(a.window_position_max(size as usize + 1))
+    }
+    fn correct_window_position_max(a: Vec<u8>, size: u8) -> bool {
+        let size = size as usize % 8 + 1;
+        let naive = a.windows(size).enumerate()
+            .map(|(i, w)| i + w.iter().position_max().unwrap());
+        itertools::equal(a.iter().window_position_max(size), naive)
+    }
+    fn size_take_while_inclusive(a: Iter<i16>, x: i16) -> bool {
+        correct_size_hint(a.take_while_inclusive(|e| *e != x))
+    }
+    fn equal_take_while_inclusive(a: Vec<i16>, x: i16) -> bool {
+        let expected = match a.iter().position(|e| *e == x) {
+            Some(i) => &a[..=i],
+            None => &a[..],
+        };
+        itertools::equal(a.iter().take_while_inclusive(|e| **e != x), expected)
+    }
+    fn size_skip_while_inclusive(a: Iter<i16>, x: i16) -> bool {
+        correct_size_hint(a.fuse().skip_while_inclusive(|e| *e != x))
+    }
+    fn equal_skip_while_inclusive(a: Vec<i16>, x: i16) -> bool {
+        let expected = match a.iter().position(|e| *e == x) {
+            Some(i) => &a[i + 1..],
+            None => &[],
+        };
+        itertools::equal(a.iter().skip_while_inclusive(|e| **e != x), expected)
+    }
     fn size_intersperse(a: Iter<i16>, x: i16) -> bool {
         correct_size_hint(a.intersperse(x))
     }
+    fn size_intersperse_ref(a: Iter<i16>, x: i16) -> bool {
+        correct_size_hint(a.intersperse_ref(&x))
+    }
+    fn size_insert_at(a: Iter<i16>, indices: Vec<u8>) -> bool {
+        let indices = indices.into_iter().map(usize::from).sorted();
+        correct_size_hint(a.insert_at(indices, |i| -(i as i16)))
+    }
+    fn size_insert_at_unbounded(indices: Vec<u8>, n: u8) -> bool {
+        let indices = indices.into_iter().map(usize::from).sorted();
+        let mut it = (0u32..).insert_at(indices, |i| i as u32);
+        it.by_ref().take(n as usize).for_each(drop);
+        // the lower bound saturates instead of overflowing
+        it.size_hint() == (usize::MAX, None) && it.take(5).count() == 5
+    }
+    fn equal_insert_at(a: Vec<i32>, indices: Vec<u8>) -> bool {
+        let indices = indices.into_iter().map(usize::from).sorted().collect_vec();
+        let mut expected = a.clone();
+        for &i in indices.iter().rev().filter(|&&i| i <= a.len()) {
+            expected.insert(i, -(i as i32) - 1);
+        }
+        itertools::equal(a.into_iter().insert_at(indices, |i| -(i as i32) - 1), expected)
+    }
+    fn equal_select_indices_remove_at(a: Vec<i32>, indices: Vec<u8>) -> bool {
+        let indices = indices.into_iter().map(usize::from).sorted().collect_vec();
+        let selected = a.iter().enumerate()
+            .filter(|(i, _)| indices.binary_search(i).is_ok())
+            .map(|(_, x)| x);
+        let removed = a.iter().enumerate()
+            .filter(|(i, _)| indices.binary_search(i).is_err())
+            .map(|(_, x)| x);
+        itertools::equal(a.iter().select_indices(indices.clone()), selected)
+            && itertools::equal(a.iter().remove_at(indices.clone()), removed)
+    }
+    fn size_select_indices_remove_at(a: Iter<i16>, indices: Vec<u8>) -> bool {
+        let indices = indices.into_iter().map(usize::from).sorted();
+        correct_size_hint(a.clone().select_indices(indices.clone()))
+            && correct_size_hint(a.remove_at(indices))
+    }
+    fn equal_select_by_mask(a: Vec<i32>, mask: Vec<bool>) -> bool {
+        let truncated = a.iter().zip(&mask).filter(|(_, &m)| m).map(|(x, _)| x);
+        let kept = a.iter().zip(mask.iter().chain(std::iter::repeat(&true)))
+            .filter(|(_, &m)| m).map(|(x, _)| x);
+        let (selected, rejected): (Vec<&i32>, Vec<&i32>) =
+            a.iter().partition_by_mask(mask.iter().copied(), MaskPolicy::Truncate);
+        itertools::equal(a.iter().select_by_mask(mask.iter().copied(), MaskPolicy::Truncate), truncated)
+            && itertools::equal(a.iter().select_by_mask(mask.iter().copied(), MaskPolicy::KeepRest), kept)
+            && selected.len() + rejected.len() == a.len().min(mask.len())
+            && itertools::equal(selected, a.iter().select_by_mask(mask.iter().copied(), MaskPolicy::Truncate))
+    }
+    fn size_select_by_mask(a: Iter<i16>, mask: Iter<i16>) -> bool {
+        let mask = mask.map(|x| x % 2 == 0);
+        correct_size_hint(a.clone().select_by_mask(mask.clone(), MaskPolicy::Truncate))
+            && correct_size_hint(a.select_by_mask(mask, MaskPolicy::KeepRest))
+    }
+    fn size_intersperse_with_indexed(a: Iter<i16>) -> bool {
+        correct_size_hint(a.intersperse_with_indexed(|i, _| i as i16))
+    }
+    fn equal_intersperse_with_indexed(a: Vec<i32>) -> bool {
+        let seps = a.len().saturating_sub(1);
+        let it = a.iter().map(|&x| (x, None))
+            .intersperse_with_indexed(|i, last| (0, Some((i, last))));
+        itertools::equal(it.clone().map(|(x, _)| x), a.iter().cloned().intersperse(0))
+            && itertools::equal(it.filter_map(|(_, s)| s), (0..seps).map(|i| (i, i + 1 == seps)))
+    }
+    fn size_intersperse_between(a: Iter<i16>) -> bool {
+        correct_size_hint(a.intersperse_between(|x, y| x.wrapping_sub(*y)))
+    }
+    fn equal_intersperse_between(a: Vec<i32>) -> bool {
+        let it = a.iter().map(|&x| (x, 0)).intersperse_between(|&(x, _), &(y, _)| (0, x ^ y));
+        let seps = a.iter().tuple_windows().map(|(x, y)| x ^ y);
+        itertools::equal(it.clone().map(|(x, _)| x), a.iter().cloned().intersperse(0))
+            && itertools::equal(it.skip(1).step_by(2).map(|(_, s)| s), seps)
+    }
+    fn equal_intersperse_ref(a: Vec<i32>, x: i32) -> bool {
+        let by_ref = a.iter().cloned().intersperse_ref(&x).map(|e| e.either(|&s| s, |e| e));
+        itertools::equal(by_ref, a.iter().cloned().intersperse(x))
+    }
     fn equal_intersperse(a: Vec<i32>, x: i32) -> bool {
         let mut inter = false;
         let mut i = 0;
@@ -752,6 +971,99 @@ quickcheck! {
 
         assert_eq!(expected, actual);
     }
+
+    fn permutations_advance_to_rank(n: usize, k: usize, r: usize) -> () {
+        let (n, k, r) = (n % 6, k % 4, r % 130);
+        let mut perms = (0..n).permutations(k);
+        perms.advance_to_rank(r);
+        let total = (0..n).permutations(k).count();
+        assert_eq!(perms.rank(), r.min(total));
+        itertools::assert_equal(perms, (0..n).permutations(k).skip(r));
+    }
+
+    fn combinations_size(a: Iter<i32>, k: usize) -> bool {
+        correct_size_hint(a.take(8).combinations(k % 5))
+    }
+
+    fn combinations_with_replacement_size(a: Iter<i32>, k: usize) -> bool {
+        correct_size_hint(a.take(6).combinations_with_replacement(k % 4))
+    }
+
+    fn combinations_with_replacement_exact_size(a: Vec<u8>, k: usize) -> bool {
+        exact_size_for_this(a.iter().take(6).combinations_with_replacement(k % 4))
+    }
+
+    fn exact_count_combinatorics(n: usize, k: usize, skip: usize) -> bool {
+        let (n, k, skip) = (n % 7, k % 5, skip % 10);
+        let mut perms = (0..n).permutations(k);
+        let mut combs = (0..n).combinations(k);
+        let mut combs_repl = (0..n).combinations_with_replacement(k);
+        let mut sets = (0..n).powerset();
+        for _ in 0..skip {
+            perms.next();
+            combs.next();
+            combs_repl.next();
+            sets.next();
+        }
+        perms.clone().exact_count() == Some(perms.count() as u128) &&
+            combs.clone().exact_count() == Some(combs.count() as u128) &&
+            combs_repl.clone().exact_count() == Some(combs_repl.count() as u128) &&
+            sets.clone().exact_count() == Some(sets.count() as u128)
+    }
+
+    fn combinations_advance_to_rank(n: usize, k: usize, r: usize) -> () {
+        let (n, k, r) = (n % 8, k % 5, r % 80);
+        let mut combs = (0..n).combinations(k);
+        combs.next();
+        combs.advance_to_rank(r.max(1));
+        let total = (0..n).combinations(k).count();
+        assert_eq!(combs.rank(), r.max(1).min(total));
+        itertools::assert_equal(combs, (0..n).combinations(k).skip(r.max(1)));
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+quickcheck! {
+    fn model_permutations(data: Vec<u8>) -> bool {
+        let mut u = Unstructured::new(&data);
+        let input: PermutationsInput<u8> = arb::Arbitrary::arbitrary(&mut u).unwrap();
+        itertools::equal(input.iter(), input.expected())
+    }
+
+    fn model_combinations(data: Vec<u8>) -> bool {
+        let mut u = Unstructured::new(&data);
+        let input: CombinationsInput<u8> = arb::Arbitrary::arbitrary(&mut u).unwrap();
+        itertools::equal(input.iter(), input.expected())
+    }
+
+    fn model_powerset(data: Vec<u8>) -> bool {
+        let mut u = Unstructured::new(&data);
+        let input: PowersetInput<u8> = arb::Arbitrary::arbitrary(&mut u).unwrap();
+        itertools::equal(input.iter(), input.expected())
+    }
+}
+
+#[cfg(feature = "oorandom")]
+quickcheck! {
+    fn permute_by_seed(a: Vec<u8>, seed: u64) -> bool {
+        let permuted = a.iter().permute_by_seed(seed).collect_vec();
+        itertools::equal(a.iter().permute_by_seed(seed), permuted.iter().copied())
+            && itertools::equal(permuted.into_iter().sorted(), a.iter().sorted())
+    }
+}
+
+#[cfg(feature = "unique_approx")]
+quickcheck! {
+    fn unique_approx(a: Vec<u8>) -> bool {
+        itertools::equal(a.iter().unique_approx(a.len(), 1e-9), a.iter().unique())
+    }
+
+    fn unique_approx_saturated(a: Vec<u8>) -> bool {
+        // with a tiny filter, some distinct elements are lost but no
+        // duplicates are let through
+        let mut unique = a.iter().unique();
+        a.iter().unique_approx(1, 0.5).all(|x| unique.any(|y| x == y))
+    }
 }
 
 quickcheck! {
@@ -812,6 +1124,30 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn tail(v: Vec<i32>, n: u8) -> bool {
+        let n = n as usize;
+        let expected = &v[v.len().saturating_sub(n)..];
+        itertools::equal(v.iter().tail(n), expected)
+    }
+}
+
+quickcheck! {
+    fn digits_round_trip(n: u64, base: u8, back: usize) -> bool {
+        let base = base as u64 % 36 + 2;
+        let from_msb = itertools::digits_msb_first(n, base).fold(0, |acc, d| acc * base + d);
+        let lsb = itertools::digits(n, base).collect_vec();
+        // mixing both ends yields the same digits
+        let mut it = itertools::digits(n, base);
+        let back = back % (lsb.len() + 1);
+        let mut mixed = it.by_ref().rev().take(back).collect_vec();
+        mixed.reverse();
+        let mixed = it.chain(mixed).collect_vec();
+        exact_size(itertools::digits(n, base)) &&
+            from_msb == n && mixed == lsb && lsb.iter().all(|&d| d < base)
+    }
+}
+
 quickcheck! {
     fn size_put_back(a: Vec<u8>, x: Option<u8>) -> bool {
         let mut it = put_back(a.into_iter());
@@ -853,6 +1189,32 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn tee_n(a: Vec<u8>, schedule: Vec<u8>, n: u8) -> bool {
+        let n = n as usize % 5 + 1;
+        let mut tees = a.iter().tee_n(n);
+        let mut read = vec![Vec::<&u8>::new(); n];
+        for i in schedule {
+            let i = i as usize % n;
+            read[i].extend(tees[i].next());
+        }
+        // a dropped handle must not affect the others
+        tees.pop();
+        read.pop();
+        tees.into_iter().zip(read).all(|(tee, mut read)| {
+            read.extend(tee);
+            read == a.iter().collect_vec()
+        })
+    }
+
+    fn size_tee_n(a: Vec<u8>, k: u8) -> bool {
+        let mut tees = a.iter().tee_n(3);
+        tees[0].nth(k as usize);
+        tees[1].next();
+        tees.into_iter().all(exact_size)
+    }
+}
+
 quickcheck! {
     fn size_take_while_ref(a: Vec<u8>, stop: u8) -> bool {
         correct_size_hint(a.iter().take_while_ref(|x| **x != stop))
@@ -871,6 +1233,28 @@ quickcheck! {
         ap.sort();
         parted && (a == ap)
     }
+
+    fn equal_partition3_map(a: Vec<i32>) -> bool {
+        use itertools::Either3;
+        let (first, second, third): (Vec<_>, Vec<_>, Vec<_>) = a.iter().partition3_map(|&x| {
+            match x.rem_euclid(3) {
+                0 => Either3::First(x),
+                1 => Either3::Second(x as i64),
+                _ => Either3::Third(x.to_string()),
+            }
+        });
+        let classified = first.iter().all(|x| x.rem_euclid(3) == 0)
+            && second.iter().all(|x| x.rem_euclid(3) == 1)
+            && third.iter().all(|x| x.parse::<i32>().unwrap().rem_euclid(3) == 2);
+
+        // each output keeps the order of the input, so they concatenate back to it
+        let mut all = first;
+        all.extend(second.iter().map(|&x| x as i32));
+        all.extend(third.iter().map(|x| x.parse::<i32>().unwrap()));
+        let mut expected = a.clone();
+        expected.sort_by_key(|x| x.rem_euclid(3));
+        classified && all == expected
+    }
 }
 
 quickcheck! {
@@ -913,12 +1297,36 @@ quickcheck! {
         // Powerset cardinality gets large very quickly, limit input to keep test fast.
         correct_size_hint(it.take(12).powerset())
     }
+
+    fn size_powerset_bfs(it: Iter<u8>) -> bool {
+        correct_size_hint(it.take(12).powerset_bfs())
+    }
+
+    fn equal_powerset_bfs(a: Vec<u8>) -> bool {
+        let a = &a[..a.len().min(10)];
+        let mut x = a.iter().powerset().collect_vec();
+        let mut y = a.iter().powerset_bfs().collect_vec();
+        x.sort();
+        y.sort();
+        x == y
+    }
 }
 
 quickcheck! {
     fn size_duplicates(it: Iter<i8>) -> bool {
         correct_size_hint(it.duplicates())
     }
+
+    fn correct_duplicates_by(a: Vec<i8>) -> bool {
+        // every key is reported once, with the element at its second occurrence
+        let mut seen = HashMap::new();
+        let naive = a.iter().filter(|&&x| {
+            let count = seen.entry(x / 4).or_insert(0);
+            *count += 1;
+            *count == 2
+        });
+        itertools::equal(a.iter().duplicates_by(|&&x| x / 4), naive)
+    }
 }
 
 quickcheck! {
@@ -1040,6 +1448,314 @@ quickcheck! {
         itertools::equal(x, y)
     }
 
+    fn exact_tuple_windows_3(a: Vec<u8>) -> bool {
+        exact_size(a.iter().tuple_windows::<(_, _, _)>())
+    }
+
+    fn equal_array_windows_1(a: Vec<u8>) -> bool {
+        let x = a.windows(1).map(|s| [&s[0]]);
+        let y = a.iter().array_windows::<1>();
+        itertools::equal(x, y)
+    }
+
+    fn equal_array_windows_3(a: Vec<u8>) -> bool {
+        let x = a.windows(3).map(|s| [&s[0], &s[1], &s[2]]);
+        let y = a.iter().array_windows::<3>();
+        itertools::equal(x, y)
+    }
+
+    fn equal_array_windows_13(a: Vec<u8>) -> bool {
+        let x = a.windows(13).map(|s| s.to_vec());
+        let y = a.iter().cloned().array_windows::<13>().map(|w| w.to_vec());
+        itertools::equal(x, y)
+    }
+
+    fn size_array_windows_4(a: Iter<u8>) -> bool {
+        correct_size_hint(a.array_windows::<4>())
+    }
+
+    fn exact_array_windows_4(a: Vec<u8>) -> bool {
+        exact_size(a.iter().array_windows::<4>())
+    }
+
+    fn equal_zip_windows_3(a: Vec<u8>, b: Vec<i16>) -> bool {
+        let x = a.windows(3).zip(b.windows(3)).map(|(s, t)| (s.to_vec(), t.to_vec()));
+        let y = a.iter().cloned().zip_windows(b.iter().cloned(),
+            |s: &[u8; 3], t: &[i16; 3]| (s.to_vec(), t.to_vec()));
+        itertools::equal(x, y)
+    }
+
+    fn size_zip_windows_2(a: Iter<u8>, b: Iter<u8>) -> bool {
+        correct_size_hint(a.zip_windows(b, |s: &[u8; 2], t: &[u8; 2]| s[0] ^ t[1]))
+    }
+
+    fn exact_zip_windows_2(a: Vec<u8>, b: Vec<u8>) -> bool {
+        exact_size(a.iter().zip_windows(&b, |s: &[&u8; 2], _: &[&u8; 2]| *s[0]))
+    }
+
+    fn equal_windows_step(a: Vec<u8>, size: u8, step: u8) -> bool {
+        let size = size as usize % 8 + 1;
+        let step = step as usize % 8 + 1;
+        let naive = (0..).step_by(step)
+            .take_while(|&i| i + size <= a.len())
+            .map(|i| a[i..i + size].to_vec());
+        itertools::equal(a.iter().cloned().windows_step(size, step), naive)
+    }
+
+    fn size_windows_step(a: Iter<u8>, size: u8, step: u8) -> bool {
+        correct_size_hint(a.windows_step(size as usize % 8 + 1, step as usize % 8 + 1))
+    }
+
+    fn exact_windows_step(a: Vec<u8>, size: u8, step: u8) -> bool {
+        exact_size(a.iter().windows_step(size as usize % 8 + 1, step as usize % 8 + 1))
+    }
+
+    fn equal_split_when(a: Vec<u8>) -> bool {
+        let naive = a.split(|x| x % 4 == 0).map(|s| s.to_vec());
+        itertools::equal(a.iter().cloned().split_when(|x| x % 4 == 0), naive)
+    }
+
+    fn equal_split_when_inclusive(a: Vec<u8>) -> bool {
+        let naive = a.split_inclusive(|x| x % 4 == 0).map(|s| s.to_vec());
+        itertools::equal(a.iter().cloned().split_when_inclusive(|x| x % 4 == 0), naive)
+    }
+
+    fn size_split_when(a: Iter<u8>, inclusive: bool) -> bool {
+        if inclusive {
+            correct_size_hint(a.split_when_inclusive(|x| x % 4 == 0))
+        } else {
+            correct_size_hint(a.split_when(|x| x % 4 == 0))
+        }
+    }
+
+    fn equal_chunk_while(a: Vec<u8>) -> bool {
+        let chunks = a.iter().chunk_while(|x, y| x <= y).collect_vec();
+        chunks.iter().all(|c| !c.is_empty() && c.iter().tuple_windows().all(|(x, y)| x <= y))
+            && chunks.iter().tuple_windows().all(|(c, d)| c.last() > d.first())
+            && itertools::equal(chunks.into_iter().flatten(), &a)
+    }
+
+    fn size_chunk_while(a: Iter<u8>) -> bool {
+        correct_size_hint(a.chunk_while(|x, y| x / 4 == y / 4))
+    }
+
+    fn equal_batch_while(a: Vec<u8>, limit: u8) -> bool {
+        let limit = u32::from(limit);
+        let sum = |b: &[u8]| b.iter().map(|&x| u32::from(x)).sum::<u32>();
+        let batches = a.iter().cloned().batch_while(|b| sum(b) < limit).collect_vec();
+        let (last, full) = match batches.split_last() {
+            Some(split) => split,
+            None => return a.is_empty(),
+        };
+        let grew = |b: &[u8]| b.len() == 1 || sum(&b[..b.len() - 1]) < limit;
+        full.iter().all(|b| sum(b) >= limit && grew(b))
+            && !last.is_empty() && grew(last)
+            && itertools::equal(batches.iter().flatten(), &a)
+    }
+
+    fn size_batch_while(a: Iter<u8>) -> bool {
+        correct_size_hint(a.batch_while(|b| b.len() < 3))
+    }
+
+    fn multi_merge_join_two(a: Vec<u8>, b: Vec<u8>) -> bool {
+        use itertools::EitherOrBoth::{Both, Left, Right};
+        let (a, b) = (a.into_iter().sorted().collect_vec(), b.into_iter().sorted().collect_vec());
+        let expected = a.iter().merge_join_by(&b, |x, y| x.cmp(y)).map(|eob| match eob {
+            Left(x) => vec![Some(x), None],
+            Right(y) => vec![None, Some(y)],
+            Both(x, y) => vec![Some(x), Some(y)],
+        });
+        itertools::equal(vec![a.iter(), b.iter()].into_iter().multi_merge_join_by_key(|&&x| x), expected)
+    }
+
+    fn size_multi_merge_join(a: Iter<u8>, b: Iter<u8>, c: Vec<u8>) -> bool {
+        let sorted = |it: Vec<u8>| it.into_iter().sorted().collect_vec();
+        let (a, b, c) = (sorted(a.collect()), sorted(b.collect()), sorted(c));
+        correct_size_hint(vec![a, b, c].into_iter().multi_merge_join_by_key(|&x| x / 2))
+    }
+
+    fn equal_chunks_owned(a: Vec<u8>, size: u8) -> bool {
+        let size = size as usize % 5 + 1;
+        itertools::equal(a.iter().into_chunks_owned(size), a.chunks(size).map(|c| c.iter().collect_vec()))
+    }
+
+    fn size_chunks_owned(a: Iter<u8>, size: u8) -> bool {
+        correct_size_hint(a.into_chunks_owned(size as usize % 5 + 1))
+    }
+
+    fn exact_chunks_owned(a: Vec<u8>, size: u8) -> bool {
+        exact_size(a.into_iter().into_chunks_owned(size as usize % 5 + 1))
+    }
+
+    fn collect_chunked_into(a: Vec<u8>, size: u8) -> bool {
+        let size = size as usize % 5 + 1;
+        let sinks = a.iter().map(|&x| x as usize).collect_chunked_into(size, |i| vec![i]);
+        itertools::equal(sinks.iter().map(|s| s[0]), 0..sinks.len())
+            && itertools::equal(sinks.iter().map(|s| s[1..].to_vec()),
+                                a.chunks(size).map(|c| c.iter().map(|&x| x as usize).collect_vec()))
+    }
+
+    fn equal_cumsum(a: Vec<u16>) -> bool {
+        let naive = a.iter().scan(0u64, |acc, &x| { *acc += x as u64; Some(*acc) });
+        itertools::equal(a.iter().map(|&x| x as u64).cumsum(), naive)
+    }
+
+    fn exact_cumsum(a: Vec<u8>) -> bool {
+        exact_size(a.into_iter().cumsum_by(u8::wrapping_add))
+    }
+
+    fn fold_cumsum(a: Vec<u8>, skip: u8) -> bool {
+        let mut it = a.into_iter().cumsum_by(u8::wrapping_add);
+        it.by_ref().take(skip as usize % 4).for_each(drop);
+        let x = it.clone().fold(Vec::new(), |mut v, t| { v.push(t); v });
+        x == it.collect_vec()
+    }
+
+    fn equal_deltas(a: Vec<i16>) -> bool {
+        let a = a.into_iter().map(i32::from).collect_vec();
+        let naive = a.windows(2).map(|w| w[1] - w[0]);
+        itertools::equal(a.iter().deltas(), naive)
+            && itertools::equal(a.iter().cloned().cumsum().deltas(), a.iter().cloned().skip(1))
+    }
+
+    fn exact_deltas(a: Vec<u8>) -> bool {
+        exact_size(a.iter().diff_by(|x, y| (*x, *y)))
+    }
+
+    fn fold_deltas(a: Vec<u8>, skip: u8) -> bool {
+        let mut it = a.into_iter().diff_by(|x, y| y.wrapping_sub(*x));
+        it.by_ref().take(skip as usize % 4).for_each(drop);
+        let x = it.clone().fold(Vec::new(), |mut v, d| { v.push(d); v });
+        x == it.collect_vec()
+    }
+
+    fn equal_map_windowed(a: Vec<u8>, size: u8) -> bool {
+        let size = size as usize % 8 + 1;
+        let naive = a.windows(size).map(|w| w.to_vec());
+        itertools::equal(a.iter().cloned().map_windowed(size, |w| w.to_vec()), naive)
+    }
+
+    fn size_map_windowed(a: Iter<u8>, size: u8) -> bool {
+        correct_size_hint(a.map_windowed(size as usize % 8 + 1, |w| w.len()))
+    }
+
+    fn exact_map_windowed(a: Vec<u8>, size: u8) -> bool {
+        exact_size(a.iter().map_windowed(size as usize % 8 + 1, |w| w[0]))
+    }
+
+    fn equal_arrays_3(a: Vec<u8>) -> bool {
+        let mut it = a.iter().arrays::<3>();
+        let chunks = it.by_ref().map(|c| c.to_vec());
+        let x = a.chunks_exact(3).map(|s| s.iter().collect_vec());
+        itertools::equal(x, chunks) &&
+            itertools::equal(a.chunks_exact(3).remainder(), it.into_remainder())
+    }
+
+    fn exact_arrays_4(a: Vec<u8>) -> bool {
+        exact_size(a.iter().arrays::<4>())
+    }
+
+    fn exact_arrays_remainder(a: Vec<u8>) -> bool {
+        let mut it = a.iter().arrays::<5>();
+        it.by_ref().count();
+        exact_size(it.into_remainder())
+    }
+
+    fn equal_flatten_ok(a: Vec<Result<Vec<u8>, i8>>) -> bool {
+        let expected = a.iter().cloned().flat_map(|r| match r {
+            Ok(v) => v.into_iter().map(Ok).collect_vec(),
+            Err(e) => vec![Err(e)],
+        });
+        itertools::equal(a.iter().cloned().flatten_ok(), expected)
+    }
+
+    fn size_flatten_ok(a: Vec<Result<Vec<u8>, i8>>, k: u8) -> bool {
+        let mut it = a.into_iter().flatten_ok();
+        it.nth(k as usize);
+        correct_size_hint(it)
+    }
+
+    fn size_group_by_eager(a: Iter<i16>) -> bool {
+        correct_size_hint(a.group_by_eager(|x| x / 4))
+    }
+
+    fn equal_chunk_by_owned(a: Vec<i16>) -> bool {
+        let x = a.iter().group_by_eager(|x| *x / 4).map(|(k, g)| (k, g.collect_vec()));
+        itertools::equal(x, a.iter().chunk_by_owned(|x| *x / 4))
+    }
+
+    fn equal_chunk_by2(a: Vec<i16>) -> bool {
+        let x = a.iter().chunk_by_owned(|x| *x / 8).map(|(k, g)| {
+            (k, g.into_iter().chunk_by_owned(|x| *x / 4).collect_vec())
+        });
+        itertools::equal(x, a.iter().chunk_by2(|x| *x / 8, |x| *x / 4))
+    }
+
+    fn equal_group_by_eager(a: Vec<i16>) -> bool {
+        let lazy = a.iter().group_by(|x| *x / 4);
+        let x = lazy.into_iter().map(|(k, g)| (k, g.collect_vec()));
+        let y = a.iter().group_by_eager(|x| *x / 4).map(|(k, g)| {
+            assert_eq!(g.len(), g.size_hint().0);
+            (k, g.collect_vec())
+        });
+        itertools::equal(x, y)
+    }
+
+    fn size_mark_boundaries(a: Iter<i16, Exact>) -> bool {
+        let filt = a.clone().dedup();
+        correct_size_hint(filt.mark_boundaries(|x| x / 4))
+            && exact_size(a.mark_boundaries(|x| x / 4))
+    }
+
+    fn equal_mark_boundaries(a: Vec<i16>) -> bool {
+        use itertools::Position;
+        let x = a.iter().group_by(|x| *x / 4).into_iter().flat_map(|(_, g)| {
+            g.with_position().map(|pos| match pos {
+                Position::First(x) => (true, false, x),
+                Position::Middle(x) => (false, false, x),
+                Position::Last(x) => (false, true, x),
+                Position::Only(x) => (true, true, x),
+            }).collect_vec()
+        }).collect_vec();
+        itertools::equal(x, a.iter().mark_boundaries(|x| *x / 4))
+    }
+
+    fn equal_circular_tuple_windows_1(a: Vec<u8>) -> bool {
+        let x = a.iter().map(|e| (e, ));
+        let y = a.iter().circular_tuple_windows::<(_,)>();
+        itertools::equal(x, y)
+    }
+
+    fn equal_circular_tuple_windows_2(a: Vec<u8>) -> bool {
+        let n = a.len();
+        let x = (0..n).map(|i| (&a[i], &a[(i + 1) % n]));
+        let y = a.iter().circular_tuple_windows::<(_, _)>();
+        itertools::equal(x, y)
+    }
+
+    fn equal_circular_tuple_windows_3(a: Vec<u8>) -> bool {
+        let n = a.len();
+        let x = (0..n).map(|i| (&a[i], &a[(i + 1) % n], &a[(i + 2) % n]));
+        let y = a.iter().circular_tuple_windows::<(_, _, _)>();
+        itertools::equal(x, y)
+    }
+
+    fn exact_circular_tuple_windows_3(a: Vec<u8>) -> bool {
+        exact_size(a.iter().circular_tuple_windows::<(_, _, _)>())
+    }
+
+    fn equal_windows_split_by_1(a: Vec<u8>) -> bool {
+        let x = a.split(|x| x % 4 == 0).flat_map(|seg| seg.windows(1).map(|s| (&s[0], )));
+        let y = a.iter().windows_split_by::<(_,), _>(|x| *x % 4 == 0);
+        itertools::equal(x, y)
+    }
+
+    fn equal_windows_split_by_3(a: Vec<u8>) -> bool {
+        let x = a.split(|x| x % 4 == 0).flat_map(|seg| seg.windows(3).map(|s| (&s[0], &s[1], &s[2])));
+        let y = a.iter().windows_split_by::<(_, _, _), _>(|x| *x % 4 == 0);
+        itertools::equal(x, y)
+    }
+
     fn equal_tuples_1(a: Vec<u8>) -> bool {
         let x = a.chunks(1).map(|s| (&s[0], ));
         let y = a.iter().tuples::<(_,)>();
@@ -1084,6 +1800,25 @@ quickcheck! {
 }
 
 quickcheck! {
+    fn equal_run_length_decode(a: Vec<u8>) -> bool {
+        itertools::equal(a.iter().run_lengths().run_length_decode(), a.iter())
+    }
+
+    fn size_run_length_decode(a: Vec<(u8, u8)>) -> bool {
+        correct_size_hint(a.into_iter().map(|(n, x)| (n as usize % 4, x)).run_length_decode())
+    }
+
+    fn correct_unique_map_policies(a: Vec<(u8, u8)>) -> bool {
+        use itertools::DuplicatePolicy;
+        let last = a.iter().cloned().collect::<HashMap<_, _>>();
+        let first = a.iter().rev().cloned().collect::<HashMap<_, _>>();
+        let has_dups = a.iter().map(|&(k, _)| k).unique().count() != a.len();
+        let error = a.iter().cloned().into_unique_map(DuplicatePolicy::Error);
+        a.iter().cloned().into_unique_map(DuplicatePolicy::KeepLast) == Ok(last.clone())
+            && a.iter().cloned().into_unique_map(DuplicatePolicy::KeepFirst) == Ok(first)
+            && if has_dups { error.is_err() } else { error == Ok(last) }
+    }
+
     fn correct_group_map_modulo_key(a: Vec<u8>, modulo: u8) -> () {
         let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
         let count = a.len();
@@ -1095,6 +1830,19 @@ quickcheck! {
             assert!(vals.iter().all(|&val| val % modulo == key));
         }
     }
+
+    fn correct_route_by_key(a: Vec<u8>, modulo: u8, max_keys: u8) -> bool {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let max_keys = max_keys as usize % 8;
+        let lookup = a.iter().copied().into_group_map_by(|i| i % modulo);
+        let routed = a.iter().copied().route_by_key(Some(max_keys), |i| i % modulo);
+        let first_over = a.iter().map(|i| i % modulo).unique().nth(max_keys);
+        a.iter().copied().route_by_key(None, |i| i % modulo) == Ok(lookup.clone())
+            && match first_over {
+                Some(key) => routed.map_err(|err| *err.key()) == Err(key),
+                None => routed == Ok(lookup),
+            }
+    }
 }
 
 /// A peculiar type: Equality compares both tuple items, but ordering only the
@@ -1140,6 +1888,83 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn collect_ok_vec_err_vec(v: Vec<Result<u8, u8>>, max_errors: Option<u8>) -> bool {
+        let max_errors = max_errors.map(usize::from);
+        let mut errors = 0;
+        let consumed = v.iter()
+            .take_while(|r| {
+                let more = max_errors.map_or(true, |m| errors < m);
+                errors += r.is_err() as usize;
+                more
+            })
+            .cloned();
+        let expected: (Vec<_>, Vec<_>) = consumed.partition_result();
+        v.into_iter().collect_ok_vec_err_vec(max_errors) == expected
+    }
+
+    fn collect_until_err(v: Vec<Result<u8, u8>>) -> bool {
+        let split = v.iter().position(|r| r.is_err()).map_or(v.len(), |i| i + 1);
+        let (oks, err, rest) = v.iter().cloned().collect_until_err();
+        oks.iter().map(|&x| Ok(x)).chain(err.map(Err)).eq(v[..split].iter().cloned()) &&
+            rest.eq(v[split..].iter().cloned())
+    }
+
+    fn try_any_all_find_position(v: Vec<u8>) -> bool {
+        let ok = |x: &u8| Ok::<_, ()>(x % 3 == 0);
+        v.iter().try_any(ok) == Ok(v.iter().any(|x| x % 3 == 0))
+            && v.iter().try_all(ok) == Ok(v.iter().all(|x| x % 3 == 0))
+            && v.iter().try_find_position(|x| ok(x)) == Ok(v.iter().find_position(|x| *x % 3 == 0))
+            && v.iter().try_any(|_| Err::<bool, _>(())) == if v.is_empty() { Ok(false) } else { Err(()) }
+    }
+
+    fn reduce_ok(v: Vec<Result<u8, u8>>) -> bool {
+        let expected = itertools::process_results(v.iter().cloned(), |it| it.max());
+        v.into_iter().reduce_ok(std::cmp::max) == expected
+    }
+
+    fn process_options(v: Vec<Option<u8>>) -> bool {
+        let expected = if v.contains(&None) { None } else { Some(v.iter().flatten().copied().collect_vec()) };
+        itertools::process_options(v, |it| it.collect_vec()) == expected
+    }
+
+    fn while_some_complete(v: Vec<Option<u8>>) -> bool {
+        let mut it = v.iter().copied().while_some_complete();
+        let prefix_ok = itertools::equal(it.by_ref(), v.iter().copied().while_some());
+        prefix_ok && it.is_complete() != it.stopped_at_none()
+            && it.stopped_at_none() == v.contains(&None)
+    }
+
+    fn process_results_partial(v: Vec<Result<u8, u8>>) -> bool {
+        let oks: Vec<u8> = v.iter().take_while(|r| r.is_ok()).flatten().copied().collect();
+        let expected = match v.iter().find_map(|r| r.err()) {
+            Some(e) => Err((e, oks)),
+            None => Ok(oks),
+        };
+        itertools::process_results_partial(v, |it| it.collect::<Vec<_>>()) == expected
+    }
+
+    fn count_min_max_by_key(a: Vec<u8>) -> bool {
+        let key = |x: &&u8| *x / 4;
+        let min = a.iter().min_set_by_key(key);
+        let max = a.iter().max_set_by_key(key);
+        a.iter().count_min_by_key(key) == min.first().map(|x| (key(x), min.len()))
+            && a.iter().count_max_by_key(key) == max.first().map(|x| (key(x), max.len()))
+    }
+
+    fn min_set_max_set(a: Vec<Val>) -> bool {
+        // `Val` only orders by its first item, so ties keep their order
+        let min = a.iter().map(|v| v.0).min();
+        let max = a.iter().map(|v| v.0).max();
+        let expected_min = a.iter().filter(|v| Some(v.0) == min).collect_vec();
+        let expected_max = a.iter().filter(|v| Some(v.0) == max).collect_vec();
+        a.iter().min_set() == expected_min &&
+            a.iter().max_set() == expected_max &&
+            a.iter().min_set_by_key(|v| v.0) == expected_min &&
+            a.iter().max_set_by(|x, y| x.0.cmp(&y.0)) == expected_max
+    }
+}
+
 quickcheck! {
     fn minmax_f64(a: Vec<f64>) -> TestResult {
         use itertools::MinMaxResult;
@@ -1524,6 +2349,49 @@ quickcheck! {
         }
     }
 
+    fn correct_grouping_map_by_statistics_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let means = a.iter().copied().into_grouping_map_by(|i| i % modulo).mean();
+        let variances = a.iter().copied().into_grouping_map_by(|i| i % modulo).variance();
+        let stddevs = a.iter().copied().into_grouping_map_by(|i| i % modulo).stddev();
+        let group_map_lookup = a.iter().copied()
+            .map(|i| (i % modulo, f64::from(i)))
+            .into_group_map();
+        assert_eq!(means.len(), group_map_lookup.len());
+
+        let close = |a: f64, b: f64| (a - b).abs() <= 1e-6 * (1. + b.abs());
+        for (key, vals) in group_map_lookup {
+            let n = vals.len() as f64;
+            let mean = vals.iter().sum::<f64>() / n;
+            let variance = vals.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / n;
+            assert!(close(means[&key], mean));
+            assert!(close(variances[&key], variance));
+            assert!(close(stddevs[&key], variance.sqrt()));
+        }
+    }
+
+    fn correct_grouping_map_by_statistics_by_modulo_key(a: Vec<i64>, modulo: i64) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let to_f64 = |_: &i64, val: i64| val as f64;
+        let means = a.iter().copied().into_grouping_map_by(|i| i % modulo).mean_by(to_f64);
+        let variances = a.iter().copied().into_grouping_map_by(|i| i % modulo).variance_by(to_f64);
+        let stddevs = a.iter().copied().into_grouping_map_by(|i| i % modulo).stddev_by(to_f64);
+        let group_map_lookup = a.iter().copied()
+            .map(|i| (i % modulo, i as f64))
+            .into_group_map();
+        assert_eq!(means.len(), group_map_lookup.len());
+
+        let close = |a: f64, b: f64| (a - b).abs() <= 1e-6 * (1. + b.abs());
+        for (key, vals) in group_map_lookup {
+            let n = vals.len() as f64;
+            let mean = vals.iter().sum::<f64>() / n;
+            let variance = vals.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / n;
+            assert!(close(means[&key], mean));
+            assert!(close(variances[&key], variance));
+            assert!(close(stddevs[&key], variance.sqrt()));
+        }
+    }
+
     // This should check that if multiple elements are equally minimum or maximum
     // then `max`, `min` and `minmax` pick the first minimum and the last maximum.
     // This is to be consistent with `std::iter::max` and `std::iter::min`.
@@ -1569,6 +2437,34 @@ quickcheck! {
         }
         TestResult::passed()
     }
+
+    fn most_common(nums: Vec<u8>, n: usize) -> bool {
+        let n = n % 8;
+        let mut expected = nums.iter().unique()
+            .map(|x| (x, nums.iter().filter(|&y| y == x).count()))
+            .collect_vec();
+        // stable, so ties stay in order of first appearance
+        expected.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        expected.truncate(n);
+        nums.iter().most_common(n) == expected
+    }
+
+    fn counts_by_sorted(nums: Vec<isize>) -> bool {
+        let sorted = nums.iter().counts_by_sorted(|x| x % 5);
+        let hashed = nums.iter().counts_by(|x| x % 5);
+        sorted.len() == hashed.len() &&
+            sorted.iter().all(|(key, count)| hashed[key] == *count)
+    }
+
+    fn diff_multiset(a: Vec<u8>, b: Vec<u8>) -> bool {
+        let diff = a.iter().diff_multiset(&b);
+        let (ca, cb) = (a.iter().counts(), b.iter().counts());
+        let count = |counts: &HashMap<_, usize>, x| counts.get(x).copied().unwrap_or(0);
+        ca.keys().chain(cb.keys()).all(|x| {
+            count(&diff.only_left, x) == count(&ca, x).saturating_sub(count(&cb, x))
+                && count(&diff.only_right, x) == count(&cb, x).saturating_sub(count(&ca, x))
+        }) && diff.is_empty() == a.iter().sorted().eq(b.iter().sorted())
+    }
 }
 
 quickcheck! {
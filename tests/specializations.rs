@@ -87,6 +87,90 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn put_back_n_qc(test_vec: Vec<i32>) -> () {
+        let mut pb = itertools::put_back_n(test_vec.into_iter());
+        test_specializations(&pb);
+        pb.put_back(1);
+        pb.put_back(2);
+        test_specializations(&pb);
+    }
+}
+
+quickcheck! {
+    fn peek_nth(v: Vec<u8>) -> () {
+        let mut it = itertools::peek_nth(v.into_iter());
+        test_specializations(&it);
+        it.peek_nth(2);
+        test_specializations(&it);
+    }
+}
+
+quickcheck! {
+    fn multipeek(v: Vec<u8>) -> () {
+        let mut it = v.into_iter().multipeek();
+        test_specializations(&it);
+        it.peek();
+        it.peek();
+        test_specializations(&it);
+    }
+}
+
+quickcheck! {
+    fn pad_using(v: Vec<u8>, min: u8) -> () {
+        test_specializations(&v.into_iter().pad_using(min as usize, |i| i as u8));
+    }
+}
+
+quickcheck! {
+    fn with_position(v: Vec<u8>) -> () {
+        use itertools::Position;
+        fn tag(p: Position<u8>) -> (u8, u8) {
+            match p {
+                Position::First(x) => (0, x),
+                Position::Middle(x) => (1, x),
+                Position::Last(x) => (2, x),
+                Position::Only(x) => (3, x),
+            }
+        }
+        let mut it = v.into_iter().with_position();
+        test_specializations(&it.clone().map(tag));
+        it.next();
+        test_specializations(&it.map(tag));
+    }
+}
+
+quickcheck! {
+    fn positions(v: Vec<u8>) -> () {
+        let mut it = v.into_iter().positions(|x| x % 3 == 0);
+        test_specializations(&it);
+        it.next();
+        test_specializations(&it);
+    }
+}
+
+quickcheck! {
+    fn while_some(v: Vec<Option<u8>>) -> () {
+        test_specializations(&v.into_iter().while_some().fuse());
+    }
+}
+
+quickcheck! {
+    fn update(v: Vec<u8>) -> () {
+        test_specializations(&v.into_iter().update(|x| *x = x.wrapping_mul(3)));
+    }
+}
+
+quickcheck! {
+    fn take_while_inclusive(v: Vec<u8>, x: u8) -> () {
+        test_specializations(&v.into_iter().take_while_inclusive(|e| *e != x));
+    }
+
+    fn skip_while_inclusive(v: Vec<u8>, x: u8) -> () {
+        test_specializations(&v.into_iter().skip_while_inclusive(|e| *e != x));
+    }
+}
+
 quickcheck! {
     fn merge_join_by_qc(i1: Vec<usize>, i2: Vec<usize>) -> () {
         test_specializations(&i1.into_iter().merge_join_by(i2.into_iter(), std::cmp::Ord::cmp));
@@ -105,6 +189,12 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn and_then_ok(v: Vec<Result<u8, char>>) -> () {
+        test_specializations(&v.into_iter().and_then_ok(|u| u.checked_add(1).ok_or('+')));
+    }
+}
+
 quickcheck! {
     fn process_results(v: Vec<Result<u8, u8>>) -> () {
         helper(v.iter().copied());
@@ -150,4 +240,29 @@ quickcheck! {
             }
         }
     }
+
+    fn process_options(v: Vec<Option<u8>>) -> () {
+        helper(v.iter().copied());
+        helper(v.iter().copied().filter(Option::is_some));
+
+        fn helper(it: impl Iterator<Item = Option<u8>> + Clone) {
+            macro_rules! check_options_specialized {
+                ($src:expr, |$it:pat| $closure:expr) => {
+                    assert_eq!(
+                        itertools::process_options($src.clone(), |$it| $closure),
+                        itertools::process_options($src.clone(), |i| {
+                            let $it = Unspecialized(i);
+                            $closure
+                        }),
+                    )
+                }
+            }
+
+            check_options_specialized!(it, |i| i.count());
+            check_options_specialized!(it, |i| i.fold(vec![], |mut acc, v| {
+                acc.push(v);
+                acc
+            }));
+        }
+    }
 }
@@ -16,6 +16,7 @@ use crate::it::free::put_back;
 use crate::it::iproduct;
 use crate::it::izip;
 use crate::it::chain;
+use crate::it::fuse_ops;
 
 #[test]
 fn product2() {
@@ -58,6 +59,23 @@ fn izip_macro() {
     assert!(zip.next().is_none());
 }
 
+#[test]
+fn fuse_ops_macro() {
+    let xs = [1, 2, 3, 4, 5, 6];
+    let mut seen = 0;
+    let it = fuse_ops!(&xs,
+        map(|x| x * 3),
+        inspect(|_| seen += 1),
+        filter(|x| x % 2 == 0),
+        filter_map(|x| if x < 15 { Some(x as u8) } else { None }),
+    );
+    it::assert_equal(it, [6u8, 12]);
+    assert_eq!(seen, 6);
+
+    let it = fuse_ops!(0..3);
+    it::assert_equal(it, 0..3);
+}
+
 #[test]
 fn izip2() {
     let _zip1: iter::Zip<_, _> = izip!(1.., 2..);
@@ -192,6 +210,27 @@ fn batching() {
     it::assert_equal(pit, ys.iter().cloned());
 }
 
+#[test]
+fn batching_put_back() {
+    let xs = [1, 1, 2, 3, 3, 3, 1];
+    let ys = [(2, 1), (1, 2), (3, 3), (1, 1)];
+
+    // An iterator that counts runs, reading one element past each run
+    let pit = xs.iter().cloned().batching_put_back(|it| {
+        let first = it.next()?;
+        let mut count = 1;
+        while let Some(x) = it.next() {
+            if x != first {
+                it.put_back(x);
+                break;
+            }
+            count += 1;
+        }
+        Some((count, first))
+    });
+    it::assert_equal(pit, ys.iter().cloned());
+}
+
 #[test]
 fn test_put_back() {
     let xs = [0, 1, 1, 1, 2, 1, 3, 3];
@@ -217,6 +256,15 @@ fn merge() {
 }
 
 
+#[test]
+fn digits() {
+    it::assert_equal(it::digits(10, 2), [0, 1, 0, 1].iter().cloned());
+    it::assert_equal(it::digits_msb_first(10, 2), [1, 0, 1, 0].iter().cloned());
+    it::assert_equal(it::digits(0, 3), Some(0));
+    it::assert_equal(it::digits_msb_first(u64::MAX, 16), core::iter::repeat(15).take(16));
+    assert_eq!(it::digits(u64::MAX, 2).len(), 64);
+}
+
 #[test]
 fn repeatn() {
     let s = "α";
@@ -320,3 +368,142 @@ fn product1() {
     assert_eq!(v[1..3].iter().cloned().product1::<i32>(), Some(2));
     assert_eq!(v[1..5].iter().cloned().product1::<i32>(), Some(24));
 }
+
+#[test]
+fn memoized_recurrence() {
+    use crate::it::memoized_recurrence;
+
+    let mut calls = 0;
+    let it = memoized_recurrence([1, 2, 3], |&[a, _, c]| { calls += 1; a * c });
+    it::assert_equal(it.take(6), [1, 2, 3, 3, 6, 18].iter().cloned());
+    assert_eq!(calls, 3);
+
+    // order 0 recurrences only have the step
+    it::assert_equal(memoized_recurrence([], |_: &[u8; 0]| 7).take(2), [7, 7].iter().cloned());
+}
+
+#[test]
+fn map_either() {
+    use crate::it::Either;
+
+    let it = (0..6).map_either(|&x| x < 3, |x| x * 2, |x| x as u8);
+    it::assert_equal(it.clone().rev(),
+                     [Either::Right(5u8), Either::Right(4), Either::Right(3),
+                      Either::Left(4), Either::Left(2), Either::Left(0)].iter().cloned());
+    assert_eq!(it.len(), 6);
+    it::assert_equal((0..6).map_either(|&x| x < 3, |x| x, |x| -x).merge_either(),
+                     [0, 1, 2, -3, -4, -5].iter().cloned());
+}
+
+#[test]
+fn linspace() {
+    use crate::it::{arange, linspace, linspace_exclusive};
+
+    it::assert_equal(linspace(0., 1., 3), [0., 0.5, 1.].iter().cloned());
+    it::assert_equal(linspace(0f32, 1., 3).rev(), [1., 0.5, 0.].iter().cloned());
+    it::assert_equal(linspace(2., 3., 1), iter::once(2.));
+    assert_eq!(linspace(0., 1., 0).next(), None);
+    assert_eq!(linspace(0.1, 0.3, 3).last(), Some(0.3));
+    let mut it = linspace(0., 9., 10);
+    assert_eq!(it.nth(3), Some(3.));
+    assert_eq!(it.len(), 6);
+
+    it::assert_equal(linspace_exclusive(0., 3., 3), [0., 1., 2.].iter().cloned());
+
+    assert_eq!(arange(0., 1., 0.3).len(), 4);
+    assert_eq!(arange(0., 1., 0.25).len(), 4);
+    assert_eq!(arange(0., -1., 0.25).len(), 0);
+    it::assert_equal(arange(3., 0., -1.), [3., 2., 1.].iter().cloned());
+
+    assert_eq!(arange(0., f64::INFINITY, 1.).len(), usize::MAX);
+    assert_eq!(arange(0., 1e300, 1.).len(), usize::MAX);
+    assert_eq!(arange(0., f64::NAN, 1.).len(), 0);
+    assert_eq!(arange(f64::NAN, 1., 1.).len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn arange_zero_step() {
+    let _ = it::arange(0., 1., 0.);
+}
+
+#[test]
+fn generate() {
+    use crate::it::{generate, try_generate};
+
+    let mut it = generate((0, 1), |&(a, b)| if a < 10 { Some((a, (b, a + b))) } else { None });
+    it::assert_equal(it.by_ref().take(3), [0, 1, 1].iter().cloned());
+    assert_eq!(it.state, (2, 3));
+    let checkpoint = it.clone();
+    it::assert_equal(it, [2, 3, 5, 8].iter().cloned());
+    it::assert_equal(checkpoint, [2, 3, 5, 8].iter().cloned());
+
+    let mut it = try_generate(0, |&n| if n == 2 { Err("two") } else { Ok(Some((n, n + 1))) });
+    assert_eq!(it.next(), Some(Ok(0)));
+    assert_eq!(it.next(), Some(Ok(1)));
+    assert_eq!(it.next(), Some(Err("two")));
+    assert_eq!(it.state, 2);
+    it.state = 3;
+    assert_eq!(it.next(), Some(Ok(3)));
+}
+
+#[test]
+fn all_values() {
+    use crate::it::all_values;
+
+    assert_eq!(all_values::<()>().count(), 1);
+    assert_eq!(all_values::<u8>().count(), 256);
+    assert_eq!(all_values::<i16>().next(), Some(i16::MIN));
+    it::assert_equal(all_values::<(bool,)>(), [(false,), (true,)].iter().cloned());
+    it::assert_equal(all_values::<(bool, Option<bool>)>(), [
+        (false, None), (false, Some(false)), (false, Some(true)),
+        (true, None), (true, Some(false)), (true, Some(true)),
+    ].iter().cloned());
+    assert_eq!(all_values::<(bool, bool, u8, ())>().count(), 1024);
+    it::assert_equal(all_values::<[bool; 2]>(),
+                     [[false, false], [false, true], [true, false], [true, true]].iter().cloned());
+    assert_eq!(all_values::<[bool; 0]>().count(), 1);
+    assert_eq!(all_values::<[Option<bool>; 3]>().count(), 27);
+    assert!(all_values::<[u8; 2]>().tuple_windows().all(|(a, b)| a < b));
+}
+
+#[test]
+fn map_while_ok() {
+    let input = [Ok(1), Err('a'), Ok(0), Ok(2)];
+    let it = input.iter().cloned().map_while_ok(|x| 10u8.checked_div(x));
+    it::assert_equal(it, [Ok(10), Err('a')].iter().cloned());
+    let it = input.iter().cloned().and_then_ok(|x| 10u8.checked_div(x).ok_or('0'));
+    it::assert_equal(it, [Ok(10), Err('a'), Err('0'), Ok(5)].iter().cloned());
+}
+
+#[test]
+#[should_panic]
+fn select_by_mask_strict_mismatch() {
+    let mask = [true, false];
+    (0..3).select_by_mask(mask.iter().copied(), it::MaskPolicy::Strict).for_each(drop);
+}
+
+#[test]
+#[cfg(feature = "use_alloc")]
+fn tee_bounded_no_std() {
+    use core::fmt::Write;
+
+    let (mut t1, mut t2) = (0..3).tee_bounded(1);
+    assert_eq!(t1.next(), Some(Ok(0)));
+    let full = t1.next().unwrap().unwrap_err();
+    assert_eq!(full, it::TeeBufferFull);
+    assert_eq!(t2.next(), Some(Ok(0)));
+    assert_eq!(t1.next(), Some(Ok(1)));
+
+    // Display does not need std
+    struct Len(usize);
+    impl Write for Len {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.0 += s.len();
+            Ok(())
+        }
+    }
+    let mut len = Len(0);
+    write!(len, "{}", full).unwrap();
+    assert!(len.0 > 0);
+}
@@ -3,7 +3,7 @@ use permutohedron;
 use quickcheck as qc;
 use rand::{distributions::{Distribution, Standard}, Rng, SeedableRng, rngs::StdRng};
 use rand::{seq::SliceRandom, thread_rng};
-use std::{cmp::min, fmt::Debug, marker::PhantomData};
+use std::{cmp::{min, Reverse}, collections::HashMap, fmt::Debug, marker::PhantomData};
 use itertools as it;
 use crate::it::Itertools;
 use crate::it::ExactlyOneError;
@@ -34,6 +34,29 @@ fn product3() {
     }
 }
 
+#[test]
+fn product_rev() {
+    let prod = iproduct!(0..3, 0..2, 0..2);
+    it::assert_equal(prod.clone().rev(), prod.collect_vec().into_iter().rev());
+
+    let mut prod = (0..2).cartesian_product(0..3);
+    assert_eq!(prod.next_back(), Some((1, 2)));
+    assert_eq!(prod.next(), Some((0, 0)));
+    assert_eq!(prod.size_hint(), (4, Some(4)));
+    it::assert_equal(prod.rev(), vec![(1, 1), (1, 0), (0, 2), (0, 1)]);
+}
+
+#[test]
+fn product_empty_right_does_not_drain_left() {
+    let mut left = 0..5;
+    let mut prod = left.by_ref().cartesian_product(0..0);
+    assert_eq!(prod.next(), None);
+    assert_eq!(prod.next(), None);
+    drop(prod);
+    // only the first element is taken, when the product is created
+    assert_eq!(left, 1..5);
+}
+
 #[test]
 fn interleave_shortest() {
     let v0: Vec<i32> = vec![0, 2, 4];
@@ -59,6 +82,133 @@ fn interleave_shortest() {
     assert_eq!(it.size_hint(), (6, Some(6)));
 }
 
+#[test]
+fn interleave_tail() {
+    use crate::it::{DrainLongest, PadWith, StopAtShortest};
+
+    let it = (0..5).interleave_tail(10..12, StopAtShortest);
+    it::assert_equal(it, vec![0, 10, 1, 11, 2]);
+    let it = (0..2).interleave_tail(10..15, StopAtShortest);
+    it::assert_equal(it, vec![0, 10, 1, 11]);
+
+    let it = (0..5).interleave_tail(10..12, DrainLongest);
+    it::assert_equal(it, vec![0, 10, 1, 11, 2, 3, 4]);
+    let it = (0..2).interleave_tail(10..15, DrainLongest);
+    it::assert_equal(it, vec![0, 10, 1, 11, 12, 13, 14]);
+
+    let it = (0..4).interleave_tail(10..12, PadWith(|| -1));
+    it::assert_equal(it, vec![0, 10, 1, 11, 2, -1, 3]);
+    let it = (0..2).interleave_tail(10..14, PadWith(|| -1));
+    it::assert_equal(it, vec![0, 10, 1, 11, -1, 12, -1, 13]);
+    let it = (0..0).interleave_tail(0..0, PadWith(|| -1));
+    it::assert_equal(it, vec![]);
+}
+
+#[test]
+fn map_parallel() {
+    use std::thread;
+    use std::time::Duration;
+
+    // later elements finish first, the output order must not change
+    let it = (0..20u64).map_parallel(4, |x| {
+        thread::sleep(Duration::from_millis(20 - x));
+        x * 2
+    });
+    assert_eq!(it.size_hint(), (20, Some(20)));
+    it::assert_equal(it, (0..20).map(|x| x * 2));
+
+    let mut it = (0..100).map_parallel(3, |x| x + 1);
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.size_hint(), (99, Some(99)));
+    drop(it);
+
+    it::assert_equal((0..0).map_parallel(1, |x: i32| x), vec![]);
+}
+
+#[test]
+#[should_panic(expected = "boom")]
+fn map_parallel_panic() {
+    (0..10).map_parallel(2, |x| if x == 5 { panic!("boom") } else { x }).count();
+}
+
+#[test]
+fn map_parallel_after_panic() {
+    use std::panic::{self, AssertUnwindSafe};
+
+    let mut it = (0..10).map_parallel(2, |x| if x == 5 { panic!("boom") } else { x });
+    let res = panic::catch_unwind(AssertUnwindSafe(|| it.by_ref().count()));
+    assert!(res.is_err());
+    // the adaptor is fused after the panic, instead of waiting forever
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn with_hasher() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::BuildHasherDefault;
+    type Deterministic = BuildHasherDefault<DefaultHasher>;
+
+    let xs = [0, 1, 2, 3, 2, 1, 3];
+    it::assert_equal(xs.iter().unique_with_hasher(Deterministic::default()), xs.iter().unique());
+    it::assert_equal(xs.iter().duplicates_with_hasher(Deterministic::default()), xs.iter().duplicates());
+    it::assert_equal(
+        xs.iter().rev().unique_by_with_hasher(|x| *x % 2, Deterministic::default()),
+        xs.iter().rev().unique_by(|x| *x % 2),
+    );
+    it::assert_equal(
+        xs.iter().rev().duplicates_by_with_hasher(|x| *x % 2, Deterministic::default()),
+        xs.iter().rev().duplicates_by(|x| *x % 2),
+    );
+
+    let counts = xs.iter().counts_with_hasher(Deterministic::default());
+    assert_eq!(counts.into_iter().collect::<HashMap<_, _>>(), xs.iter().counts());
+}
+
+#[test]
+fn pipeline() {
+    use crate::it::pipeline;
+
+    let config = ["double", "odd_only", "skip_one", "unknown"];
+    let stages = config.iter().filter_map(|name| match *name {
+        "double" => Some(pipeline::map(|x: u32| x * 2)),
+        "odd_only" => Some(pipeline::filter(|x: &u32| x % 4 == 2)),
+        "skip_one" => Some(pipeline::skip(1)),
+        _ => None,
+    });
+    let it = pipeline::apply(0..10, stages);
+    it::assert_equal(it, vec![6, 10, 14, 18]);
+
+    let names = pipeline::map(|x: u32| x.to_string()).run(pipeline::apply(0..3, vec![]));
+    it::assert_equal(names, vec!["0", "1", "2"]);
+
+    let sums = pipeline::map(|c: Vec<u32>| c.iter().sum::<u32>())
+        .run(pipeline::chunks(3).run(Box::new(1..=7)));
+    it::assert_equal(sums, vec![6, 15, 7]);
+}
+
+#[test]
+fn dyn_itertools() {
+    use crate::it::pipeline::{self, BoxIter, DynItertools};
+
+    let words = "one two three two one";
+    let pipelines: Vec<BoxIter<'_, String>> = vec![
+        words.split(' ').boxed_map(String::from),
+        words.split(' ').boxed_filter(|w| w.starts_with('t')).boxed_dedup()
+             .boxed_map(str::to_uppercase),
+        words.split(' ').boxed_skip(3).boxed_chain(Some("four"))
+             .boxed_flat_map(|w| w.get(..1)).boxed_map(String::from),
+    ];
+    let results = pipelines.into_iter().map(|mut it| it.join(" ")).collect_vec();
+    assert_eq!(results, vec!["one two three two one", "TWO THREE TWO", "t o f"]);
+
+    let it = (1..10).boxed_filter_map(|x| if x % 3 == 0 { Some(x * 2) } else { None })
+                    .boxed_take(2)
+                    .boxed_apply(pipeline::map(|x: i32| -x));
+    it::assert_equal(it, vec![-6, -12]);
+}
+
 #[test]
 fn duplicates_by() {
     let xs = ["aaa", "bbbbb", "aa", "ccc", "bbbb", "aaaaa", "cccc"];
@@ -120,6 +270,26 @@ fn unique() {
     it::assert_equal(ys_rev.iter(), xs.iter().unique().rev());
 }
 
+#[test]
+fn unique_seen() {
+    let mut it = (0..100).map(|x| x % 10).unique();
+    it.by_ref().take(5).for_each(drop);
+    assert_eq!(it.seen_len(), 5);
+    it.by_ref().for_each(drop);
+    assert_eq!(it.seen_len(), 10);
+    it.shrink_seen_to_fit();
+    assert!(it.seen_capacity() >= 10);
+    let (rest, seen) = it.into_inner();
+    assert_eq!(rest.count(), 0);
+    assert_eq!(seen, (0..10).collect());
+
+    let mut it = [1, 2, 2, 3].iter().duplicates();
+    it.by_ref().for_each(drop);
+    assert_eq!(it.seen_len(), 3);
+    let (_, seen) = it.into_inner();
+    it::assert_equal([4, 3, 5].iter().duplicates().with_seen(seen), &[3]);
+}
+
 #[test]
 fn intersperse() {
     let xs = ["a", "", "b", "c"];
@@ -184,6 +354,14 @@ fn dedup_by() {
     assert_eq!(&xs_d, &ys);
 }
 
+#[test]
+fn dedup_by_key() {
+    let xs = [(0, 0), (0, 1), (1, 1), (2, 1), (0, 2), (3, 1), (0, 3), (1, 3)];
+    let ys = [(0, 0), (0, 1), (0, 2), (3, 1), (0, 3)];
+    it::assert_equal(ys.iter(), xs.iter().dedup_by_key(|x| x.1));
+    it::assert_equal(xs.iter().dedup_by(|x, y| x.0==y.0), xs.iter().dedup_by_key(|x| x.0));
+}
+
 #[test]
 fn dedup_with_count() {
     let xs: [i32; 8] = [0, 1, 1, 1, 2, 1, 3, 3];
@@ -211,6 +389,17 @@ fn dedup_by_with_count() {
     it::assert_equal(ys.iter().cloned(), xs.iter().dedup_by_with_count(|x, y| x.0==y.0));
 }
 
+#[test]
+fn run_lengths_by_key() {
+    let xs = [(0, 0), (0, 1), (1, 1), (2, 1), (0, 2), (3, 1), (0, 3), (1, 3)];
+    let ys = [(1, &(0, 0)), (3, &(0, 1)), (1, &(0, 2)), (1, &(3, 1)), (2, &(0, 3))];
+
+    it::assert_equal(ys.iter().cloned(), xs.iter().run_lengths_by_key(|x| x.1));
+    it::assert_equal(xs.iter().run_lengths_by_key(|x| x.1),
+                     xs.iter().run_lengths_by(|x, y| x.1 == y.1));
+    it::assert_equal(xs.iter().run_lengths(), xs.iter().map(|x| (1, x)));
+}
+
 #[test]
 fn all_equal() {
     assert!("".chars().all_equal());
@@ -229,6 +418,29 @@ fn all_unique() {
     assert!(::std::iter::empty::<usize>().all_unique());
 }
 
+#[test]
+fn into_unique_map_error_key() {
+    use std::hash::{Hash, Hasher};
+
+    // keys that compare equal by name, but remember where they were read
+    #[derive(Debug)]
+    struct Key(&'static str, usize);
+
+    impl PartialEq for Key {
+        fn eq(&self, other: &Self) -> bool { self.0 == other.0 }
+    }
+
+    impl Eq for Key {}
+
+    impl Hash for Key {
+        fn hash<H: Hasher>(&self, state: &mut H) { self.0.hash(state) }
+    }
+
+    let data = vec![(Key("a", 0), 1), (Key("b", 1), 2), (Key("a", 2), 3)];
+    let err = data.into_iter().into_unique_map(itertools::DuplicatePolicy::Error).unwrap_err();
+    assert_eq!(err.key().1, 2);
+}
+
 #[test]
 fn test_put_back_n() {
     let xs = [0, 1, 1, 1, 2, 1, 3, 3];
@@ -266,6 +478,40 @@ fn tee() {
     it::assert_equal(t1.zip(t2), xs.iter().cloned().zip(xs.iter().cloned()));
 }
 
+#[test]
+fn tee_bounded() {
+    use itertools::TeeBufferFull;
+
+    let xs = [0, 1, 2, 3];
+    let (mut t1, mut t2) = xs.iter().cloned().tee_bounded(1);
+    assert_eq!(t1.next(), Some(Ok(0)));
+    assert_eq!(t1.next(), Some(Err(TeeBufferFull)));
+    assert_eq!(t1.next(), Some(Err(TeeBufferFull)));
+    assert_eq!(t2.next(), Some(Ok(0)));
+    assert_eq!(t2.next(), Some(Ok(1)));
+    assert_eq!(t2.next(), Some(Err(TeeBufferFull)));
+    assert_eq!(t1.next(), Some(Ok(1)));
+    assert_eq!(t1.next(), Some(Ok(2)));
+
+    // in lockstep, the buffer never fills up
+    let (t1, t2) = xs.iter().cloned().tee_bounded(1);
+    it::assert_equal(t1.zip(t2), xs.iter().map(|&x| (Ok(x), Ok(x))));
+
+    // once one half is dropped, the other one is never blocked
+    let (mut t1, t2) = xs.iter().cloned().tee_bounded(1);
+    assert_eq!(t1.next(), Some(Ok(0)));
+    assert_eq!(t1.next(), Some(Err(TeeBufferFull)));
+    drop(t2);
+    it::assert_equal(t1, vec![Ok(1), Ok(2), Ok(3)]);
+
+    // the remaining half still reads what was buffered for it
+    let (t1, mut t2) = xs.iter().cloned().tee_bounded(2);
+    assert_eq!(t2.next(), Some(Ok(0)));
+    assert_eq!(t2.next(), Some(Ok(1)));
+    drop(t2);
+    it::assert_equal(t1, xs.iter().map(|&x| Ok(x)));
+}
+
 
 #[test]
 fn test_rciter() {
@@ -386,11 +632,46 @@ fn join() {
     assert_eq!(many.iter().join(", "), "1, 2, 3");
     assert_eq!( one.iter().join(", "), "1");
     assert_eq!(none.iter().join(", "), "");
+
+    assert_eq!(many.iter().join_with_capacity(", ", 1), "1, 2, 3");
+    assert_eq!( one.iter().join_with_capacity(", ", 0), "1");
+    assert_eq!(none.iter().join_with_capacity(", ", 8), "");
+
+    // the first element says nothing about the length of the others
+    let long = "x".repeat(1000);
+    let joined = std::iter::once(&*long).chain(std::iter::repeat("").take(1000)).join("");
+    assert_eq!(joined, long);
+    assert!(joined.capacity() < 10_000);
+}
+
+#[test]
+fn write_join() {
+    let many = [1, 2, 3];
+    let none: Vec<i32> = vec![];
+
+    let mut bytes = Vec::new();
+    many.iter().write_join(&mut bytes, ", ").unwrap();
+    none.iter().write_join(&mut bytes, ", ").unwrap();
+    assert_eq!(bytes, b"1, 2, 3");
+
+    let mut string = String::new();
+    many.iter().write_join_fmt(&mut string, ", ").unwrap();
+    none.iter().write_join_fmt(&mut string, ", ").unwrap();
+    assert_eq!(string, many.iter().join(", "));
+}
+
+#[test]
+fn sorted_unstable() {
+    let sc = [3, 4, 1, 2].iter().cloned().sorted_unstable();
+    it::assert_equal(sc, vec![1, 2, 3, 4]);
+
+    let v = (0..5).rev().sorted_unstable();
+    it::assert_equal(v, 0..5);
 }
 
 #[test]
 fn sorted_unstable_by() {
-    let sc = [3, 4, 1, 2].iter().cloned().sorted_by(|&a, &b| {
+    let sc = [3, 4, 1, 2].iter().cloned().sorted_unstable_by(|&a, &b| {
         a.cmp(&b)
     });
     it::assert_equal(sc, vec![1, 2, 3, 4]);
@@ -477,10 +758,26 @@ impl<T: Clone + Send, R: Clone + Rng + SeedableRng + Send> qc::Arbitrary for Ran
 fn k_smallest_sort<I>(i: I, k: u16) -> ()
 where
     I: Iterator + Clone,
-    I::Item: Ord + Debug,
+    I::Item: Ord + Debug + Clone,
 {
     let j = i.clone();
     let k = k as usize;
+    it::assert_equal(
+        i.clone().k_smallest_by(k, |a, b| b.cmp(a)),
+        j.clone().sorted_by(|a, b| b.cmp(a)).take(k)
+    );
+    it::assert_equal(
+        i.clone().k_smallest_by_key(k, |x| Reverse(x.clone())),
+        j.clone().sorted_by(|a, b| b.cmp(a)).take(k)
+    );
+    it::assert_equal(
+        i.clone().k_largest(k),
+        j.clone().sorted_by(|a, b| b.cmp(a)).take(k)
+    );
+    it::assert_equal(
+        i.clone().k_largest_by_key(k, |x| Reverse(x.clone())),
+        j.clone().sorted().take(k)
+    );
     it::assert_equal(
         i.k_smallest(k),
         j.sorted().take(k)
@@ -501,6 +798,16 @@ macro_rules! generic_test {
 
 generic_test!(k_smallest_sort, u8, u16, u32, u64, i8, i16, i32, i64);
 
+#[test]
+fn sorted_with_indices() {
+    let v = [3, 1, 3, 0].iter().sorted_with_indices();
+    it::assert_equal(v, vec![(3, &0), (1, &1), (0, &3), (2, &3)]);
+
+    // elements with equal keys are not compared, so they keep their order
+    let v = [(0, 'b'), (1, 'a'), (0, 'a')].iter().sorted_with_indices_by_key(|x| x.0);
+    it::assert_equal(v, vec![(0, &(0, 'b')), (2, &(0, 'a')), (1, &(1, 'a'))]);
+}
+
 #[test]
 fn sorted_by_key() {
     let sc = [3, 4, 1, 2].iter().cloned().sorted_by_key(|&x| x);
@@ -664,6 +971,24 @@ fn test_peek_nth_peeking_next() {
     assert_eq!(iter.peek(), None);
 }
 
+#[test]
+fn test_peeked() {
+    let mut iter = peek_nth(1..8);
+    assert_eq!(iter.peeked().count(), 0);
+    iter.peek_nth(3);
+    it::assert_equal(iter.peeked(), &[1, 2, 3, 4]);
+    it::assert_equal(iter.peeking_take_while(|&x| x < 3), 1..3);
+    it::assert_equal(iter.peeked(), &[3, 4]);
+
+    let mut iter = multipeek(1..8);
+    iter.peek();
+    iter.peek();
+    iter.reset_peek();
+    it::assert_equal(iter.peeked(), &[1, 2]);
+    it::assert_equal(iter.peeking_take_while(|&x| x < 2), 1..2);
+    it::assert_equal(iter.peeked(), &[2]);
+}
+
 #[test]
 fn pad_using() {
     it::assert_equal((0..0).pad_using(1, |_| 1), 1..2);
@@ -839,6 +1164,30 @@ fn chunks() {
     }
 }
 
+#[test]
+fn owned_groups_send() {
+    // the owned chunks and groups can be consumed on another thread
+    let data = vec![0, 0, 0, 1, 1, 0, 0, 2, 2, 3, 3];
+    let chunks = data.into_iter().into_chunks_owned(3);
+    let data = vec![1, 3, -2, -2, 1, 0, 1, 2];
+    let groups = data.into_iter().group_by_eager(|elt| *elt >= 0);
+    let (sums, lens) = std::thread::spawn(move || {
+        let sums = chunks.map(|chunk| chunk.into_iter().sum()).collect::<Vec<i32>>();
+        let lens = groups.map(|(key, group)| (key, group.len())).collect::<Vec<_>>();
+        (sums, lens)
+    }).join().unwrap();
+    assert_eq!(sums, vec![0, 2, 4, 6]);
+    assert_eq!(lens, vec![(true, 2), (false, 2), (true, 4)]);
+}
+
+#[test]
+fn chunk_by_owned() {
+    let data = vec![1, 3, -2, -2, 1, 0, 1, 2];
+    let groups = data.into_iter().chunk_by_owned(|elt| *elt >= 0);
+    let groups = std::thread::spawn(move || groups.collect::<Vec<_>>()).join().unwrap();
+    assert_eq!(groups, vec![(true, vec![1, 3]), (false, vec![-2, -2]), (true, vec![1, 0, 1, 2])]);
+}
+
 #[test]
 fn concat_empty() {
     let data: Vec<Vec<()>> = Vec::new();
@@ -934,6 +1283,20 @@ fn combinations_with_replacement() {
     );
 }
 
+#[test]
+fn combinatorial_size_hint_saturates() {
+    // every count here overflows a `usize`
+    assert_eq!((0..200).combinations(100).size_hint(), (usize::MAX, None));
+    assert_eq!((0..200).combinations_with_replacement(100).size_hint(), (usize::MAX, None));
+    assert_eq!((0..200).powerset().size_hint(), (usize::MAX, None));
+    // only once the source is exhausted
+    let mut perms = (0..30).permutations(30);
+    perms.next();
+    assert_eq!(perms.size_hint(), (0, None));
+    perms.next();
+    assert_eq!(perms.size_hint(), (usize::MAX, None));
+}
+
 #[test]
 fn powerset() {
     it::assert_equal((0..0).powerset(), vec![vec![]]);
@@ -951,6 +1314,20 @@ fn powerset() {
     assert_eq!((0..16).powerset().count(), 1 << 16);
 }
 
+#[test]
+fn powerset_bfs() {
+    it::assert_equal((0..0).powerset_bfs(), vec![vec![]]);
+    it::assert_equal((0..2).powerset_bfs(), vec![vec![], vec![0], vec![1], vec![0, 1]]);
+    assert_eq!((0..8).powerset_bfs().count(), 1 << 8);
+
+    // only the elements needed so far are read
+    let mut read = 0;
+    let mut it = (0..).inspect(|_| read += 1).powerset_bfs();
+    it.by_ref().take(5).for_each(drop);
+    drop(it);
+    assert_eq!(read, 3);
+}
+
 #[test]
 fn diff_mismatch() {
     let a = vec![1, 2, 3, 4];
@@ -992,6 +1369,43 @@ fn diff_shorter() {
     });
 }
 
+#[test]
+fn diff_report() {
+    let diff = (1..5).first_divergence(vec![1, 2, 0], |a, b| a == b).unwrap();
+    assert_eq!(diff.index(), 2);
+    assert_eq!(diff.elements(), (Some(&3), Some(&0)));
+    assert!(format!("{:?}", diff).starts_with("FirstMismatch(2, "));
+
+    let diff = (1..3).first_divergence(vec![1, 2, 3, 4], |a, b| a == b).unwrap();
+    assert_eq!(diff.index(), 2);
+    assert_eq!(diff.elements(), (None, Some(&3)));
+    assert!(matches!(diff, it::Diff::Longer(..)));
+
+    assert!((1..3).first_divergence(vec![1, 2], |a, b| a == b).is_none());
+}
+
+#[test]
+fn assert_equal_by() {
+    it::assert_equal_by(vec![1.0, 2.5], [1.01, 2.49], |a: &f64, b: &f64| (a - b).abs() < 0.1);
+    it::assert_equal_by("abc".chars(), "ABC".chars(), |a, b| a.eq_ignore_ascii_case(b));
+}
+
+#[test]
+#[should_panic(expected = "Failed assertion Some(5) == Some(0) for iteration 4\n  \
+                           left: [.., 2, 3, 4] Some(5) [6, 7, 8, ..]\n \
+                           right: [.., 2, 3, 4] Some(0) [6]")]
+fn assert_equal_by_context() {
+    it::assert_equal_by(1..10, vec![1, 2, 3, 4, 0, 6], |a, b| a == b);
+}
+
+#[test]
+#[should_panic(expected = "Failed assertion None == Some(3) for iteration 2\n  \
+                           left: [1, 2] None []\n \
+                           right: [1, 2] Some(3) []")]
+fn assert_equal_by_shorter() {
+    it::assert_equal_by(1..3, 1..4, |a, b| a == b);
+}
+
 #[test]
 fn minmax() {
     use std::cmp::Ordering;
@@ -1046,6 +1460,74 @@ fn format() {
     let dataf = [1.1, 2.71828, -22.];
     let t3 = format!("{:.2e}", dataf.iter().format(", "));
     assert_eq!(t3, "1.10e0, 2.72e0, -2.20e1");
+
+    // Debug-only elements, with the flags forwarded to each one
+    let datad = [(1, 'a'), (2, 'b')];
+    let t4 = format!("{:?}", datad.iter().format("; "));
+    assert_eq!(t4, "(1, 'a'); (2, 'b')");
+    let t5 = format!("{:03?}", datad.iter().format("; "));
+    assert_eq!(t5, "(001, 'a'); (002, 'b')");
+    let t6 = format!("{:#?}", [Some(1)].iter().format(""));
+    assert_eq!(t6, "Some(\n    1,\n)");
+}
+
+#[test]
+fn format_with_indexed() {
+    let list = (1..5).format_with_indexed(|_, last| if last { " and " } else { ", " }, |e, f| f(&e));
+    assert_eq!(list.to_string(), "1, 2, 3 and 4");
+    // the element flags do not apply to the separators
+    let padded = (1..4).format_with_indexed(|_, _| ",", |e, f| f(&e));
+    assert_eq!(format!("{:>4}", padded), "   1,   2,   3");
+}
+
+#[test]
+fn format_filter_with() {
+    let evens = (0..7).format_filter_with(", ", |x, f| if x % 2 == 0 { f(&x) } else { Ok(()) });
+    assert_eq!(evens.to_string(), "0, 2, 4, 6");
+    let odds = (0..7).format_filter_with("-", |x, f| if x % 2 == 1 { f(&x) } else { Ok(()) });
+    assert_eq!(odds.to_string(), "1-3-5");
+    // several calls of the callback for one element write a single separator
+    let pairs = (1..4).format_filter_with(" ", |x, f| {
+        f(&x)?;
+        f(&"!")
+    });
+    assert_eq!(pairs.to_string(), "1! 2! 3!");
+
+    use std::fmt::Write;
+    let mut out = String::new();
+    let failing = (0..5).format_filter_with(", ", |x, f| {
+        if x == 3 { Err(std::fmt::Error) } else { f(&x) }
+    });
+    assert!(write!(out, "{}", failing).is_err());
+    assert_eq!(out, "0, 1, 2");
+}
+
+#[test]
+fn format_truncated() {
+    let more = |n| format!("... {} more", n);
+    assert_eq!(format!("{}", (0..0).format_truncated(2, ", ", more)), "");
+    assert_eq!(format!("{}", (0..5).format_truncated(0, ", ", more)), "... 5 more");
+    assert_eq!(format!("{:02}", (0..5).format_truncated(2, ", ", more)), "00, 01... 3 more");
+    // counted by consuming the rest when the size hint is not exact
+    let evens = (0..10).filter(|x| x % 2 == 0);
+    assert_eq!(format!("{}", evens.format_truncated(4, " ", more)), "0 2 4 6... 1 more");
+    // the element flags do not apply to the suffix
+    let floats = [1.111, 2.222, 3.333, 4.444];
+    let more = |n| format!(", ... ({} more)", n);
+    assert_eq!(format!("{:.2}", floats.iter().format_truncated(2, ", ", more)),
+               "1.11, 2.22, ... (2 more)");
+}
+
+#[test]
+fn format_wrapped() {
+    let data = [0, 1, 2, 3];
+    let none: [i32; 0] = [];
+
+    assert_eq!(format!("{}", data.iter().format_wrapped("[", ", ", "]")), "[0, 1, 2, 3]");
+    assert_eq!(format!("{:02x}", data.iter().format_wrapped("<", "", ">")), "<00010203>");
+    assert_eq!(format!("{}", none.iter().format_wrapped("[", ", ", "]")), "[]");
+    assert_eq!(format!("{}", none.iter().format_wrapped("[", ", ", "]").with_empty("")), "");
+    assert_eq!(format!("{}", data[..1].iter().format_wrapped("[", ", ", "]").with_empty("")), "[0]");
 }
 
 #[test]
@@ -1119,4 +1601,27 @@ fn multiunzip() {
     let (): () = [(), (), ()].iter().cloned().multiunzip();
     let t: (Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>) = [(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)].iter().cloned().multiunzip();    
     assert_eq!(t, (vec![0], vec![1], vec![2], vec![3], vec![4], vec![5], vec![6], vec![7], vec![8], vec![9], vec![10], vec![11]));
-}
\ No newline at end of file
+    let (a, _, _, _, _, _, _, _, _, _, _, _, _, _, _, p): (Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>) = [(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15)].iter().cloned().multiunzip();
+    assert_eq!((a, p), (vec![0], vec![15]));
+}
+
+#[test]
+fn unzip_array() {
+    let [a, b, c] = [[0, 1, 2], [3, 4, 5], [6, 7, 8]].iter().cloned().unzip_array();
+    assert_eq!((a, b, c), (vec![0, 3, 6], vec![1, 4, 7], vec![2, 5, 8]));
+    let columns: [Vec<u8>; 4] = std::iter::empty().unzip_array();
+    assert!(columns.iter().all(Vec::is_empty));
+    let []: [Vec<u8>; 0] = [[], []].iter().cloned().unzip_array();
+}
+
+#[test]
+fn try_collect_vec() {
+    let mut it = vec![Ok(1), Err("a"), Ok(2), Err("b")].into_iter();
+    assert_eq!(it.by_ref().try_collect_vec(), Err("a"));
+    // stopped at the first error
+    assert_eq!(it.next(), Some(Ok(2)));
+    let ok: Result<Vec<u8>, ()> = vec![Ok(1), Ok(2)].into_iter().try_collect_vec();
+    assert_eq!(ok, Ok(vec![1, 2]));
+    let set: Result<std::collections::HashSet<u8>, ()> = vec![Ok(1), Ok(1)].into_iter().try_collect();
+    assert_eq!(set.map(|set| set.len()), Ok(1));
+}